@@ -4,11 +4,27 @@ use pqc_nostd::{
     kyber_generate_key_pair, FIPS_CONTEXT,
 };
 
+/// Restores benchmark credentials on the global module and logs in as User.
+fn login_user() {
+    use pqc_nostd::auth::{load_credentials, login, Role, StoredCredentials};
+    if pqc_nostd::FipsModule::global().has_default_credentials() {
+        let stored = StoredCredentials::from_passwords(
+            b"user password",
+            [0x11; 16],
+            b"officer password",
+            [0x22; 16],
+        )
+        .unwrap();
+        load_credentials(&stored).unwrap();
+    }
+    login(Role::User, b"user password").unwrap();
+}
+
 fn benchmark_ml_kem(c: &mut Criterion) {
     // Ensure FIPS module is initialized
     let _ = pqc_nostd::run_post();
     // Authenticate first
-    pqc_nostd::auth::login(pqc_nostd::auth::Role::User, b"user123").unwrap();
+    login_user();
 
     let mut group = c.benchmark_group("ML-KEM-1024");
 
@@ -39,7 +55,7 @@ fn benchmark_ml_dsa(c: &mut Criterion) {
     // Ensure FIPS module is initialized
     let _ = pqc_nostd::run_post();
     // Authenticate first
-    pqc_nostd::auth::login(pqc_nostd::auth::Role::User, b"user123").unwrap();
+    login_user();
 
     let mut group = c.benchmark_group("ML-DSA-65");
 
@@ -6,9 +6,36 @@
 // Contact: aaronschnacky@gmail.com
 // src/auth.rs
 //! Role-Based Authentication (Level 2 Requirement).
+//!
+//! Each role has one credential: a PBKDF2-HMAC-SHA-256 verifier of its
+//! password (SP 800-132) under a per-role salt. Only the verifier is stored,
+//! and [`FipsModule::login`] compares it in constant time. A new module
+//! holds the default passwords `user123` and `admin456` under fixed salts
+//! and refuses every service except [`FipsModule::set_password`] and
+//! [`FipsModule::logout`] until the Crypto Officer has replaced both.
+//!
+//! Credentials live only in RAM. After provisioning, the Crypto Officer
+//! exports them with [`FipsModule::export_credentials`] to non-volatile
+//! storage, and at power-up the application restores them with
+//! [`FipsModule::with_credentials`] or [`FipsModule::load_credentials`].
 
 use crate::error::{PqcError, Result};
-use core::sync::atomic::{AtomicU8, Ordering};
+use crate::module::FipsModule;
+use core::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Salt size in bytes (128 bits, SP 800-132 Section 5.1).
+pub const SALT_BYTES: usize = 16;
+/// Password verifier size in bytes.
+pub const VERIFIER_BYTES: usize = 32;
+/// PBKDF2 iteration count.
+pub const PBKDF2_ITERATIONS: u32 = 10_000;
+/// Minimum length of a password set with [`FipsModule::set_password`].
+pub const MIN_PASSWORD_BYTES: usize = 8;
+/// Size of [`StoredCredentials::to_bytes`].
+pub const STORED_CREDENTIALS_BYTES: usize = 2 * (SALT_BYTES + VERIFIER_BYTES);
 
 /// FIPS 140-3 Roles.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,6 +46,85 @@ pub enum Role {
     CryptoOfficer,
 }
 
+/// Salt of the default User password.
+const DEFAULT_USER_SALT: [u8; SALT_BYTES] = *b"pqc-nostd User\0\0";
+/// PBKDF2 verifier of the default User password `user123`.
+const DEFAULT_USER_VERIFIER: [u8; VERIFIER_BYTES] = [
+    0x79, 0xf4, 0xb3, 0xa2, 0xb1, 0x48, 0xdb, 0x98, 0xc4, 0x05, 0xa6, 0x29, 0xe3, 0xe1, 0x4a, 0x75,
+    0x99, 0xf2, 0xdc, 0xcf, 0xc4, 0xb5, 0x54, 0x52, 0xbf, 0x0f, 0x7b, 0x07, 0xf9, 0x2d, 0xa6, 0x6a,
+];
+/// Salt of the default Crypto Officer password.
+const DEFAULT_CO_SALT: [u8; SALT_BYTES] = *b"pqc-nostd CO\0\0\0\0";
+/// PBKDF2 verifier of the default Crypto Officer password `admin456`.
+const DEFAULT_CO_VERIFIER: [u8; VERIFIER_BYTES] = [
+    0x02, 0x9f, 0xec, 0x42, 0xcf, 0xc2, 0xe5, 0x60, 0xf8, 0xf3, 0x94, 0x15, 0xf6, 0x98, 0x36, 0xad,
+    0x97, 0x58, 0x76, 0x3d, 0xc5, 0x6f, 0x9a, 0x9f, 0x18, 0x5d, 0xca, 0x42, 0x49, 0x30, 0xb1, 0xb7,
+];
+
+/// The salts and PBKDF2 verifiers of both roles, as kept in non-volatile
+/// storage between power cycles.
+///
+/// The encoding is the User salt and verifier followed by the Crypto Officer
+/// salt and verifier.
+#[derive(Clone, PartialEq, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct StoredCredentials {
+    user_salt: [u8; SALT_BYTES],
+    user_verifier: [u8; VERIFIER_BYTES],
+    co_salt: [u8; SALT_BYTES],
+    co_verifier: [u8; VERIFIER_BYTES],
+}
+
+impl StoredCredentials {
+    /// Derives the credentials for new User and Crypto Officer passwords,
+    /// e.g. when provisioning a device at the factory.
+    ///
+    /// Salts must be fresh output of an Approved DRBG. Passwords shorter than
+    /// [`MIN_PASSWORD_BYTES`] return [`PqcError::InvalidParameter`].
+    pub fn from_passwords(
+        user_password: &[u8],
+        user_salt: [u8; SALT_BYTES],
+        co_password: &[u8],
+        co_salt: [u8; SALT_BYTES],
+    ) -> Result<Self> {
+        if user_password.len() < MIN_PASSWORD_BYTES || co_password.len() < MIN_PASSWORD_BYTES {
+            return Err(PqcError::InvalidParameter);
+        }
+        Ok(Self {
+            user_salt,
+            user_verifier: pbkdf2_sha256(user_password, &user_salt, PBKDF2_ITERATIONS),
+            co_salt,
+            co_verifier: pbkdf2_sha256(co_password, &co_salt, PBKDF2_ITERATIONS),
+        })
+    }
+
+    /// Decodes credentials read back from storage.
+    pub fn from_bytes(bytes: &[u8; STORED_CREDENTIALS_BYTES]) -> Self {
+        let mut stored = Self {
+            user_salt: [0; SALT_BYTES],
+            user_verifier: [0; VERIFIER_BYTES],
+            co_salt: [0; SALT_BYTES],
+            co_verifier: [0; VERIFIER_BYTES],
+        };
+        let (user, co) = bytes.split_at(SALT_BYTES + VERIFIER_BYTES);
+        stored.user_salt.copy_from_slice(&user[..SALT_BYTES]);
+        stored.user_verifier.copy_from_slice(&user[SALT_BYTES..]);
+        stored.co_salt.copy_from_slice(&co[..SALT_BYTES]);
+        stored.co_verifier.copy_from_slice(&co[SALT_BYTES..]);
+        stored
+    }
+
+    /// Encodes the credentials for storage.
+    pub fn to_bytes(&self) -> [u8; STORED_CREDENTIALS_BYTES] {
+        let mut out = [0u8; STORED_CREDENTIALS_BYTES];
+        let (user, co) = out.split_at_mut(SALT_BYTES + VERIFIER_BYTES);
+        user[..SALT_BYTES].copy_from_slice(&self.user_salt);
+        user[SALT_BYTES..].copy_from_slice(&self.user_verifier);
+        co[..SALT_BYTES].copy_from_slice(&self.co_salt);
+        co[SALT_BYTES..].copy_from_slice(&self.co_verifier);
+        out
+    }
+}
+
 /// Authentication state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum AuthState {
@@ -27,53 +133,360 @@ enum AuthState {
     LoggedInCO = 2,
 }
 
-static AUTH_STATE: AtomicU8 = AtomicU8::new(AuthState::LoggedOut as u8);
-
-/// Logs in with the specified role and password.
+/// One role's salt, password verifier, failed login count and back-off
+/// deadline.
 ///
-/// **Note:** In a real module, passwords would be hashed and compared against stored hashes.
-/// For this demonstration, we use simple hardcoded checks.
-pub fn login(role: Role, password: &[u8]) -> Result<()> {
-    // Mock credentials
-    let user_pw = b"user123";
-    let co_pw = b"admin456";
-
-    match role {
-        Role::User => {
-            if password == user_pw {
-                AUTH_STATE.store(AuthState::LoggedInUser as u8, Ordering::Release);
-                Ok(())
-            } else {
-                Err(PqcError::AuthenticationFailure)
-            }
+/// A login that races with [`FipsModule::set_password`] may read a mix of the
+/// old and new values and fail; it never succeeds with a wrong password.
+pub(crate) struct Credential {
+    salt: [AtomicU8; SALT_BYTES],
+    verifier: [AtomicU8; VERIFIER_BYTES],
+    failures: AtomicU8,
+    /// Module clock time before which the next attempt is refused.
+    next_attempt_ms: AtomicU64,
+}
+
+impl Credential {
+    const fn new(salt: [u8; SALT_BYTES], verifier: [u8; VERIFIER_BYTES]) -> Self {
+        Self {
+            salt: atomic_bytes(salt),
+            verifier: atomic_bytes(verifier),
+            failures: AtomicU8::new(0),
+            next_attempt_ms: AtomicU64::new(0),
         }
-        Role::CryptoOfficer => {
-            if password == co_pw {
-                AUTH_STATE.store(AuthState::LoggedInCO as u8, Ordering::Release);
-                Ok(())
-            } else {
-                Err(PqcError::AuthenticationFailure)
-            }
+    }
+
+    /// The credential for the default User password.
+    pub(crate) const fn default_user() -> Self {
+        Self::new(DEFAULT_USER_SALT, DEFAULT_USER_VERIFIER)
+    }
+
+    /// The credential for the default Crypto Officer password.
+    pub(crate) const fn default_co() -> Self {
+        Self::new(DEFAULT_CO_SALT, DEFAULT_CO_VERIFIER)
+    }
+
+    /// Returns true if `password` matches, comparing in constant time.
+    fn verify(&self, password: &[u8]) -> bool {
+        let mut salt = [0u8; SALT_BYTES];
+        load_bytes(&self.salt, &mut salt);
+        let mut expected = [0u8; VERIFIER_BYTES];
+        load_bytes(&self.verifier, &mut expected);
+        let mut derived = pbkdf2_sha256(password, &salt, PBKDF2_ITERATIONS);
+        let diff = derived
+            .iter()
+            .zip(expected.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        derived.zeroize();
+        expected.zeroize();
+        core::hint::black_box(diff) == 0
+    }
+
+    fn set(&self, password: &[u8], salt: &[u8; SALT_BYTES]) {
+        let mut verifier = pbkdf2_sha256(password, salt, PBKDF2_ITERATIONS);
+        self.store(salt, &verifier);
+        verifier.zeroize();
+    }
+
+    fn store(&self, salt: &[u8; SALT_BYTES], verifier: &[u8; VERIFIER_BYTES]) {
+        store_bytes(&self.salt, salt);
+        store_bytes(&self.verifier, verifier);
+    }
+
+    fn load(&self, salt: &mut [u8; SALT_BYTES], verifier: &mut [u8; VERIFIER_BYTES]) {
+        load_bytes(&self.salt, salt);
+        load_bytes(&self.verifier, verifier);
+    }
+
+    /// Returns true if the credential is the given salt and verifier.
+    fn matches(&self, salt: &[u8; SALT_BYTES], verifier: &[u8; VERIFIER_BYTES]) -> bool {
+        self.salt
+            .iter()
+            .zip(salt)
+            .all(|(a, b)| a.load(Ordering::Acquire) == *b)
+            && self
+                .verifier
+                .iter()
+                .zip(verifier)
+                .all(|(a, b)| a.load(Ordering::Acquire) == *b)
+    }
+}
+
+const fn atomic_bytes<const N: usize>(bytes: [u8; N]) -> [AtomicU8; N] {
+    let mut out = [const { AtomicU8::new(0) }; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = AtomicU8::new(bytes[i]);
+        i += 1;
+    }
+    out
+}
+
+fn load_bytes(src: &[AtomicU8], dst: &mut [u8]) {
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = s.load(Ordering::Acquire);
+    }
+}
+
+fn store_bytes(dst: &[AtomicU8], src: &[u8]) {
+    for (d, s) in dst.iter().zip(src) {
+        d.store(*s, Ordering::Release);
+    }
+}
+
+/// PBKDF2 with HMAC-SHA-256 and a 32-byte output (SP 800-132, RFC 8018).
+pub(crate) fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32) -> [u8; 32] {
+    let prf = <Hmac<Sha256> as Mac>::new_from_slice(password).expect("HMAC accepts any key length");
+    let mut mac = prf.clone();
+    mac.update(salt);
+    mac.update(&1u32.to_be_bytes());
+    let mut u: [u8; 32] = mac.finalize().into_bytes().into();
+    let mut t = u;
+    for _ in 1..iterations {
+        let mut mac = prf.clone();
+        mac.update(&u);
+        u = mac.finalize().into_bytes().into();
+        for (t, u) in t.iter_mut().zip(u.iter()) {
+            *t ^= u;
         }
     }
+    u.zeroize();
+    t
 }
 
-/// Logs out the current operator.
+impl FipsModule {
+    /// Logs in with the specified role and password.
+    ///
+    /// The password is checked against the role's PBKDF2 verifier. Every
+    /// attempt is counted against the [`crate::policy::LockoutPolicy`] before
+    /// the check runs, so concurrent attempts cannot get past the lockout
+    /// threshold: a role that has reached it returns
+    /// [`PqcError::AuthenticationLocked`] until [`FipsModule::unlock`]. Past
+    /// the back-off threshold each attempt also starts a delay on the module
+    /// clock, doubling with every failure; attempts made before it has
+    /// passed return [`PqcError::AuthenticationLocked`] without checking the
+    /// password. The delay only runs while the application reports time
+    /// with [`FipsModule::update_clock`]. A failed login leaves the current
+    /// operator logged in and a successful one clears the count.
+    pub fn login(&self, role: Role, password: &[u8]) -> Result<()> {
+        let credential = self.credential(role);
+        let state = match role {
+            Role::User => AuthState::LoggedInUser,
+            Role::CryptoOfficer => AuthState::LoggedInCO,
+        };
+        let policy = self.lockout_policy();
+        let now = self.clock_ms.load(Ordering::Acquire);
+        // Claim the time slot first, starting the delay this attempt earns
+        // if it fails, so concurrent attempts cannot share one slot
+        let delay = policy.backoff_delay(
+            credential
+                .failures
+                .load(Ordering::Acquire)
+                .saturating_add(1),
+        );
+        credential
+            .next_attempt_ms
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |next| {
+                (now >= next).then(|| now.saturating_add(delay))
+            })
+            .map_err(|_| PqcError::AuthenticationLocked)?;
+        credential
+            .failures
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |f| {
+                (f < policy.lockout_threshold).then(|| f + 1)
+            })
+            .map_err(|_| PqcError::AuthenticationLocked)?;
+        if !credential.verify(password) {
+            return Err(PqcError::AuthenticationFailure);
+        }
+        credential.failures.store(0, Ordering::Release);
+        credential.next_attempt_ms.store(0, Ordering::Release);
+        self.auth.store(state as u8, Ordering::Release);
+        Ok(())
+    }
+
+    /// Reports the current time of the application's monotonic clock, in
+    /// milliseconds, for the login back-off.
+    ///
+    /// Call it before every login, or periodically from a timer. Readings
+    /// that go backwards are ignored.
+    pub fn update_clock(&self, now_ms: u64) {
+        self.clock_ms.fetch_max(now_ms, Ordering::AcqRel);
+    }
+
+    /// Clears the failed login count and back-off delay of `role`, lifting a
+    /// lockout (Crypto Officer only).
+    ///
+    /// A locked-out Crypto Officer cannot log in to call this; only a new
+    /// module (a power cycle) clears that lockout.
+    pub fn unlock(&self, role: Role) -> Result<()> {
+        self.check_service(Role::CryptoOfficer)?;
+        let credential = self.credential(role);
+        credential.failures.store(0, Ordering::Release);
+        credential.next_attempt_ms.store(0, Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns the number of consecutive failed logins for `role`.
+    pub fn failed_logins(&self, role: Role) -> u8 {
+        self.credential(role).failures.load(Ordering::Acquire)
+    }
+
+    /// Returns true if `role` is locked out after too many failed logins.
+    pub fn is_locked(&self, role: Role) -> bool {
+        self.failed_logins(role) >= self.lockout_policy().lockout_threshold
+    }
+
+    fn credential(&self, role: Role) -> &Credential {
+        match role {
+            Role::User => &self.user_credential,
+            Role::CryptoOfficer => &self.co_credential,
+        }
+    }
+
+    /// Sets or rotates the password of `role` (Crypto Officer only).
+    ///
+    /// `salt` must be fresh output of an Approved DRBG. Passwords shorter
+    /// than [`MIN_PASSWORD_BYTES`] return [`PqcError::InvalidParameter`].
+    /// Rotating the password does not log out an operator already logged in.
+    /// This is the one service that runs while the module still holds a
+    /// default password.
+    pub fn set_password(&self, role: Role, password: &[u8], salt: [u8; SALT_BYTES]) -> Result<()> {
+        self.check_operational()?;
+        self.check_authority(Role::CryptoOfficer)?;
+        if password.len() < MIN_PASSWORD_BYTES {
+            return Err(PqcError::InvalidParameter);
+        }
+        self.credential(role).set(password, &salt);
+        Ok(())
+    }
+
+    /// Creates a module, like [`FipsModule::new`], holding credentials
+    /// restored from storage.
+    pub fn with_credentials(stored: &StoredCredentials) -> Self {
+        let module = Self::new();
+        module.store_credentials(stored);
+        module
+    }
+
+    /// Replaces both credentials with ones restored from storage.
+    ///
+    /// While both roles still hold their default passwords anyone may call
+    /// this, in any state, since the default passwords are public; it is how
+    /// the credentials of the global module are restored at power-up. Once
+    /// either password is set, it is a Crypto Officer service on an
+    /// Operational module, like [`FipsModule::set_password`]. Operators
+    /// already logged in stay logged in.
+    pub fn load_credentials(&self, stored: &StoredCredentials) -> Result<()> {
+        if !self.has_only_default_credentials() {
+            self.check_operational()?;
+            self.check_authority(Role::CryptoOfficer)?;
+        }
+        self.store_credentials(stored);
+        Ok(())
+    }
+
+    /// Returns the current credentials for storage (Crypto Officer only).
+    ///
+    /// Only the salts and verifiers leave the module, never a password.
+    pub fn export_credentials(&self) -> Result<StoredCredentials> {
+        self.check_service(Role::CryptoOfficer)?;
+        let mut stored = StoredCredentials::from_bytes(&[0; STORED_CREDENTIALS_BYTES]);
+        self.user_credential
+            .load(&mut stored.user_salt, &mut stored.user_verifier);
+        self.co_credential
+            .load(&mut stored.co_salt, &mut stored.co_verifier);
+        Ok(stored)
+    }
+
+    /// Returns true while either role still has its default password.
+    pub fn has_default_credentials(&self) -> bool {
+        self.user_credential
+            .matches(&DEFAULT_USER_SALT, &DEFAULT_USER_VERIFIER)
+            || self
+                .co_credential
+                .matches(&DEFAULT_CO_SALT, &DEFAULT_CO_VERIFIER)
+    }
+
+    /// Returns true while both roles still have their default passwords.
+    fn has_only_default_credentials(&self) -> bool {
+        self.user_credential
+            .matches(&DEFAULT_USER_SALT, &DEFAULT_USER_VERIFIER)
+            && self
+                .co_credential
+                .matches(&DEFAULT_CO_SALT, &DEFAULT_CO_VERIFIER)
+    }
+
+    fn store_credentials(&self, stored: &StoredCredentials) {
+        self.user_credential
+            .store(&stored.user_salt, &stored.user_verifier);
+        self.co_credential
+            .store(&stored.co_salt, &stored.co_verifier);
+    }
+
+    /// Logs out the current operator.
+    pub fn logout(&self) {
+        self.auth
+            .store(AuthState::LoggedOut as u8, Ordering::Release);
+    }
+
+    /// Checks if the current operator has the required authority.
+    pub fn check_authority(&self, required_role: Role) -> Result<()> {
+        let current = self.auth.load(Ordering::Acquire);
+        match (required_role, current) {
+            (Role::User, 1) => Ok(()),          // User is logged in
+            (Role::CryptoOfficer, 2) => Ok(()), // CO is logged in
+            _ => Err(PqcError::AuthenticationFailure),
+        }
+    }
+
+    /// Returns true if any user is logged in.
+    pub fn is_authenticated(&self) -> bool {
+        self.auth.load(Ordering::Acquire) != AuthState::LoggedOut as u8
+    }
+}
+
+/// Logs in to the global module with the specified role and password.
+pub fn login(role: Role, password: &[u8]) -> Result<()> {
+    FipsModule::global().login(role, password)
+}
+
+/// Reports the current time of the application's monotonic clock to the global module.
+pub fn update_clock(now_ms: u64) {
+    FipsModule::global().update_clock(now_ms);
+}
+
+/// Sets or rotates the password of `role` on the global module (Crypto Officer only).
+pub fn set_password(role: Role, password: &[u8], salt: [u8; SALT_BYTES]) -> Result<()> {
+    FipsModule::global().set_password(role, password, salt)
+}
+
+/// Replaces both credentials of the global module with ones restored from storage.
+pub fn load_credentials(stored: &StoredCredentials) -> Result<()> {
+    FipsModule::global().load_credentials(stored)
+}
+
+/// Returns the current credentials of the global module for storage (Crypto Officer only).
+pub fn export_credentials() -> Result<StoredCredentials> {
+    FipsModule::global().export_credentials()
+}
+
+/// Clears the failed login count of `role` on the global module (Crypto Officer only).
+pub fn unlock(role: Role) -> Result<()> {
+    FipsModule::global().unlock(role)
+}
+
+/// Logs out the current operator of the global module.
 pub fn logout() {
-    AUTH_STATE.store(AuthState::LoggedOut as u8, Ordering::Release);
+    FipsModule::global().logout();
 }
 
-/// Checks if the current operator has the required authority.
+/// Checks if the current operator of the global module has the required authority.
 pub fn check_authority(required_role: Role) -> Result<()> {
-    let current = AUTH_STATE.load(Ordering::Acquire);
-    match (required_role, current) {
-        (Role::User, 1) => Ok(()),          // User is logged in
-        (Role::CryptoOfficer, 2) => Ok(()), // CO is logged in
-        _ => Err(PqcError::AuthenticationFailure),
-    }
+    FipsModule::global().check_authority(required_role)
 }
 
-/// Returns true if any user is logged in.
+/// Returns true if any user is logged in to the global module.
 pub fn is_authenticated() -> bool {
-    AUTH_STATE.load(Ordering::Acquire) != AuthState::LoggedOut as u8
+    FipsModule::global().is_authenticated()
 }
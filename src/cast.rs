@@ -7,6 +7,12 @@
 // src/cast.rs
 //! FIPS 140-3 CASTs – now compiles with digest 0.10
 
+#[cfg(all(feature = "ctr-drbg", feature = "fips_140_3"))]
+use crate::drbg::CtrDrbg;
+#[cfg(feature = "fips_140_3")]
+use crate::drbg::HmacDrbg;
+#[cfg(feature = "fips_140_3")]
+use crate::entropy::HealthTests;
 #[cfg(feature = "fips_140_3")]
 use crate::error::{PqcError, Result};
 #[cfg(feature = "fips_140_3")]
@@ -68,12 +74,119 @@ pub fn shake256_cast() -> Result<()> {
     }
 }
 
-/// Runs all hash-based Conditional Algorithm Self-Tests.
+/// Runs the HMAC_DRBG (SHA-256) Conditional Algorithm Self-Test.
+///
+/// Covers the instantiate, generate and reseed functions (SP 800-90A,
+/// Section 11.3). The first part is CAVP HMAC_DRBG SHA-256 COUNT 0 (no
+/// prediction resistance, no personalization or additional input). The
+/// reseed part, with additional input to both reseed and generate, was
+/// computed with OpenSSL 3.5's HMAC-DRBG, which reproduces COUNT 0 from the
+/// same inputs.
+#[cfg(feature = "fips_140_3")]
+pub fn hmac_drbg_cast() -> Result<()> {
+    let mut drbg = HmacDrbg::instantiate_unchecked(
+        &hex!("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488"),
+        &hex!("659ba96c601dc69fc902940805ec0ca8"),
+        &[],
+    )?;
+    let mut out = [0u8; 128];
+    drbg.generate_unchecked(&mut out, &[])?;
+    drbg.generate_unchecked(&mut out, &[])?;
+    if out != hex!("e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc107694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8") {
+        return Err(PqcError::CastFailure);
+    }
+
+    drbg.reseed_unchecked(&[0x5a; 32], b"reseed additional input")?;
+    let mut out = [0u8; 32];
+    drbg.generate_unchecked(&mut out, b"HMAC_DRBG reseed CAST")?;
+    if out != hex!("ee0b5724fdeb2b10740c174599bb28ec590f786579aa291aa7024f3bdec5bc37") {
+        Err(PqcError::CastFailure)
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs the CTR_DRBG (AES-256, with derivation function) Conditional
+/// Algorithm Self-Test.
+///
+/// Covers the instantiate, generate and reseed functions (SP 800-90A,
+/// Section 11.3). The first part is CAVP CTR_DRBG AES-256 use df COUNT 0 (no
+/// prediction resistance, no personalization or additional input). The
+/// reseed part, with additional input to both reseed and generate, was
+/// computed with OpenSSL 3.5's CTR-DRBG, which reproduces COUNT 0 from the
+/// same inputs.
+#[cfg(all(feature = "ctr-drbg", feature = "fips_140_3"))]
+pub fn ctr_drbg_cast() -> Result<()> {
+    let mut drbg = CtrDrbg::instantiate_unchecked(
+        &hex!("36401940fa8b1fba91a1661f211d78a0b9389a74e5bccfece8d766af1a6d3b14"),
+        &hex!("496f25b0f1301b4f501be30380a137eb"),
+        &[],
+    )?;
+    let mut out = [0u8; 64];
+    drbg.generate_unchecked(&mut out, &[])?;
+    drbg.generate_unchecked(&mut out, &[])?;
+    if out != hex!("5862eb38bd558dd978a696e6df164782ddd887e7e9a6c9f3f1fbafb78941b535a64912dfd224c6dc7454e5250b3d97165e16260c2faf1cc7735cb75fb4f07e1d") {
+        return Err(PqcError::CastFailure);
+    }
+
+    drbg.reseed_unchecked(&[0x5a; 32], b"reseed additional input")?;
+    let mut out = [0u8; 32];
+    drbg.generate_unchecked(&mut out, b"CTR_DRBG reseed CAST")?;
+    if out != hex!("4b95e569d516b5a25fbee5cda4dc068e9a2249548bdc26de5427f0a271498669") {
+        Err(PqcError::CastFailure)
+    } else {
+        Ok(())
+    }
+}
+
+/// Self-tests the SP 800-90B Repetition Count and Adaptive Proportion Tests.
+///
+/// A stuck source must fail the RCT, a source biased towards one value must
+/// fail the APT, and a uniform sequence must pass both (H = 8 bits/sample).
+#[cfg(feature = "fips_140_3")]
+pub fn entropy_health_cast() -> Result<()> {
+    let mut health = HealthTests::new(8)?;
+    if (0..4).all(|_| health.check(0x00).is_ok()) {
+        return Err(PqcError::CastFailure);
+    }
+
+    let mut health = HealthTests::new(8)?;
+    if (1..=32u8).all(|i| health.check(0xaa).is_ok() && health.check(i).is_ok()) {
+        return Err(PqcError::CastFailure);
+    }
+
+    let mut health = HealthTests::new(8)?;
+    for i in 0..4 * 256 {
+        health.check(i as u8).map_err(|_| PqcError::CastFailure)?;
+    }
+    Ok(())
+}
+
+/// Runs the PBKDF2-HMAC-SHA-256 Conditional Algorithm Self-Test used by
+/// the password verifiers.
+#[cfg(feature = "fips_140_3")]
+pub fn pbkdf2_cast() -> Result<()> {
+    // RFC 7914, Section 11 inputs with c = 2
+    let out = crate::auth::pbkdf2_sha256(b"passwd", b"salt", 2);
+    if out != hex!("2d412f896e76685e30df569f0a740634e31f031f749d607d9e44210bffb91a6a") {
+        Err(PqcError::CastFailure)
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs all hash, DRBG, entropy health test and PBKDF2 Conditional
+/// Algorithm Self-Tests.
 #[cfg(feature = "fips_140_3")]
 pub fn run_hash_casts() -> Result<()> {
     sha3_256_cast()?;
     sha3_512_cast()?;
     shake128_cast()?;
     shake256_cast()?;
+    hmac_drbg_cast()?;
+    #[cfg(feature = "ctr-drbg")]
+    ctr_drbg_cast()?;
+    entropy_health_cast()?;
+    pbkdf2_cast()?;
     Ok(())
 }
@@ -0,0 +1,383 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/composite.rs
+//! Composite ML-DSA signatures (draft-ietf-lamps-pq-composite-sigs):
+//! `id-MLDSA65-ECDSA-P256-SHA512` and `id-MLDSA65-Ed25519-SHA512`.
+//!
+//! Both components sign the message representative
+//! `M' = Prefix || Label || len(ctx) || ctx || SHA-512(M)`; ML-DSA-65 signs it
+//! with the Label as its context string. A composite signature verifies
+//! only if both component signatures do.
+//!
+//! Encodings are concatenations with the ML-DSA-65 part first:
+//!
+//! - public key: ML-DSA-65 public key || traditional public key
+//! - private key: ML-DSA-65 seed (32 bytes) || traditional private key
+//! - signature: ML-DSA-65 signature || traditional signature
+//!
+//! The ECDSA public key is an uncompressed point, its private key an
+//! RFC 5915 `ECPrivateKey` without the optional fields and its signature
+//! a DER `Ecdsa-Sig-Value` (RFC 6979 deterministic, SHA-256). Ed25519 keys
+//! and signatures are the raw RFC 8032 encodings.
+
+use crate::error::{PqcError, Result};
+use crate::{
+    dilithium_generate_key_pair_internal, dilithium_sign_internal, dilithium_verify_internal,
+    DilithiumSignature, DilithiumSigningKey, DilithiumVerifyingKey, ML_DSA_65_PK_BYTES,
+    ML_DSA_65_SIG_BYTES,
+};
+use p256::ecdsa::signature::{Signer, Verifier};
+use sha2::{Digest, Sha512};
+use zeroize::Zeroize;
+
+/// Prefix of every composite message representative.
+const PREFIX: &[u8; 32] = b"CompositeAlgorithmSignatures2025";
+
+/// Largest message representative: Prefix, Label, len(ctx), ctx and the digest.
+const MAX_M_PRIME_BYTES: usize = 32 + 40 + 1 + 255 + 64;
+
+/// DER header of an `ECPrivateKey` holding only version 1 and a P-256 scalar.
+const EC_PRIVATE_KEY_HEADER: [u8; 7] = [0x30, 0x25, 0x02, 0x01, 0x01, 0x04, 0x20];
+
+/// Builds `M' = Prefix || Label || len(ctx) || ctx || SHA-512(M)`.
+fn message_representative<'a>(
+    buf: &'a mut [u8; MAX_M_PRIME_BYTES],
+    label: &[u8],
+    ctx: &[u8],
+    msg: &[u8],
+) -> Result<&'a [u8]> {
+    if ctx.len() > 255 {
+        return Err(PqcError::InvalidParameter);
+    }
+    let digest = Sha512::digest(msg);
+    let mut len = 0;
+    for part in [&PREFIX[..], label, &[ctx.len() as u8], ctx, &digest[..]] {
+        buf[len..len + part.len()].copy_from_slice(part);
+        len += part.len();
+    }
+    Ok(&buf[..len])
+}
+
+/// Derives an ECDSA P-256 key pair from a 32-byte scalar.
+fn ecdsa_p256_keygen(seed: &[u8; 32]) -> Result<([u8; 39], [u8; 65])> {
+    let sk = p256::ecdsa::SigningKey::from_slice(seed).map_err(|_| PqcError::InvalidParameter)?;
+    let mut sk_bytes = [0u8; 39];
+    sk_bytes[..7].copy_from_slice(&EC_PRIVATE_KEY_HEADER);
+    sk_bytes[7..].copy_from_slice(&sk.to_bytes());
+    let mut pk_bytes = [0u8; 65];
+    pk_bytes.copy_from_slice(sk.verifying_key().to_encoded_point(false).as_bytes());
+    Ok((sk_bytes, pk_bytes))
+}
+
+/// Signs `m` with ECDSA P-256 and SHA-256, writing the DER signature to `out`.
+fn ecdsa_p256_sign(sk: &[u8; 39], m: &[u8], out: &mut [u8; 72]) -> Result<usize> {
+    if sk[..7] != EC_PRIVATE_KEY_HEADER {
+        return Err(PqcError::InvalidParameter);
+    }
+    let sk =
+        p256::ecdsa::SigningKey::from_slice(&sk[7..]).map_err(|_| PqcError::InvalidParameter)?;
+    let sig: p256::ecdsa::Signature = sk.try_sign(m).map_err(|_| PqcError::FipsErrorState)?;
+    let der = sig.to_der();
+    out[..der.len()].copy_from_slice(der.as_bytes());
+    Ok(der.len())
+}
+
+/// Verifies a DER ECDSA P-256 signature; `Ok(false)` means it does not verify.
+fn ecdsa_p256_verify(pk: &[u8; 65], m: &[u8], sig: &[u8]) -> Result<bool> {
+    if pk[0] != 0x04 {
+        return Err(PqcError::InvalidParameter);
+    }
+    let vk =
+        p256::ecdsa::VerifyingKey::from_sec1_bytes(pk).map_err(|_| PqcError::InvalidParameter)?;
+    let sig = p256::ecdsa::Signature::from_der(sig).map_err(|_| PqcError::InvalidParameter)?;
+    Ok(vk.verify(m, &sig).is_ok())
+}
+
+/// Derives an Ed25519 key pair from its 32-byte private key.
+fn ed25519_keygen(seed: &[u8; 32]) -> Result<([u8; 32], [u8; 32])> {
+    let sk = ed25519_dalek::SigningKey::from_bytes(seed);
+    Ok((*seed, sk.verifying_key().to_bytes()))
+}
+
+/// Signs `m` with Ed25519, writing the signature to `out`.
+fn ed25519_sign(sk: &[u8; 32], m: &[u8], out: &mut [u8; 64]) -> Result<usize> {
+    let sk = ed25519_dalek::SigningKey::from_bytes(sk);
+    out.copy_from_slice(&sk.sign(m).to_bytes());
+    Ok(64)
+}
+
+/// Verifies an Ed25519 signature; `Ok(false)` means it does not verify.
+fn ed25519_verify(pk: &[u8; 32], m: &[u8], sig: &[u8]) -> Result<bool> {
+    let vk = ed25519_dalek::VerifyingKey::from_bytes(pk).map_err(|_| PqcError::InvalidParameter)?;
+    let sig = ed25519_dalek::Signature::from_slice(sig).map_err(|_| PqcError::InvalidParameter)?;
+    Ok(vk.verify_strict(m, &sig).is_ok())
+}
+
+/// Generates the types and operations for one composite algorithm.
+macro_rules! composite_algorithm {
+    (
+        $name:literal, $label:literal,
+        $sk:ident, $vk:ident, $sig:ident, $kp:ident,
+        $keygen:ident, $sign:ident, $verify:ident,
+        $trad_keygen:ident, $trad_sign:ident, $trad_verify:ident,
+        $trad_sk_bytes:literal, $trad_pk_bytes:literal, $trad_sig_min:literal, $trad_sig_max:literal,
+        $sk_bytes:path, $pk_bytes:path, $sig_max_bytes:path
+    ) => {
+        #[doc = concat!($name, " signing key (ML-DSA-65 seed || traditional private key).")]
+        pub struct $sk {
+            encoded: [u8; $sk_bytes],
+            mldsa: DilithiumSigningKey,
+        }
+
+        #[doc = concat!($name, " verification key (ML-DSA-65 public key || traditional public key).")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $vk([u8; $pk_bytes]);
+
+        #[doc = concat!($name, " signature (ML-DSA-65 signature || traditional signature).")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $sig {
+            bytes: [u8; $sig_max_bytes],
+            len: usize,
+        }
+
+        #[doc = concat!($name, " key pair.")]
+        pub struct $kp {
+            /// Signing key.
+            pub signing_key: $sk,
+            /// Verification key.
+            pub verification_key: $vk,
+        }
+
+        impl $sk {
+            /// Size of the encoded key in bytes.
+            pub const SIZE: usize = $sk_bytes;
+
+            /// Returns the encoded key.
+            pub fn as_slice(&self) -> &[u8] {
+                &self.encoded
+            }
+        }
+
+        impl TryFrom<&[u8]> for $sk {
+            type Error = PqcError;
+
+            /// Decodes a signing key, re-deriving the ML-DSA-65 key from its seed.
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                let encoded: [u8; $sk_bytes] =
+                    bytes.try_into().map_err(|_| PqcError::InvalidParameter)?;
+                let mut seed = [0u8; 32];
+                seed.copy_from_slice(&encoded[..32]);
+                let kp = dilithium_generate_key_pair_internal(seed);
+                seed.zeroize();
+                Ok(Self {
+                    encoded,
+                    mldsa: kp.signing_key,
+                })
+            }
+        }
+
+        impl Drop for $sk {
+            fn drop(&mut self) {
+                self.encoded.zeroize();
+                self.mldsa.as_mut_slice().zeroize();
+            }
+        }
+
+        impl $vk {
+            /// Size of the encoded key in bytes.
+            pub const SIZE: usize = $pk_bytes;
+
+            /// Wraps an encoded verification key.
+            pub fn new(bytes: [u8; $pk_bytes]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns the encoded key.
+            pub fn as_slice(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $vk {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $vk {
+            type Error = PqcError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                bytes
+                    .try_into()
+                    .map(Self)
+                    .map_err(|_| PqcError::InvalidParameter)
+            }
+        }
+
+        impl $sig {
+            /// Largest encoded signature in bytes.
+            pub const MAX_SIZE: usize = $sig_max_bytes;
+
+            /// Returns the encoded signature.
+            pub fn as_slice(&self) -> &[u8] {
+                &self.bytes[..self.len]
+            }
+        }
+
+        impl AsRef<[u8]> for $sig {
+            fn as_ref(&self) -> &[u8] {
+                self.as_slice()
+            }
+        }
+
+        impl TryFrom<&[u8]> for $sig {
+            type Error = PqcError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                if bytes.len() < ML_DSA_65_SIG_BYTES + $trad_sig_min || bytes.len() > $sig_max_bytes {
+                    return Err(PqcError::InvalidParameter);
+                }
+                let mut sig = Self {
+                    bytes: [0u8; $sig_max_bytes],
+                    len: bytes.len(),
+                };
+                sig.bytes[..bytes.len()].copy_from_slice(bytes);
+                Ok(sig)
+            }
+        }
+
+        #[doc = concat!("Generates a ", $name, " key pair from the ML-DSA-65 seed followed by the")]
+        /// 32-byte traditional private key (Unauthenticated).
+        pub fn $keygen(mut seed: [u8; 64]) -> Result<$kp> {
+            let mut mldsa_seed = [0u8; 32];
+            mldsa_seed.copy_from_slice(&seed[..32]);
+            let mut trad_seed = [0u8; 32];
+            trad_seed.copy_from_slice(&seed[32..]);
+            seed.zeroize();
+            let trad = $trad_keygen(&trad_seed);
+            trad_seed.zeroize();
+            let (mut trad_sk, trad_pk) = trad?;
+
+            let mut encoded = [0u8; $sk_bytes];
+            encoded[..32].copy_from_slice(&mldsa_seed);
+            encoded[32..].copy_from_slice(&trad_sk);
+            trad_sk.zeroize();
+            let kp = dilithium_generate_key_pair_internal(mldsa_seed);
+            mldsa_seed.zeroize();
+
+            let mut pk = [0u8; $pk_bytes];
+            pk[..ML_DSA_65_PK_BYTES].copy_from_slice(kp.verification_key.as_slice());
+            pk[ML_DSA_65_PK_BYTES..].copy_from_slice(&trad_pk);
+            Ok($kp {
+                signing_key: $sk {
+                    encoded,
+                    mldsa: kp.signing_key,
+                },
+                verification_key: $vk(pk),
+            })
+        }
+
+        #[doc = concat!("Signs a message with ", $name, " (Unauthenticated).")]
+        ///
+        /// `randomness` hedges the ML-DSA-65 component.
+        pub fn $sign(sk: &$sk, msg: &[u8], ctx: &[u8], randomness: [u8; 32]) -> Result<$sig> {
+            let mut buf = [0u8; MAX_M_PRIME_BYTES];
+            let m_prime = message_representative(&mut buf, $label, ctx, msg)?;
+            let mldsa_sig = dilithium_sign_internal(&sk.mldsa, m_prime, $label, randomness)
+                .map_err(|_| PqcError::FipsErrorState)?;
+
+            let mut trad_sk = [0u8; $trad_sk_bytes];
+            trad_sk.copy_from_slice(&sk.encoded[32..]);
+            let mut trad_sig = [0u8; $trad_sig_max];
+            let trad_len = $trad_sign(&trad_sk, m_prime, &mut trad_sig);
+            trad_sk.zeroize();
+            let trad_len = trad_len?;
+
+            let mut sig = $sig {
+                bytes: [0u8; $sig_max_bytes],
+                len: ML_DSA_65_SIG_BYTES + trad_len,
+            };
+            sig.bytes[..ML_DSA_65_SIG_BYTES].copy_from_slice(mldsa_sig.as_slice());
+            sig.bytes[ML_DSA_65_SIG_BYTES..sig.len].copy_from_slice(&trad_sig[..trad_len]);
+            Ok(sig)
+        }
+
+        #[doc = concat!("Verifies a ", $name, " signature (Unauthenticated).")]
+        ///
+        /// Both component signatures are always checked and both must verify.
+        pub fn $verify(pk: &$vk, msg: &[u8], ctx: &[u8], sig: &$sig) -> Result<()> {
+            let mut buf = [0u8; MAX_M_PRIME_BYTES];
+            let m_prime = message_representative(&mut buf, $label, ctx, msg)?;
+
+            let mut mldsa_pk = [0u8; ML_DSA_65_PK_BYTES];
+            mldsa_pk.copy_from_slice(&pk.0[..ML_DSA_65_PK_BYTES]);
+            let mut trad_pk = [0u8; $trad_pk_bytes];
+            trad_pk.copy_from_slice(&pk.0[ML_DSA_65_PK_BYTES..]);
+            let mut mldsa_sig = [0u8; ML_DSA_65_SIG_BYTES];
+            mldsa_sig.copy_from_slice(&sig.bytes[..ML_DSA_65_SIG_BYTES]);
+
+            let mldsa_ok = dilithium_verify_internal(
+                &DilithiumVerifyingKey::new(mldsa_pk),
+                m_prime,
+                $label,
+                &DilithiumSignature::new(mldsa_sig),
+            )
+            .is_ok();
+            let trad_ok = $trad_verify(&trad_pk, m_prime, &sig.bytes[ML_DSA_65_SIG_BYTES..sig.len])?;
+            if mldsa_ok & trad_ok {
+                Ok(())
+            } else {
+                Err(PqcError::FipsErrorState)
+            }
+        }
+    };
+}
+
+composite_algorithm!(
+    "MLDSA65-ECDSA-P256-SHA512",
+    b"COMPSIG-MLDSA65-ECDSA-P256-SHA512",
+    MlDsa65EcdsaP256SigningKey,
+    MlDsa65EcdsaP256VerifyingKey,
+    MlDsa65EcdsaP256Signature,
+    MlDsa65EcdsaP256Keypair,
+    generate_key_pair_mldsa65_ecdsa_p256,
+    sign_mldsa65_ecdsa_p256,
+    verify_mldsa65_ecdsa_p256,
+    ecdsa_p256_keygen,
+    ecdsa_p256_sign,
+    ecdsa_p256_verify,
+    39,
+    65,
+    8,
+    72,
+    crate::MLDSA65_ECDSA_P256_SK_BYTES,
+    crate::MLDSA65_ECDSA_P256_PK_BYTES,
+    crate::MLDSA65_ECDSA_P256_SIG_MAX_BYTES
+);
+
+composite_algorithm!(
+    "MLDSA65-Ed25519-SHA512",
+    b"COMPSIG-MLDSA65-Ed25519-SHA512",
+    MlDsa65Ed25519SigningKey,
+    MlDsa65Ed25519VerifyingKey,
+    MlDsa65Ed25519Signature,
+    MlDsa65Ed25519Keypair,
+    generate_key_pair_mldsa65_ed25519,
+    sign_mldsa65_ed25519,
+    verify_mldsa65_ed25519,
+    ed25519_keygen,
+    ed25519_sign,
+    ed25519_verify,
+    32,
+    32,
+    64,
+    64,
+    crate::MLDSA65_ED25519_SK_BYTES,
+    crate::MLDSA65_ED25519_PK_BYTES,
+    crate::MLDSA65_ED25519_SIG_BYTES
+);
@@ -5,16 +5,50 @@
 // License: MIT (publicly auditable for FIPS/CMVP verification)
 // Contact: aaronschnacky@gmail.com
 // src/csp.rs – FINAL
+#[cfg(any(
+    feature = "ml-kem",
+    feature = "ml-kem-512",
+    feature = "ml-kem-768",
+    feature = "ml-dsa",
+    feature = "ml-dsa-44",
+    feature = "ml-dsa-87",
+    feature = "slh-dsa"
+))]
 use crate::error::{PqcError, Result};
-#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+#[cfg(any(
+    feature = "ml-kem",
+    feature = "ml-kem-512",
+    feature = "ml-kem-768",
+    feature = "ml-dsa",
+    feature = "ml-dsa-44",
+    feature = "ml-dsa-87",
+    feature = "slh-dsa"
+))]
 use crate::state::check_operational;
 
 #[cfg(feature = "ml-kem")]
-use crate::{KyberSecretKey, KyberSharedSecret};
+use crate::KyberSecretKey;
+#[cfg(any(feature = "ml-kem", feature = "ml-kem-512", feature = "ml-kem-768"))]
+use crate::KyberSharedSecret;
+
+#[cfg(feature = "ml-kem-768")]
+use crate::Kyber768PrivateKey;
+
+#[cfg(feature = "ml-kem-512")]
+use crate::Kyber512PrivateKey;
 
 #[cfg(feature = "ml-dsa")]
 use crate::DilithiumSigningKey;
 
+#[cfg(feature = "ml-dsa-44")]
+use crate::Dilithium44SigningKey;
+
+#[cfg(feature = "ml-dsa-87")]
+use crate::Dilithium87SigningKey;
+
+#[cfg(feature = "slh-dsa")]
+use crate::{SlhDsaShake128sSigningKey, SlhDsaShake256sSigningKey};
+
 /// Policy for exporting Critical Security Parameters (CSPs).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CspExportPolicy {
@@ -36,6 +70,16 @@ pub fn get_csp_export_policy() -> CspExportPolicy {
     }
 }
 
+// Unused when only verify-only features such as `lms` are enabled.
+#[cfg(any(
+    feature = "ml-kem",
+    feature = "ml-kem-512",
+    feature = "ml-kem-768",
+    feature = "ml-dsa",
+    feature = "ml-dsa-44",
+    feature = "ml-dsa-87",
+    feature = "slh-dsa"
+))]
 fn export_blocked() -> Result<()> {
     if matches!(get_csp_export_policy(), CspExportPolicy::BlockPlaintext) {
         Err(PqcError::CspExportBlocked)
@@ -52,6 +96,22 @@ pub fn guard_kyber_sk_export(_sk: &KyberSecretKey) -> Result<&[u8]> {
     unreachable!()
 }
 
+/// Guards the export of an ML-KEM-768 secret key.
+#[cfg(feature = "ml-kem-768")]
+pub fn guard_kyber768_sk_export(_sk: &Kyber768PrivateKey) -> Result<&[u8]> {
+    check_operational()?;
+    export_blocked()?;
+    unreachable!()
+}
+
+/// Guards the export of an ML-KEM-512 secret key.
+#[cfg(feature = "ml-kem-512")]
+pub fn guard_kyber512_sk_export(_sk: &Kyber512PrivateKey) -> Result<&[u8]> {
+    check_operational()?;
+    export_blocked()?;
+    unreachable!()
+}
+
 /// Guards the export of a Dilithium signing key.
 #[cfg(feature = "ml-dsa")]
 pub fn guard_dilithium_sk_export(_sk: &DilithiumSigningKey) -> Result<&[u8]> {
@@ -60,8 +120,40 @@ pub fn guard_dilithium_sk_export(_sk: &DilithiumSigningKey) -> Result<&[u8]> {
     unreachable!()
 }
 
+/// Guards the export of an ML-DSA-44 signing key.
+#[cfg(feature = "ml-dsa-44")]
+pub fn guard_dilithium44_sk_export(_sk: &Dilithium44SigningKey) -> Result<&[u8]> {
+    check_operational()?;
+    export_blocked()?;
+    unreachable!()
+}
+
+/// Guards the export of an ML-DSA-87 signing key.
+#[cfg(feature = "ml-dsa-87")]
+pub fn guard_dilithium87_sk_export(_sk: &Dilithium87SigningKey) -> Result<&[u8]> {
+    check_operational()?;
+    export_blocked()?;
+    unreachable!()
+}
+
+/// Guards the export of an SLH-DSA-SHAKE-128s signing key.
+#[cfg(feature = "slh-dsa")]
+pub fn guard_slh_dsa_shake_128s_sk_export(_sk: &SlhDsaShake128sSigningKey) -> Result<&[u8]> {
+    check_operational()?;
+    export_blocked()?;
+    unreachable!()
+}
+
+/// Guards the export of an SLH-DSA-SHAKE-256s signing key.
+#[cfg(feature = "slh-dsa")]
+pub fn guard_slh_dsa_shake_256s_sk_export(_sk: &SlhDsaShake256sSigningKey) -> Result<&[u8]> {
+    check_operational()?;
+    export_blocked()?;
+    unreachable!()
+}
+
 /// Guards the export of a Kyber shared secret.
-#[cfg(feature = "ml-kem")]
+#[cfg(any(feature = "ml-kem", feature = "ml-kem-512", feature = "ml-kem-768"))]
 pub fn guard_shared_secret_export(_ss: &KyberSharedSecret) -> Result<&[u8]> {
     check_operational()?;
     export_blocked()?;
@@ -0,0 +1,446 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/drbg.rs
+//! SP 800-90A Deterministic Random Bit Generators (DRBGs).
+//!
+//! [`HmacDrbg`] implements HMAC_DRBG with HMAC-SHA-256 and, with the
+//! `ctr-drbg` feature, `CtrDrbg` implements CTR_DRBG with AES-256 and the
+//! block cipher derivation function. Both have 256-bit security strength and
+//! no prediction resistance. The caller supplies entropy input from its
+//! entropy source; the DRBG state lives in the caller's value and is zeroized
+//! on drop.
+
+use crate::error::{PqcError, Result};
+use crate::module::FipsModule;
+#[cfg(feature = "ctr-drbg")]
+use aes::cipher::{BlockEncrypt, KeyInit};
+#[cfg(feature = "ctr-drbg")]
+use aes::Aes256;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Minimum entropy input length in bytes (the 256-bit security strength).
+pub const MIN_ENTROPY_BYTES: usize = 32;
+/// Minimum nonce length in bytes (half the security strength).
+pub const MIN_NONCE_BYTES: usize = 16;
+/// Maximum number of bytes returned by one generate request (2^19 bits).
+pub const MAX_BYTES_PER_REQUEST: usize = 1 << 16;
+/// Number of generate requests allowed between reseeds.
+pub const RESEED_INTERVAL: u64 = 1 << 16;
+
+/// An SP 800-90A DRBG mechanism.
+///
+/// Every DRBG the module exposes implements this trait, so services that
+/// draw randomness (e.g. [`crate::kyber_generate_key_pair_with_drbg`]) work
+/// with any of them. The provided methods run on [`FipsModule::global`]; use
+/// [`FipsModule::drbg_instantiate`] and friends for another module.
+pub trait Drbg: sealed::DrbgCore {
+    /// Instantiates the DRBG (Authenticated).
+    ///
+    /// `entropy_input` must hold at least [`MIN_ENTROPY_BYTES`] of full-entropy
+    /// data and `nonce` at least [`MIN_NONCE_BYTES`].
+    fn instantiate(entropy_input: &[u8], nonce: &[u8], personalization: &[u8]) -> Result<Self> {
+        FipsModule::global().drbg_instantiate(entropy_input, nonce, personalization)
+    }
+
+    /// Reseeds the DRBG with fresh entropy input (Authenticated).
+    fn reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<()> {
+        FipsModule::global().drbg_reseed(self, entropy_input, additional_input)
+    }
+
+    /// Fills `out` with pseudorandom bytes (Authenticated).
+    ///
+    /// Returns [`PqcError::DrbgReseedRequired`] once [`RESEED_INTERVAL`]
+    /// requests have been served since the last (re)seed.
+    fn generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<()> {
+        FipsModule::global().drbg_generate(self, out, additional_input)
+    }
+}
+
+mod sealed {
+    use crate::error::Result;
+
+    /// The unchecked DRBG functions behind [`super::Drbg`].
+    pub trait DrbgCore: Sized {
+        fn core_instantiate(
+            entropy_input: &[u8],
+            nonce: &[u8],
+            personalization: &[u8],
+        ) -> Result<Self>;
+        fn core_reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<()>;
+        fn core_generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<()>;
+    }
+}
+
+impl FipsModule {
+    /// Instantiates a DRBG (Authenticated).
+    ///
+    /// See [`Drbg::instantiate`].
+    pub fn drbg_instantiate<D: Drbg>(
+        &self,
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization: &[u8],
+    ) -> Result<D> {
+        self.begin_service(true)?;
+        D::core_instantiate(entropy_input, nonce, personalization)
+    }
+
+    /// Reseeds a DRBG with fresh entropy input (Authenticated).
+    pub fn drbg_reseed<D: Drbg>(
+        &self,
+        drbg: &mut D,
+        entropy_input: &[u8],
+        additional_input: &[u8],
+    ) -> Result<()> {
+        self.begin_service(true)?;
+        drbg.core_reseed(entropy_input, additional_input)
+    }
+
+    /// Fills `out` with pseudorandom bytes from a DRBG (Authenticated).
+    ///
+    /// See [`Drbg::generate`].
+    pub fn drbg_generate<D: Drbg>(
+        &self,
+        drbg: &mut D,
+        out: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<()> {
+        self.begin_service(true)?;
+        drbg.core_generate(out, additional_input)
+    }
+}
+
+/// HMAC_DRBG with HMAC-SHA-256 (SP 800-90A, Section 10.1.2).
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct HmacDrbg {
+    k: [u8; 32],
+    v: [u8; 32],
+    reseed_counter: u64,
+}
+
+impl HmacDrbg {
+    fn mac(&self) -> HmacSha256 {
+        <HmacSha256 as Mac>::new_from_slice(&self.k).expect("HMAC accepts any key length")
+    }
+
+    /// HMAC_DRBG_Update; `provided_data` is the concatenation of its parts.
+    fn update(&mut self, provided_data: &[&[u8]]) {
+        let empty = provided_data.iter().all(|part| part.is_empty());
+        for round in [0x00u8, 0x01] {
+            let mut mac = self.mac();
+            mac.update(&self.v);
+            mac.update(&[round]);
+            for part in provided_data {
+                mac.update(part);
+            }
+            self.k = mac.finalize().into_bytes().into();
+            let mut mac = self.mac();
+            mac.update(&self.v);
+            self.v = mac.finalize().into_bytes().into();
+            if empty {
+                break;
+            }
+        }
+    }
+
+    /// Instantiate function without the state and role checks, for the CAST.
+    pub(crate) fn instantiate_unchecked(
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization: &[u8],
+    ) -> Result<Self> {
+        if entropy_input.len() < MIN_ENTROPY_BYTES || nonce.len() < MIN_NONCE_BYTES {
+            return Err(PqcError::InvalidParameter);
+        }
+        let mut drbg = Self {
+            k: [0x00; 32],
+            v: [0x01; 32],
+            reseed_counter: 1,
+        };
+        drbg.update(&[entropy_input, nonce, personalization]);
+        Ok(drbg)
+    }
+
+    /// Reseed function without the state and role checks, for the CAST.
+    pub(crate) fn reseed_unchecked(
+        &mut self,
+        entropy_input: &[u8],
+        additional_input: &[u8],
+    ) -> Result<()> {
+        if entropy_input.len() < MIN_ENTROPY_BYTES {
+            return Err(PqcError::InvalidParameter);
+        }
+        self.update(&[entropy_input, additional_input]);
+        self.reseed_counter = 1;
+        Ok(())
+    }
+
+    /// Generate function without the state and role checks, for the CAST.
+    pub(crate) fn generate_unchecked(
+        &mut self,
+        out: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<()> {
+        if out.len() > MAX_BYTES_PER_REQUEST {
+            return Err(PqcError::InvalidParameter);
+        }
+        if self.reseed_counter > RESEED_INTERVAL {
+            return Err(PqcError::DrbgReseedRequired);
+        }
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+        for chunk in out.chunks_mut(32) {
+            let mut mac = self.mac();
+            mac.update(&self.v);
+            self.v = mac.finalize().into_bytes().into();
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[additional_input]);
+        self.reseed_counter += 1;
+        Ok(())
+    }
+}
+
+impl sealed::DrbgCore for HmacDrbg {
+    fn core_instantiate(
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization: &[u8],
+    ) -> Result<Self> {
+        Self::instantiate_unchecked(entropy_input, nonce, personalization)
+    }
+
+    fn core_reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<()> {
+        self.reseed_unchecked(entropy_input, additional_input)
+    }
+
+    fn core_generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<()> {
+        self.generate_unchecked(out, additional_input)
+    }
+}
+
+impl Drbg for HmacDrbg {}
+
+/// AES block length in bytes (outlen).
+#[cfg(feature = "ctr-drbg")]
+const AES_BLOCK: usize = 16;
+/// AES-256 key length in bytes (keylen).
+#[cfg(feature = "ctr-drbg")]
+const AES_KEY: usize = 32;
+/// CTR_DRBG seed length in bytes (seedlen = keylen + outlen).
+#[cfg(feature = "ctr-drbg")]
+const SEED_LEN: usize = AES_KEY + AES_BLOCK;
+
+/// Streams input blocks through BCC (SP 800-90A, Section 10.3.3).
+#[cfg(feature = "ctr-drbg")]
+struct Bcc<'a> {
+    cipher: &'a Aes256,
+    chaining_value: [u8; AES_BLOCK],
+    block: [u8; AES_BLOCK],
+    filled: usize,
+}
+
+#[cfg(feature = "ctr-drbg")]
+impl<'a> Bcc<'a> {
+    fn new(cipher: &'a Aes256) -> Self {
+        Self {
+            cipher,
+            chaining_value: [0u8; AES_BLOCK],
+            block: [0u8; AES_BLOCK],
+            filled: 0,
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let take = (AES_BLOCK - self.filled).min(data.len());
+            self.block[self.filled..self.filled + take].copy_from_slice(&data[..take]);
+            self.filled += take;
+            data = &data[take..];
+            if self.filled == AES_BLOCK {
+                for (c, b) in self.chaining_value.iter_mut().zip(self.block.iter()) {
+                    *c ^= b;
+                }
+                self.cipher.encrypt_block((&mut self.chaining_value).into());
+                self.filled = 0;
+            }
+        }
+    }
+
+    /// Returns the chaining value; the input must be a whole number of blocks.
+    fn finalize(mut self) -> [u8; AES_BLOCK] {
+        debug_assert_eq!(self.filled, 0);
+        self.block.zeroize();
+        self.chaining_value
+    }
+}
+
+/// Block_Cipher_df (SP 800-90A, Section 10.3.2) with a seedlen-byte output.
+/// `input` is the concatenation of its parts.
+#[cfg(feature = "ctr-drbg")]
+fn block_cipher_df(input: &[&[u8]]) -> [u8; SEED_LEN] {
+    let len: usize = input.iter().map(|part| part.len()).sum();
+    let pad = (AES_BLOCK - (8 + len + 1) % AES_BLOCK) % AES_BLOCK;
+
+    let mut key = [0u8; AES_KEY];
+    for (i, k) in key.iter_mut().enumerate() {
+        *k = i as u8;
+    }
+    let cipher = Aes256::new(&key.into());
+    let mut temp = [0u8; SEED_LEN];
+    for (i, chunk) in temp.chunks_exact_mut(AES_BLOCK).enumerate() {
+        let mut iv = [0u8; AES_BLOCK];
+        iv[..4].copy_from_slice(&(i as u32).to_be_bytes());
+        let mut bcc = Bcc::new(&cipher);
+        bcc.update(&iv);
+        bcc.update(&(len as u32).to_be_bytes());
+        bcc.update(&(SEED_LEN as u32).to_be_bytes());
+        for part in input {
+            bcc.update(part);
+        }
+        bcc.update(&[0x80]);
+        bcc.update(&[0u8; AES_BLOCK][..pad]);
+        chunk.copy_from_slice(&bcc.finalize());
+    }
+
+    key.copy_from_slice(&temp[..AES_KEY]);
+    let cipher = Aes256::new(&key.into());
+    let mut x = [0u8; AES_BLOCK];
+    x.copy_from_slice(&temp[AES_KEY..]);
+    for chunk in temp.chunks_exact_mut(AES_BLOCK) {
+        cipher.encrypt_block((&mut x).into());
+        chunk.copy_from_slice(&x);
+    }
+    key.zeroize();
+    x.zeroize();
+    temp
+}
+
+/// CTR_DRBG with AES-256 and the derivation function (SP 800-90A,
+/// Section 10.2.1).
+#[cfg(feature = "ctr-drbg")]
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct CtrDrbg {
+    key: [u8; AES_KEY],
+    v: [u8; AES_BLOCK],
+    reseed_counter: u64,
+}
+
+#[cfg(feature = "ctr-drbg")]
+impl CtrDrbg {
+    /// Encrypts successive counter blocks V + 1, V + 2, ... into `out`.
+    fn ctr(&mut self, cipher: &Aes256, out: &mut [u8]) {
+        for chunk in out.chunks_mut(AES_BLOCK) {
+            self.v = (u128::from_be_bytes(self.v).wrapping_add(1)).to_be_bytes();
+            let mut block = self.v;
+            cipher.encrypt_block((&mut block).into());
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            block.zeroize();
+        }
+    }
+
+    /// CTR_DRBG_Update (SP 800-90A, Section 10.2.1.2).
+    fn update(&mut self, provided_data: &[u8; SEED_LEN]) {
+        let cipher = Aes256::new(&self.key.into());
+        let mut temp = [0u8; SEED_LEN];
+        self.ctr(&cipher, &mut temp);
+        for (t, p) in temp.iter_mut().zip(provided_data.iter()) {
+            *t ^= p;
+        }
+        self.key.copy_from_slice(&temp[..AES_KEY]);
+        self.v.copy_from_slice(&temp[AES_KEY..]);
+        temp.zeroize();
+    }
+
+    /// Instantiate function without the state and role checks, for the CAST.
+    pub(crate) fn instantiate_unchecked(
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization: &[u8],
+    ) -> Result<Self> {
+        if entropy_input.len() < MIN_ENTROPY_BYTES || nonce.len() < MIN_NONCE_BYTES {
+            return Err(PqcError::InvalidParameter);
+        }
+        let mut seed_material = block_cipher_df(&[entropy_input, nonce, personalization]);
+        let mut drbg = Self {
+            key: [0u8; AES_KEY],
+            v: [0u8; AES_BLOCK],
+            reseed_counter: 1,
+        };
+        drbg.update(&seed_material);
+        seed_material.zeroize();
+        Ok(drbg)
+    }
+
+    /// Reseed function without the state and role checks, for the CAST.
+    pub(crate) fn reseed_unchecked(
+        &mut self,
+        entropy_input: &[u8],
+        additional_input: &[u8],
+    ) -> Result<()> {
+        if entropy_input.len() < MIN_ENTROPY_BYTES {
+            return Err(PqcError::InvalidParameter);
+        }
+        let mut seed_material = block_cipher_df(&[entropy_input, additional_input]);
+        self.update(&seed_material);
+        seed_material.zeroize();
+        self.reseed_counter = 1;
+        Ok(())
+    }
+
+    /// Generate function without the state and role checks, for the CAST.
+    pub(crate) fn generate_unchecked(
+        &mut self,
+        out: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<()> {
+        if out.len() > MAX_BYTES_PER_REQUEST {
+            return Err(PqcError::InvalidParameter);
+        }
+        if self.reseed_counter > RESEED_INTERVAL {
+            return Err(PqcError::DrbgReseedRequired);
+        }
+        let mut additional = [0u8; SEED_LEN];
+        if !additional_input.is_empty() {
+            additional = block_cipher_df(&[additional_input]);
+            self.update(&additional);
+        }
+        let cipher = Aes256::new(&self.key.into());
+        self.ctr(&cipher, out);
+        self.update(&additional);
+        additional.zeroize();
+        self.reseed_counter += 1;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ctr-drbg")]
+impl sealed::DrbgCore for CtrDrbg {
+    fn core_instantiate(
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization: &[u8],
+    ) -> Result<Self> {
+        Self::instantiate_unchecked(entropy_input, nonce, personalization)
+    }
+
+    fn core_reseed(&mut self, entropy_input: &[u8], additional_input: &[u8]) -> Result<()> {
+        self.reseed_unchecked(entropy_input, additional_input)
+    }
+
+    fn core_generate(&mut self, out: &mut [u8], additional_input: &[u8]) -> Result<()> {
+        self.generate_unchecked(out, additional_input)
+    }
+}
+
+#[cfg(feature = "ctr-drbg")]
+impl Drbg for CtrDrbg {}
@@ -0,0 +1,276 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/ecdh_mlkem.rs
+//! ECDHE-MLKEM hybrid key exchange for TLS 1.3 (draft-ietf-tls-ecdhe-mlkem):
+//! `SecP256r1MLKEM768` and `SecP384r1MLKEM1024`.
+//!
+//! Both components are Approved algorithms, so these groups stay usable in
+//! Approved mode where X25519 is not. All byte layouts are the TLS wire
+//! format and are concatenations with the ECDH part first:
+//!
+//! - client `key_share`: uncompressed ECDH point || ML-KEM encapsulation key
+//! - server `key_share`: uncompressed ECDH point || ML-KEM ciphertext
+//! - shared secret: ECDH x-coordinate || ML-KEM shared secret
+//!
+//! The private key encoding is the ECDH scalar followed by the ML-KEM
+//! decapsulation key.
+
+use crate::error::{PqcError, Result};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Generates the types and operations for one ECDHE-MLKEM group.
+macro_rules! ecdh_mlkem_group {
+    (
+        $name:literal, $curve:ident,
+        $ml_keygen:path, $ml_encaps:path, $ml_decaps:path, $ml_validate:path,
+        $sk:ident, $client:ident, $server:ident, $ss:ident, $kp:ident,
+        $keygen:ident, $encaps:ident, $decaps:ident,
+        $ml_pk:ty, $ml_sk:ty, $ml_ct:ty,
+        $scalar_bytes:literal, $ml_pk_bytes:path, $ml_sk_bytes:path, $ml_ct_bytes:path,
+        $client_bytes:path, $server_bytes:path, $sk_bytes:path, $ss_bytes:path
+    ) => {
+        #[doc = concat!($name, " shared secret (ECDH x-coordinate || ML-KEM shared secret).")]
+        pub type $ss = [u8; $ss_bytes];
+
+        #[doc = concat!($name, " private key (ECDH scalar || ML-KEM decapsulation key).")]
+        #[derive(Clone, Zeroize, ZeroizeOnDrop)]
+        pub struct $sk([u8; $sk_bytes]);
+
+        #[doc = concat!($name, " client `key_share` (ECDH point || ML-KEM encapsulation key).")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $client([u8; $client_bytes]);
+
+        #[doc = concat!($name, " server `key_share` (ECDH point || ML-KEM ciphertext).")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $server([u8; $server_bytes]);
+
+        #[doc = concat!($name, " key pair.")]
+        #[derive(Clone)]
+        pub struct $kp {
+            /// Private key.
+            pub private_key: $sk,
+            /// Client `key_share`.
+            pub client_share: $client,
+        }
+
+        impl_encoding!($sk, $sk_bytes, "private key");
+        impl_encoding!($client, $client_bytes, "key share");
+        impl_encoding!($server, $server_bytes, "key share");
+
+        #[doc = concat!("Generates a ", $name, " key pair from the ECDH scalar followed by the")]
+        /// 64-byte ML-KEM seed (Unauthenticated).
+        ///
+        /// Returns [`PqcError::InvalidParameter`] if the scalar is zero or not
+        /// below the group order; draw a fresh seed in that case.
+        pub fn $keygen(mut seed: [u8; $scalar_bytes + 64]) -> Result<$kp> {
+            let (scalar, ml_seed) = seed.split_at($scalar_bytes);
+            let ecdh_sk = $curve::SecretKey::from_slice(scalar)
+                .map_err(|_| PqcError::InvalidParameter);
+            let mut d_z = [0u8; 64];
+            d_z.copy_from_slice(ml_seed);
+            seed.zeroize();
+            let ecdh_sk = ecdh_sk?;
+            let (ml_sk, ml_pk) = $ml_keygen(d_z).into_parts();
+            d_z.zeroize();
+            let mut ml_sk: [u8; $ml_sk_bytes] = ml_sk.into();
+
+            let mut sk = [0u8; $sk_bytes];
+            sk[..$scalar_bytes].copy_from_slice(&ecdh_sk.to_bytes());
+            sk[$scalar_bytes..].copy_from_slice(&ml_sk);
+            ml_sk.zeroize();
+
+            let mut client = [0u8; $client_bytes];
+            let point = ecdh_sk.public_key().to_encoded_point(false);
+            client[..1 + 2 * $scalar_bytes].copy_from_slice(point.as_bytes());
+            client[1 + 2 * $scalar_bytes..].copy_from_slice(ml_pk.as_slice());
+
+            Ok($kp {
+                private_key: $sk(sk),
+                client_share: $client(client),
+            })
+        }
+
+        #[doc = concat!("Answers a ", $name, " client `key_share` (Unauthenticated).")]
+        ///
+        /// `randomness` is the ephemeral ECDH scalar followed by the 32-byte
+        /// ML-KEM encapsulation randomness. Returns
+        /// [`PqcError::InvalidParameter`] if the client share is not an
+        /// uncompressed point on the curve followed by a valid ML-KEM
+        /// encapsulation key, or if the ephemeral scalar is out of range.
+        pub fn $encaps(
+            client_share: &$client,
+            mut randomness: [u8; $scalar_bytes + 32],
+        ) -> Result<($server, $ss)> {
+            let (scalar, m) = randomness.split_at($scalar_bytes);
+            let ephemeral = $curve::SecretKey::from_slice(scalar)
+                .map_err(|_| PqcError::InvalidParameter);
+            let mut m_bytes = [0u8; 32];
+            m_bytes.copy_from_slice(m);
+            randomness.zeroize();
+            let ephemeral = ephemeral?;
+
+            let (point, ml_pk) = client_share.0.split_at(1 + 2 * $scalar_bytes);
+            let peer = parse_point!($curve, point)?;
+            let ml_pk: [u8; $ml_pk_bytes] =
+                ml_pk.try_into().map_err(|_| PqcError::InvalidParameter)?;
+            let ml_pk = <$ml_pk>::from(ml_pk);
+            if !$ml_validate(&ml_pk) {
+                return Err(PqcError::InvalidParameter);
+            }
+
+            let (ml_ct, mut ml_ss) = $ml_encaps(&ml_pk, m_bytes);
+            m_bytes.zeroize();
+            let z = $curve::ecdh::diffie_hellman(ephemeral.to_nonzero_scalar(), peer.as_affine());
+
+            let mut server = [0u8; $server_bytes];
+            let point = ephemeral.public_key().to_encoded_point(false);
+            server[..1 + 2 * $scalar_bytes].copy_from_slice(point.as_bytes());
+            server[1 + 2 * $scalar_bytes..].copy_from_slice(ml_ct.as_slice());
+
+            let mut ss = [0u8; $ss_bytes];
+            ss[..$scalar_bytes].copy_from_slice(z.raw_secret_bytes());
+            ss[$scalar_bytes..].copy_from_slice(&ml_ss);
+            ml_ss.zeroize();
+            Ok(($server(server), ss))
+        }
+
+        #[doc = concat!("Derives the ", $name, " shared secret from a server `key_share` (Unauthenticated).")]
+        ///
+        /// Returns [`PqcError::InvalidParameter`] if the server's ECDH share
+        /// is not an uncompressed point on the curve.
+        pub fn $decaps(sk: &$sk, server_share: &$server) -> Result<$ss> {
+            let (scalar, ml_sk) = sk.0.split_at($scalar_bytes);
+            let ecdh_sk =
+                $curve::SecretKey::from_slice(scalar).map_err(|_| PqcError::InvalidParameter)?;
+            let (point, ml_ct) = server_share.0.split_at(1 + 2 * $scalar_bytes);
+            let peer = parse_point!($curve, point)?;
+
+            let mut ml_sk_bytes = [0u8; $ml_sk_bytes];
+            ml_sk_bytes.copy_from_slice(ml_sk);
+            let mut ml_sk = <$ml_sk>::from(ml_sk_bytes);
+            ml_sk_bytes.zeroize();
+            let ml_ct: [u8; $ml_ct_bytes] =
+                ml_ct.try_into().map_err(|_| PqcError::InvalidParameter)?;
+            let mut ml_ss = $ml_decaps(&ml_sk, &<$ml_ct>::from(ml_ct));
+            ml_sk[0..].zeroize();
+            let z = $curve::ecdh::diffie_hellman(ecdh_sk.to_nonzero_scalar(), peer.as_affine());
+
+            let mut ss = [0u8; $ss_bytes];
+            ss[..$scalar_bytes].copy_from_slice(z.raw_secret_bytes());
+            ss[$scalar_bytes..].copy_from_slice(&ml_ss);
+            ml_ss.zeroize();
+            Ok(ss)
+        }
+    };
+}
+
+/// Parses an uncompressed SEC1 point, rejecting the compressed and identity
+/// encodings that TLS 1.3 does not allow.
+macro_rules! parse_point {
+    ($curve:ident, $bytes:expr) => {{
+        let bytes: &[u8] = $bytes;
+        if bytes.first() != Some(&0x04) {
+            Err(PqcError::InvalidParameter)
+        } else {
+            $curve::PublicKey::from_sec1_bytes(bytes).map_err(|_| PqcError::InvalidParameter)
+        }
+    }};
+}
+
+/// Implements the shared byte-encoding API for a hybrid group type.
+macro_rules! impl_encoding {
+    ($ty:ident, $bytes:path, $what:literal) => {
+        impl $ty {
+            #[doc = concat!("Size of the encoded ", $what, " in bytes.")]
+            pub const SIZE: usize = $bytes;
+
+            #[doc = concat!("Wraps an encoded ", $what, ".")]
+            pub fn new(bytes: [u8; $bytes]) -> Self {
+                Self(bytes)
+            }
+
+            #[doc = concat!("Returns the encoded ", $what, ".")]
+            pub fn as_slice(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $ty {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $ty {
+            type Error = PqcError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                bytes
+                    .try_into()
+                    .map(Self)
+                    .map_err(|_| PqcError::InvalidParameter)
+            }
+        }
+    };
+}
+
+ecdh_mlkem_group!(
+    "SecP256r1MLKEM768",
+    p256,
+    crate::ml_kem::generate_key_pair_768,
+    crate::ml_kem::encapsulate_768,
+    crate::ml_kem::decapsulate_768,
+    crate::ml_kem::validate_public_key_768,
+    SecP256r1MlKem768PrivateKey,
+    SecP256r1MlKem768ClientShare,
+    SecP256r1MlKem768ServerShare,
+    SecP256r1MlKem768SharedSecret,
+    SecP256r1MlKem768Keypair,
+    generate_key_pair_p256_mlkem768,
+    encapsulate_p256_mlkem768,
+    decapsulate_p256_mlkem768,
+    crate::Kyber768PublicKey,
+    crate::Kyber768PrivateKey,
+    crate::Kyber768Ciphertext,
+    32,
+    crate::ML_KEM_768_PK_BYTES,
+    crate::ML_KEM_768_SK_BYTES,
+    crate::ML_KEM_768_CT_BYTES,
+    crate::SECP256R1_MLKEM768_CLIENT_SHARE_BYTES,
+    crate::SECP256R1_MLKEM768_SERVER_SHARE_BYTES,
+    crate::SECP256R1_MLKEM768_SK_BYTES,
+    crate::SECP256R1_MLKEM768_SS_BYTES
+);
+
+ecdh_mlkem_group!(
+    "SecP384r1MLKEM1024",
+    p384,
+    crate::ml_kem::generate_key_pair,
+    crate::ml_kem::encapsulate,
+    crate::ml_kem::decapsulate,
+    crate::ml_kem::validate_public_key,
+    SecP384r1MlKem1024PrivateKey,
+    SecP384r1MlKem1024ClientShare,
+    SecP384r1MlKem1024ServerShare,
+    SecP384r1MlKem1024SharedSecret,
+    SecP384r1MlKem1024Keypair,
+    generate_key_pair_p384_mlkem1024,
+    encapsulate_p384_mlkem1024,
+    decapsulate_p384_mlkem1024,
+    crate::KyberPublicKey,
+    crate::KyberPrivateKey,
+    crate::KyberCiphertext,
+    48,
+    crate::ML_KEM_1024_PK_BYTES,
+    crate::ML_KEM_1024_SK_BYTES,
+    crate::ML_KEM_1024_CT_BYTES,
+    crate::SECP384R1_MLKEM1024_CLIENT_SHARE_BYTES,
+    crate::SECP384R1_MLKEM1024_SERVER_SHARE_BYTES,
+    crate::SECP384R1_MLKEM1024_SK_BYTES,
+    crate::SECP384R1_MLKEM1024_SS_BYTES
+);
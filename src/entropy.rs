@@ -0,0 +1,185 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/entropy.rs
+//! Entropy input from a caller-provided noise source (NIST SP 800-90B).
+//!
+//! Every raw sample passes the Repetition Count Test and the Adaptive
+//! Proportion Test (SP 800-90B, Section 4.4) before it is conditioned with
+//! SHA-256 into full-entropy output for seeding a [`crate::drbg::Drbg`].
+//! The startup tests run from [`crate::preop::run_post_with_entropy`] (or on
+//! first use); a continuous test failure moves the module to the Error state.
+
+use crate::error::{PqcError, Result};
+use crate::module::FipsModule;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+/// Adaptive Proportion Test window size for non-binary samples.
+pub const APT_WINDOW: u16 = 512;
+/// Number of samples the startup tests run over (SP 800-90B, Section 4.3).
+pub const STARTUP_SAMPLES: usize = 1024;
+
+/// APT cutoffs for W = 512 and alpha = 2^-20, indexed by H - 1
+/// (1 + CRITBINOM(W, 2^-H, 1 - alpha), SP 800-90B, Section 4.4.2).
+const APT_CUTOFFS: [u16; 8] = [311, 177, 103, 62, 39, 25, 18, 13];
+
+/// A physical noise source that produces raw, unconditioned samples.
+pub trait NoiseSource {
+    /// Returns the next raw sample (up to 8 bits).
+    fn sample(&mut self) -> u8;
+}
+
+/// Repetition Count Test and Adaptive Proportion Test state.
+pub(crate) struct HealthTests {
+    rct_cutoff: u16,
+    rct_last: u8,
+    rct_count: u16,
+    apt_cutoff: u16,
+    apt_first: u8,
+    apt_count: u16,
+    apt_seen: u16,
+}
+
+impl HealthTests {
+    /// `min_entropy` is the claimed min-entropy per sample, H, in bits.
+    pub(crate) fn new(min_entropy: u8) -> Result<Self> {
+        if !(1..=8).contains(&min_entropy) {
+            return Err(PqcError::InvalidParameter);
+        }
+        Ok(Self {
+            // C = 1 + ceil(20 / H) for alpha = 2^-20 (Section 4.4.1)
+            rct_cutoff: 1 + 20u16.div_ceil(min_entropy as u16),
+            rct_last: 0,
+            rct_count: 0,
+            apt_cutoff: APT_CUTOFFS[min_entropy as usize - 1],
+            apt_first: 0,
+            apt_count: 0,
+            apt_seen: 0,
+        })
+    }
+
+    /// Runs both tests on one sample.
+    pub(crate) fn check(&mut self, sample: u8) -> Result<()> {
+        if self.rct_count > 0 && sample == self.rct_last {
+            self.rct_count += 1;
+            if self.rct_count >= self.rct_cutoff {
+                return Err(PqcError::EntropySourceFailure);
+            }
+        } else {
+            self.rct_last = sample;
+            self.rct_count = 1;
+        }
+
+        if self.apt_seen == 0 {
+            self.apt_first = sample;
+            self.apt_count = 1;
+        } else if sample == self.apt_first {
+            self.apt_count += 1;
+            if self.apt_count >= self.apt_cutoff {
+                return Err(PqcError::EntropySourceFailure);
+            }
+        }
+        self.apt_seen = (self.apt_seen + 1) % APT_WINDOW;
+        Ok(())
+    }
+}
+
+/// A health-tested entropy source built on a [`NoiseSource`].
+pub struct EntropySource<N: NoiseSource> {
+    noise: N,
+    min_entropy: u8,
+    health: HealthTests,
+    started: bool,
+    failed: bool,
+}
+
+impl<N: NoiseSource> EntropySource<N> {
+    /// Wraps `noise`, whose claimed min-entropy is `min_entropy` bits per
+    /// sample (1 to 8, from the SP 800-90B entropy assessment).
+    ///
+    /// Sources assessed at less than one bit per sample must pack several
+    /// samples into one before handing them out.
+    pub fn new(noise: N, min_entropy: u8) -> Result<Self> {
+        Ok(Self {
+            noise,
+            min_entropy,
+            health: HealthTests::new(min_entropy)?,
+            started: false,
+            failed: false,
+        })
+    }
+
+    /// Returns the next health-tested sample.
+    fn next_sample(&mut self) -> Result<u8> {
+        if self.failed {
+            return Err(PqcError::EntropySourceFailure);
+        }
+        let sample = self.noise.sample();
+        if let Err(e) = self.health.check(sample) {
+            self.failed = true;
+            return Err(e);
+        }
+        Ok(sample)
+    }
+
+    /// Runs the startup health tests over [`STARTUP_SAMPLES`] samples, which
+    /// are then discarded.
+    pub fn startup_test(&mut self) -> Result<()> {
+        for _ in 0..STARTUP_SAMPLES {
+            self.next_sample()?;
+        }
+        self.started = true;
+        Ok(())
+    }
+
+    /// Fills `out` with full-entropy bits for DRBG entropy input or nonces
+    /// (Authenticated).
+    ///
+    /// Each 32-byte block is SHA-256 over samples carrying at least 64 bits
+    /// more min-entropy than the block (SP 800-90B, Section 3.1.5.1.2). A
+    /// health test failure enters the Error state and returns
+    /// [`PqcError::EntropySourceFailure`].
+    pub fn fill(&mut self, out: &mut [u8]) -> Result<()> {
+        FipsModule::global().entropy_fill(self, out)
+    }
+
+    fn fill_unchecked(&mut self, out: &mut [u8]) -> Result<()> {
+        if !self.started {
+            self.startup_test()?;
+        }
+        let samples_per_block = (256usize + 64).div_ceil(self.min_entropy as usize);
+        for chunk in out.chunks_mut(32) {
+            let mut hasher = Sha256::new();
+            for _ in 0..samples_per_block {
+                hasher.update([self.next_sample()?]);
+            }
+            let mut block: [u8; 32] = hasher.finalize().into();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+            block.zeroize();
+        }
+        Ok(())
+    }
+}
+
+impl FipsModule {
+    /// Fills `out` with full-entropy bits from `source` (Authenticated).
+    ///
+    /// See [`EntropySource::fill`]; a health test failure moves this module to
+    /// the Error state.
+    pub fn entropy_fill<N: NoiseSource>(
+        &self,
+        source: &mut EntropySource<N>,
+        out: &mut [u8],
+    ) -> Result<()> {
+        self.begin_service(true)?;
+        let result = source.fill_unchecked(out);
+        if result.is_err() {
+            self.enter_error_state();
+        }
+        result
+    }
+}
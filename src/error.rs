@@ -29,6 +29,19 @@ pub enum PqcError {
     KatFailure,
     /// Platform-specific error (e.g., code segment detection failed).
     PlatformError,
+    /// An input parameter has an invalid length or value.
+    InvalidParameter,
+    /// The requested service is disabled by the module security policy.
+    ServiceDisabled,
+    /// The DRBG reached its reseed interval and must be reseeded.
+    DrbgReseedRequired,
+    /// An entropy source health test (SP 800-90B) failed.
+    EntropySourceFailure,
+    /// The role is locked after too many failed login attempts.
+    AuthenticationLocked,
+    /// A role still has its default password and the module must be
+    /// provisioned first.
+    DefaultCredentials,
 }
 
 /// specialized Result type for PQC operations.
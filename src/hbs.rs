@@ -0,0 +1,55 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/hbs.rs
+//! Hash primitives shared by the SP 800-208 stateful hash-based signature
+//! verifiers (LMS/HSS and XMSS/XMSS^MT).
+
+use sha2::digest::{ExtendableOutput, FixedOutput, Update, XofReader};
+use sha2::Sha256;
+use sha3::Shake256;
+
+/// Hash function underlying a parameter set.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HashFamily {
+    /// SHA-256, truncated to n bytes for the 192-bit parameter sets.
+    Sha256,
+    /// SHAKE256 with an n-byte output.
+    Shake256,
+}
+
+/// Incremental hash over one of the supported hash families.
+// Boxing is not available without `alloc`, and each hasher is short-lived.
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum Hasher {
+    Sha256(Sha256),
+    Shake256(Shake256),
+}
+
+impl Hasher {
+    pub(crate) fn new(family: HashFamily) -> Self {
+        match family {
+            HashFamily::Sha256 => Self::Sha256(Sha256::default()),
+            HashFamily::Shake256 => Self::Shake256(Shake256::default()),
+        }
+    }
+
+    pub(crate) fn chain(mut self, data: &[u8]) -> Self {
+        match &mut self {
+            Self::Sha256(h) => h.update(data),
+            Self::Shake256(h) => h.update(data),
+        }
+        self
+    }
+
+    /// Writes the first `out.len()` bytes of the digest.
+    pub(crate) fn finalize(self, out: &mut [u8]) {
+        match self {
+            Self::Sha256(h) => out.copy_from_slice(&h.finalize_fixed()[..out.len()]),
+            Self::Shake256(h) => h.finalize_xof().read(out),
+        }
+    }
+}
@@ -0,0 +1,536 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/hpke.rs
+//! Hybrid Public Key Encryption (RFC 9180) with post-quantum KEMs.
+//!
+//! The KEM is ML-KEM-1024 (KEM ID `0x0042`, draft-ietf-hpke-pq) or, with the
+//! `xwing` feature, X-Wing (KEM ID `0x647A`). The encapsulated key `enc` is
+//! the KEM ciphertext and the KEM shared secret feeds the RFC 9180 key
+//! schedule unchanged. Recipient keys come from the regular KEM key
+//! generation services.
+//!
+//! Only the base and PSK modes exist: neither KEM offers the authenticated
+//! encapsulation that the auth modes need. Every operation writes into a
+//! caller-provided buffer, so nothing is allocated.
+//!
+//! ChaCha20-Poly1305 is not an Approved algorithm; use an AES-GCM suite in
+//! Approved mode.
+
+use crate::error::{PqcError, Result};
+use crate::ml_kem::{
+    decapsulate, encapsulate, validate_public_key, KyberCiphertext, KyberPrivateKey, KyberPublicKey,
+};
+#[cfg(feature = "xwing")]
+use crate::ml_kem::{validate_public_key_768, Kyber768PublicKey};
+#[cfg(feature = "xwing")]
+use crate::xwing::{self, XWingCiphertext, XWingPrivateKey, XWingPublicKey};
+use crate::{HPKE_KEM_ML_KEM_1024, HPKE_TAG_BYTES};
+#[cfg(feature = "xwing")]
+use crate::{HPKE_KEM_XWING, ML_KEM_768_PK_BYTES};
+use aes_gcm::aead::{AeadInPlace, KeyInit};
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use chacha20poly1305::ChaCha20Poly1305;
+use hmac::{Hmac, Mac};
+use sha2::{Sha256, Sha384};
+use zeroize::Zeroize;
+
+/// Largest supported hash output (SHA-384).
+const MAX_NH: usize = 48;
+/// AEAD nonce length (`Nn`) for every supported AEAD.
+const NN: usize = 12;
+/// Largest supported AEAD key (`Nk`).
+const MAX_NK: usize = 32;
+
+/// HPKE key derivation function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HpkeKdf {
+    /// HKDF-SHA256 (KDF ID `0x0001`).
+    HkdfSha256,
+    /// HKDF-SHA384 (KDF ID `0x0002`).
+    HkdfSha384,
+}
+
+impl HpkeKdf {
+    /// Returns the HPKE KDF ID.
+    pub const fn id(self) -> u16 {
+        match self {
+            Self::HkdfSha256 => 0x0001,
+            Self::HkdfSha384 => 0x0002,
+        }
+    }
+
+    /// Hash output length `Nh` in bytes.
+    const fn nh(self) -> usize {
+        match self {
+            Self::HkdfSha256 => 32,
+            Self::HkdfSha384 => 48,
+        }
+    }
+}
+
+/// HPKE AEAD.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HpkeAead {
+    /// AES-128-GCM (AEAD ID `0x0001`).
+    Aes128Gcm,
+    /// AES-256-GCM (AEAD ID `0x0002`).
+    Aes256Gcm,
+    /// ChaCha20-Poly1305 (AEAD ID `0x0003`). Not Approved.
+    ChaCha20Poly1305,
+    /// Export-only (AEAD ID `0xFFFF`): the context can export secrets but
+    /// cannot seal or open.
+    ExportOnly,
+}
+
+impl HpkeAead {
+    /// Returns the HPKE AEAD ID.
+    pub const fn id(self) -> u16 {
+        match self {
+            Self::Aes128Gcm => 0x0001,
+            Self::Aes256Gcm => 0x0002,
+            Self::ChaCha20Poly1305 => 0x0003,
+            Self::ExportOnly => 0xFFFF,
+        }
+    }
+
+    /// Key length `Nk` in bytes.
+    const fn nk(self) -> usize {
+        match self {
+            Self::Aes128Gcm => 16,
+            Self::Aes256Gcm | Self::ChaCha20Poly1305 => 32,
+            Self::ExportOnly => 0,
+        }
+    }
+}
+
+/// HPKE KDF and AEAD choice; the KEM is fixed by the setup function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HpkeSuite {
+    /// Key derivation function.
+    pub kdf: HpkeKdf,
+    /// AEAD.
+    pub aead: HpkeAead,
+}
+
+/// HPKE mode.
+#[derive(Clone, Copy, Debug)]
+pub enum HpkeMode<'a> {
+    /// Base mode (`mode_base`).
+    Base,
+    /// Pre-shared key mode (`mode_psk`). The PSK must be at least 32 bytes
+    /// and the PSK ID must not be empty.
+    Psk {
+        /// Pre-shared key.
+        psk: &'a [u8],
+        /// Identifier of the pre-shared key.
+        psk_id: &'a [u8],
+    },
+}
+
+impl HpkeMode<'_> {
+    /// Returns the mode byte and the PSK inputs, enforcing `VerifyPSKInputs`.
+    fn parts(&self) -> Result<(u8, &[u8], &[u8])> {
+        match *self {
+            Self::Base => Ok((0x00, &[], &[])),
+            Self::Psk { psk, psk_id } if psk.len() >= 32 && !psk_id.is_empty() => {
+                Ok((0x01, psk, psk_id))
+            }
+            Self::Psk { .. } => Err(PqcError::InvalidParameter),
+        }
+    }
+}
+
+/// A pseudorandom key or hash output, sized for the largest supported hash.
+struct Prk {
+    bytes: [u8; MAX_NH],
+    len: usize,
+}
+
+impl Prk {
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Drop for Prk {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+/// HKDF-Extract over the concatenation of `parts`.
+fn extract<M: Mac + KeyInit>(salt: &[u8], parts: &[&[u8]]) -> Prk {
+    let mut mac = <M as KeyInit>::new_from_slice(salt).expect("HMAC accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    let tag = mac.finalize().into_bytes();
+    let mut prk = Prk {
+        bytes: [0u8; MAX_NH],
+        len: tag.len(),
+    };
+    prk.bytes[..tag.len()].copy_from_slice(&tag);
+    prk
+}
+
+/// HKDF-Expand with the concatenation of `info` as info; `out` must not
+/// exceed 255 hash blocks.
+fn expand<M: Mac + KeyInit>(prk: &[u8], info: &[&[u8]], out: &mut [u8]) {
+    let mut t = [0u8; MAX_NH];
+    let mut t_len = 0;
+    for (i, chunk) in out.chunks_mut(M::output_size()).enumerate() {
+        let mut mac = <M as KeyInit>::new_from_slice(prk).expect("HMAC accepts any key length");
+        mac.update(&t[..t_len]);
+        for part in info {
+            mac.update(part);
+        }
+        mac.update(&[i as u8 + 1]);
+        let block = mac.finalize().into_bytes();
+        t_len = block.len();
+        t[..t_len].copy_from_slice(&block);
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+    t.zeroize();
+}
+
+/// The per-suite key schedule state: suite ID and KDF.
+struct Schedule {
+    suite_id: [u8; 10],
+    kdf: HpkeKdf,
+}
+
+impl Schedule {
+    fn new(kem_id: u16, suite: HpkeSuite) -> Self {
+        let mut suite_id = [0u8; 10];
+        suite_id[..4].copy_from_slice(b"HPKE");
+        suite_id[4..6].copy_from_slice(&kem_id.to_be_bytes());
+        suite_id[6..8].copy_from_slice(&suite.kdf.id().to_be_bytes());
+        suite_id[8..].copy_from_slice(&suite.aead.id().to_be_bytes());
+        Self {
+            suite_id,
+            kdf: suite.kdf,
+        }
+    }
+
+    /// `LabeledExtract(salt, label, ikm)`.
+    fn labeled_extract(&self, salt: &[u8], label: &[u8], ikm: &[u8]) -> Prk {
+        let parts: [&[u8]; 4] = [b"HPKE-v1", &self.suite_id, label, ikm];
+        match self.kdf {
+            HpkeKdf::HkdfSha256 => extract::<Hmac<Sha256>>(salt, &parts),
+            HpkeKdf::HkdfSha384 => extract::<Hmac<Sha384>>(salt, &parts),
+        }
+    }
+
+    /// `LabeledExpand(prk, label, info, out.len())`.
+    fn labeled_expand(&self, prk: &[u8], label: &[u8], info: &[&[u8]], out: &mut [u8]) {
+        let len = (out.len() as u16).to_be_bytes();
+        let mut parts: [&[u8]; 7] = [&len, b"HPKE-v1", &self.suite_id, label, &[], &[], &[]];
+        parts[4..4 + info.len()].copy_from_slice(info);
+        let parts = &parts[..4 + info.len()];
+        match self.kdf {
+            HpkeKdf::HkdfSha256 => expand::<Hmac<Sha256>>(prk, parts, out),
+            HpkeKdf::HkdfSha384 => expand::<Hmac<Sha384>>(prk, parts, out),
+        }
+    }
+}
+
+/// Keys and counters shared by the sender and recipient contexts.
+struct Context {
+    suite: HpkeSuite,
+    schedule: Schedule,
+    key: [u8; MAX_NK],
+    base_nonce: [u8; NN],
+    exporter_secret: Prk,
+    seq: u64,
+    approved: bool,
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        self.key.zeroize();
+        self.base_nonce.zeroize();
+    }
+}
+
+impl Context {
+    /// The RFC 9180 `KeySchedule`.
+    fn new(
+        kem_id: u16,
+        suite: HpkeSuite,
+        mode: HpkeMode<'_>,
+        shared_secret: &[u8],
+        info: &[u8],
+    ) -> Result<Self> {
+        let (mode, psk, psk_id) = mode.parts()?;
+        let schedule = Schedule::new(kem_id, suite);
+        let psk_id_hash = schedule.labeled_extract(&[], b"psk_id_hash", psk_id);
+        let info_hash = schedule.labeled_extract(&[], b"info_hash", info);
+        let context: [&[u8]; 3] = [&[mode], psk_id_hash.as_slice(), info_hash.as_slice()];
+        let secret = schedule.labeled_extract(shared_secret, b"secret", psk);
+
+        let mut key = [0u8; MAX_NK];
+        let mut base_nonce = [0u8; NN];
+        if suite.aead != HpkeAead::ExportOnly {
+            let nk = suite.aead.nk();
+            schedule.labeled_expand(secret.as_slice(), b"key", &context, &mut key[..nk]);
+            schedule.labeled_expand(secret.as_slice(), b"base_nonce", &context, &mut base_nonce);
+        }
+        let nh = suite.kdf.nh();
+        let mut exporter_secret = Prk {
+            bytes: [0u8; MAX_NH],
+            len: nh,
+        };
+        schedule.labeled_expand(
+            secret.as_slice(),
+            b"exp",
+            &context,
+            &mut exporter_secret.bytes[..nh],
+        );
+
+        Ok(Self {
+            suite,
+            schedule,
+            key,
+            base_nonce,
+            exporter_secret,
+            seq: 0,
+            approved: kem_id == HPKE_KEM_ML_KEM_1024 && suite.aead != HpkeAead::ChaCha20Poly1305,
+        })
+    }
+
+    /// Computes the nonce for the current sequence number and advances it.
+    fn next_nonce(&mut self) -> Result<[u8; NN]> {
+        if self.suite.aead == HpkeAead::ExportOnly {
+            return Err(PqcError::InvalidParameter);
+        }
+        let seq = self.seq;
+        self.seq = seq.checked_add(1).ok_or(PqcError::InvalidParameter)?;
+        let mut nonce = self.base_nonce;
+        for (n, s) in nonce[NN - 8..].iter_mut().zip(seq.to_be_bytes()) {
+            *n ^= s;
+        }
+        Ok(nonce)
+    }
+
+    fn seal(&mut self, aad: &[u8], plaintext: &[u8], out: &mut [u8]) -> Result<usize> {
+        let len = plaintext.len();
+        if out.len() < len + HPKE_TAG_BYTES {
+            return Err(PqcError::InvalidParameter);
+        }
+        let nonce = self.next_nonce()?;
+        let (buf, tag_out) = out.split_at_mut(len);
+        buf.copy_from_slice(plaintext);
+        let key = &self.key[..self.suite.aead.nk()];
+        let nonce = (&nonce).into();
+        let tag = match self.suite.aead {
+            HpkeAead::Aes128Gcm => {
+                Aes128Gcm::new(key.into()).encrypt_in_place_detached(nonce, aad, buf)
+            }
+            HpkeAead::Aes256Gcm => {
+                Aes256Gcm::new(key.into()).encrypt_in_place_detached(nonce, aad, buf)
+            }
+            HpkeAead::ChaCha20Poly1305 => {
+                ChaCha20Poly1305::new(key.into()).encrypt_in_place_detached(nonce, aad, buf)
+            }
+            HpkeAead::ExportOnly => unreachable!("rejected by next_nonce"),
+        }
+        .map_err(|_| PqcError::InvalidParameter)?;
+        tag_out[..HPKE_TAG_BYTES].copy_from_slice(&tag);
+        Ok(len + HPKE_TAG_BYTES)
+    }
+
+    fn open(&mut self, aad: &[u8], ciphertext: &[u8], out: &mut [u8]) -> Result<usize> {
+        let len = ciphertext
+            .len()
+            .checked_sub(HPKE_TAG_BYTES)
+            .ok_or(PqcError::InvalidParameter)?;
+        if out.len() < len {
+            return Err(PqcError::InvalidParameter);
+        }
+        if self.suite.aead == HpkeAead::ExportOnly {
+            return Err(PqcError::InvalidParameter);
+        }
+        // The sequence number only advances on success (RFC 9180, Section 5.2).
+        let seq = self.seq;
+        let nonce = self.next_nonce()?;
+        let (body, tag) = ciphertext.split_at(len);
+        let buf = &mut out[..len];
+        buf.copy_from_slice(body);
+        let key = &self.key[..self.suite.aead.nk()];
+        let nonce = (&nonce).into();
+        let tag = tag.into();
+        let opened = match self.suite.aead {
+            HpkeAead::Aes128Gcm => {
+                Aes128Gcm::new(key.into()).decrypt_in_place_detached(nonce, aad, buf, tag)
+            }
+            HpkeAead::Aes256Gcm => {
+                Aes256Gcm::new(key.into()).decrypt_in_place_detached(nonce, aad, buf, tag)
+            }
+            HpkeAead::ChaCha20Poly1305 => {
+                ChaCha20Poly1305::new(key.into()).decrypt_in_place_detached(nonce, aad, buf, tag)
+            }
+            HpkeAead::ExportOnly => unreachable!("rejected above"),
+        };
+        if opened.is_err() {
+            buf.zeroize();
+            self.seq = seq;
+            return Err(PqcError::FipsErrorState);
+        }
+        Ok(len)
+    }
+
+    fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<()> {
+        if out.len() > 255 * self.suite.kdf.nh() {
+            return Err(PqcError::InvalidParameter);
+        }
+        self.schedule.labeled_expand(
+            self.exporter_secret.as_slice(),
+            b"sec",
+            &[exporter_context],
+            out,
+        );
+        Ok(())
+    }
+}
+
+/// Sender context: seals messages to the recipient and exports secrets.
+pub struct HpkeSenderContext(Context);
+
+/// Recipient context: opens messages from the sender and exports secrets.
+pub struct HpkeRecipientContext(Context);
+
+impl HpkeSenderContext {
+    /// Encrypts `plaintext` into `out` as ciphertext || tag and returns the
+    /// number of bytes written (Unauthenticated).
+    ///
+    /// `out` must hold at least `plaintext.len() + HPKE_TAG_BYTES` bytes.
+    /// Returns [`PqcError::InvalidParameter`] if it does not, for export-only
+    /// suites, or once the sequence number is exhausted.
+    pub fn seal(&mut self, aad: &[u8], plaintext: &[u8], out: &mut [u8]) -> Result<usize> {
+        self.0.seal(aad, plaintext, out)
+    }
+
+    /// Returns true if the suite and KEM are Approved algorithms.
+    pub(crate) fn is_approved(&self) -> bool {
+        self.0.approved
+    }
+
+    /// Derives `out.len()` bytes of exported secret (Unauthenticated).
+    ///
+    /// Returns [`PqcError::InvalidParameter`] if `out` exceeds 255 hash blocks.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<()> {
+        self.0.export(exporter_context, out)
+    }
+}
+
+impl HpkeRecipientContext {
+    /// Decrypts ciphertext || tag into `out` and returns the plaintext length
+    /// (Unauthenticated).
+    ///
+    /// `out` must hold at least `ciphertext.len() - HPKE_TAG_BYTES` bytes.
+    /// Returns [`PqcError::FipsErrorState`] if authentication fails, in which
+    /// case `out` is cleared and the sequence number is not advanced.
+    pub fn open(&mut self, aad: &[u8], ciphertext: &[u8], out: &mut [u8]) -> Result<usize> {
+        self.0.open(aad, ciphertext, out)
+    }
+
+    /// Returns true if the suite and KEM are Approved algorithms.
+    pub(crate) fn is_approved(&self) -> bool {
+        self.0.approved
+    }
+
+    /// Derives `out.len()` bytes of exported secret (Unauthenticated).
+    ///
+    /// Returns [`PqcError::InvalidParameter`] if `out` exceeds 255 hash blocks.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<()> {
+        self.0.export(exporter_context, out)
+    }
+}
+
+/// Sets up an ML-KEM-1024 sender context (`SetupBaseS` / `SetupPSKS`)
+/// (Unauthenticated).
+///
+/// `randomness` is the ML-KEM encapsulation randomness; the returned
+/// ciphertext is `enc`. Returns [`PqcError::InvalidParameter`] if `pk` fails
+/// the FIPS 203 encapsulation key check or the PSK inputs are invalid.
+pub fn setup_sender_mlkem1024(
+    suite: HpkeSuite,
+    mode: HpkeMode<'_>,
+    pk: &KyberPublicKey,
+    info: &[u8],
+    randomness: [u8; 32],
+) -> Result<(KyberCiphertext, HpkeSenderContext)> {
+    if !validate_public_key(pk) {
+        return Err(PqcError::InvalidParameter);
+    }
+    mode.parts()?;
+    let (enc, mut ss) = encapsulate(pk, randomness);
+    let ctx = Context::new(HPKE_KEM_ML_KEM_1024, suite, mode, &ss, info);
+    ss.zeroize();
+    Ok((enc, HpkeSenderContext(ctx?)))
+}
+
+/// Sets up an ML-KEM-1024 recipient context (`SetupBaseR` / `SetupPSKR`)
+/// (Unauthenticated).
+pub fn setup_recipient_mlkem1024(
+    suite: HpkeSuite,
+    mode: HpkeMode<'_>,
+    sk: &KyberPrivateKey,
+    enc: &KyberCiphertext,
+    info: &[u8],
+) -> Result<HpkeRecipientContext> {
+    mode.parts()?;
+    let mut ss = decapsulate(sk, enc);
+    let ctx = Context::new(HPKE_KEM_ML_KEM_1024, suite, mode, &ss, info);
+    ss.zeroize();
+    Ok(HpkeRecipientContext(ctx?))
+}
+
+/// Sets up an X-Wing sender context (`SetupBaseS` / `SetupPSKS`)
+/// (Unauthenticated).
+///
+/// `eseed` is the X-Wing encapsulation seed; the returned ciphertext is
+/// `enc`. Returns [`PqcError::InvalidParameter`] if the ML-KEM-768 part of
+/// `pk` fails the FIPS 203 encapsulation key check or the PSK inputs are
+/// invalid.
+#[cfg(feature = "xwing")]
+pub fn setup_sender_xwing(
+    suite: HpkeSuite,
+    mode: HpkeMode<'_>,
+    pk: &XWingPublicKey,
+    info: &[u8],
+    eseed: [u8; 64],
+) -> Result<(XWingCiphertext, HpkeSenderContext)> {
+    let pk_m: [u8; ML_KEM_768_PK_BYTES] = pk.as_slice()[..ML_KEM_768_PK_BYTES]
+        .try_into()
+        .map_err(|_| PqcError::InvalidParameter)?;
+    if !validate_public_key_768(&Kyber768PublicKey::from(pk_m)) {
+        return Err(PqcError::InvalidParameter);
+    }
+    mode.parts()?;
+    let (enc, mut ss) = xwing::encapsulate(pk, eseed);
+    let ctx = Context::new(HPKE_KEM_XWING, suite, mode, &ss, info);
+    ss.zeroize();
+    Ok((enc, HpkeSenderContext(ctx?)))
+}
+
+/// Sets up an X-Wing recipient context (`SetupBaseR` / `SetupPSKR`)
+/// (Unauthenticated).
+#[cfg(feature = "xwing")]
+pub fn setup_recipient_xwing(
+    suite: HpkeSuite,
+    mode: HpkeMode<'_>,
+    sk: &XWingPrivateKey,
+    enc: &XWingCiphertext,
+    info: &[u8],
+) -> Result<HpkeRecipientContext> {
+    mode.parts()?;
+    let mut ss = xwing::decapsulate(sk, enc);
+    let ctx = Context::new(HPKE_KEM_XWING, suite, mode, &ss, info);
+    ss.zeroize();
+    Ok(HpkeRecipientContext(ctx?))
+}
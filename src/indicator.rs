@@ -0,0 +1,88 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/indicator.rs
+//! FIPS 140-3 approved service indicator (IG 2.4.C).
+//!
+//! Every cryptographic service exported from the crate root, including the
+//! DRBG, entropy and streaming signature services, records whether it is an
+//! Approved service as it passes the service gate; a service the gate
+//! refuses is recorded as non-approved. Callers read the result with
+//! [`last_service_indicator`] right after the call. A service is reported as
+//! non-approved when the module is built without `fips_140_3`, when it uses
+//! a non-approved algorithm (X25519, ChaCha20-Poly1305) or when it is given
+//! a non-empty context string in place of [`crate::FIPS_CONTEXT`].
+//!
+//! Each [`FipsModule`] keeps its own indicator: callers that share one
+//! module between threads must serialize the call and the read.
+
+use crate::auth::Role;
+use crate::error::Result;
+use crate::module::FipsModule;
+use core::sync::atomic::Ordering;
+
+/// Approval status of the most recent service.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceIndicator {
+    /// No service has run yet.
+    None = 0,
+    /// The last service was an Approved service.
+    Approved = 1,
+    /// The last service was not an Approved service.
+    NonApproved = 2,
+}
+
+impl FipsModule {
+    /// Returns the approval status of the most recent service.
+    pub fn last_service_indicator(&self) -> ServiceIndicator {
+        ServiceIndicator::from(self.indicator.load(Ordering::Acquire))
+    }
+
+    /// Returns true if the most recent service was an Approved service.
+    pub fn last_service_approved(&self) -> bool {
+        self.last_service_indicator() == ServiceIndicator::Approved
+    }
+
+    /// Passes the User service gate and records the status of the service
+    /// about to run. A service the gate refuses is recorded as non-approved.
+    pub(crate) fn begin_service(&self, approved: bool) -> Result<()> {
+        let gate = self.check_service(Role::User);
+        self.record(approved && gate.is_ok());
+        gate
+    }
+
+    /// Records the status of a service; nothing is Approved outside Approved
+    /// mode.
+    fn record(&self, approved: bool) {
+        let indicator = if approved && cfg!(feature = "fips_140_3") {
+            ServiceIndicator::Approved
+        } else {
+            ServiceIndicator::NonApproved
+        };
+        self.indicator.store(indicator as u8, Ordering::Release);
+    }
+}
+
+/// Returns the approval status of the most recent service of the global module.
+pub fn last_service_indicator() -> ServiceIndicator {
+    FipsModule::global().last_service_indicator()
+}
+
+/// Returns true if the most recent service of the global module was an Approved service.
+pub fn last_service_approved() -> bool {
+    FipsModule::global().last_service_approved()
+}
+
+impl From<u8> for ServiceIndicator {
+    fn from(val: u8) -> Self {
+        match val {
+            0 => ServiceIndicator::None,
+            1 => ServiceIndicator::Approved,
+            _ => ServiceIndicator::NonApproved,
+        }
+    }
+}
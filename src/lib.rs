@@ -8,20 +8,41 @@
 //! # PQC-NOSTD
 //!
 //! Post-Quantum Cryptography library for `no_std` environments.
-//! Implements ML-KEM-1024 (FIPS 203) and ML-DSA-65 (FIPS 204).
+//! Implements ML-KEM-1024 (FIPS 203) and ML-DSA-65 (FIPS 204), with optional
+//! ML-KEM-512, ML-KEM-768, ML-DSA-44 and ML-DSA-87 parameter sets.
 //!
 //! ## Features
 //! - `ml-kem`: Enables ML-KEM-1024.
+//! - `ml-kem-512`: Enables ML-KEM-512.
+//! - `ml-kem-768`: Enables ML-KEM-768.
 //! - `ml-dsa`: Enables ML-DSA-65.
+//! - `ml-dsa-44`: Enables ML-DSA-44.
+//! - `ml-dsa-87`: Enables ML-DSA-87.
+//! - `slh-dsa`: Enables SLH-DSA-SHAKE-128s and SLH-DSA-SHAKE-256s (FIPS 205).
+//! - `lms`: Enables LMS/HSS signature verification (SP 800-208).
+//! - `xmss`: Enables XMSS/XMSS^MT signature verification (SP 800-208).
+//! - `ctr-drbg`: Enables CTR_DRBG with AES-256 (SP 800-90A).
+//! - `rand_core`: Adds `_with_rng` variants of the key generation, encapsulation
+//!   and signing services that take any `CryptoRng + RngCore`.
+//! - `signature`: Implements the RustCrypto `signature` traits for ML-DSA-65.
+//! - `kem`: Implements the RustCrypto `kem` traits for ML-KEM-1024.
+//! - `xwing`: Enables the X-Wing hybrid KEM (X25519 + ML-KEM-768).
+//! - `ecdh-mlkem`: Enables the TLS 1.3 `SecP256r1MLKEM768` and
+//!   `SecP384r1MLKEM1024` hybrid groups.
+//! - `composite`: Enables composite ML-DSA-65 + ECDSA-P256/Ed25519 signatures.
+//! - `hpke`: Enables HPKE (RFC 9180) with ML-KEM-1024, and X-Wing with `xwing`.
 //! - `fips_140_3`: Enables FIPS 140-3 Approved mode (POST, CASTs, state machine).
 //!
+//! Every parameter set also implements the [`Kem`] or [`SignatureScheme`] trait, so
+//! code can be written once against the generic services in [`traits`].
+//!
 //! ## Approved-Mode Usage
 //!
 //! This example demonstrates the correct initialization and usage of the module in FIPS Approved mode.
 //!
 //! ```rust
 //! use pqc_nostd::{run_post_or_panic, is_operational, FIPS_CONTEXT};
-//! use pqc_nostd::auth::{login, Role};
+//! use pqc_nostd::auth::{load_credentials, login, Role, StoredCredentials};
 //!
 //! fn main() {
 //!     // 1. Power-On Self-Tests (POST) MUST be run before any crypto operation.
@@ -30,11 +51,18 @@
 //!     // 2. Verify the module is in the Operational state.
 //!     assert!(is_operational());
 //!
-//!     // 3. Login as User (Level 2 Requirement)
+//!     // 3. Restore the credentials provisioned by the Crypto Officer.
+//!     // In a real app, you would read them back from non-volatile storage.
+//!     let stored = StoredCredentials::from_passwords(
+//!         b"user password", [0x11u8; 16], b"officer password", [0x22u8; 16],
+//!     ).unwrap();
+//!     load_credentials(&stored).expect("Credentials rejected");
+//!
+//!     // 4. Login as User (Level 2 Requirement)
 //!     // In a real app, you would prompt for credentials.
-//!     login(Role::User, b"user123").expect("Login failed");
+//!     login(Role::User, b"user password").expect("Login failed");
 //!
-//!     // 4. Use Approved Algorithms
+//!     // 5. Use Approved Algorithms
 //!     
 //!     // ML-KEM-1024 (Key Encapsulation)
 //!     let kyber_kp = pqc_nostd::kyber_generate_key_pair([0x01u8; 64]).unwrap();
@@ -58,11 +86,26 @@
 pub mod auth;
 /// Conditional Algorithm Self-Tests (CASTs).
 pub mod cast;
+/// Composite ML-DSA + ECDSA/Ed25519 signatures.
+#[cfg(feature = "composite")]
+pub mod composite;
 /// Critical Security Parameter (CSP) management.
 /// Critical Security Parameter (CSP) management.
 pub mod csp;
+/// SP 800-90A Deterministic Random Bit Generators.
+pub mod drbg;
+/// ECDHE-MLKEM hybrid key exchange for TLS 1.3.
+#[cfg(feature = "ecdh-mlkem")]
+pub mod ecdh_mlkem;
+/// SP 800-90B entropy source health tests.
+pub mod entropy;
 /// Error types and Result alias.
 pub mod error;
+/// Hybrid Public Key Encryption (RFC 9180) with post-quantum KEMs.
+#[cfg(feature = "hpke")]
+pub mod hpke;
+/// FIPS 140-3 approved service indicator.
+pub mod indicator;
 /// Software Integrity Test (Level 1/2).
 pub mod integrity;
 /// Generated integrity data (HMAC).
@@ -70,36 +113,93 @@ pub mod integrity_data;
 /// Known Answer Tests (KATs).
 #[cfg(feature = "fips_140_3")]
 pub mod kat;
+/// The FIPS module context.
+pub mod module;
 /// Pair-wise Consistency Tests (PCTs).
 pub mod pct;
+/// Crypto Officer configurable security policy.
+pub mod policy;
 /// Pre-operational self-tests (POST).
 pub mod preop;
 /// FIPS module state management.
 pub mod state;
+/// Generic KEM and signature parameter-set traits.
+pub mod traits;
 
-/// ML-DSA-65 (FIPS 204) implementation.
-#[cfg(feature = "ml-dsa")]
+/// Cryptographic services of a [`FipsModule`].
+mod services;
+
+/// Hash primitives shared by the stateful hash-based signature verifiers.
+#[cfg(any(feature = "lms", feature = "xmss"))]
+mod hbs;
+/// LMS/HSS (SP 800-208) signature verification.
+#[cfg(feature = "lms")]
+pub mod lms;
+/// ML-DSA (FIPS 204) implementation.
+#[cfg(any(feature = "ml-dsa", feature = "ml-dsa-44", feature = "ml-dsa-87"))]
 pub mod ml_dsa;
-/// ML-KEM-1024 (FIPS 203) implementation.
-#[cfg(feature = "ml-kem")]
+/// Portable ML-DSA signing core operating on a precomputed μ.
+#[cfg(feature = "ml-dsa")]
+mod ml_dsa_core;
+/// RustCrypto `signature` trait implementations for ML-DSA-65.
+#[cfg(feature = "signature")]
+pub mod ml_dsa_signature;
+/// ML-KEM (FIPS 203) implementation.
+#[cfg(any(feature = "ml-kem", feature = "ml-kem-512", feature = "ml-kem-768"))]
 pub mod ml_kem;
+/// RustCrypto `kem` trait implementations for ML-KEM-1024.
+#[cfg(feature = "kem")]
+pub mod ml_kem_kem;
+/// SLH-DSA (FIPS 205) implementation.
+#[cfg(feature = "slh-dsa")]
+pub mod slh_dsa;
+/// XMSS/XMSS^MT (SP 800-208) signature verification.
+#[cfg(feature = "xmss")]
+pub mod xmss;
+/// X-Wing hybrid KEM (X25519 + ML-KEM-768).
+#[cfg(feature = "xwing")]
+pub mod xwing;
 
 // ML-KEM re-exports
+#[cfg(any(feature = "ml-kem", feature = "ml-kem-512", feature = "ml-kem-768"))]
+pub use ml_kem::KyberSharedSecret;
 #[cfg(feature = "ml-kem")]
 pub use ml_kem::{
     decapsulate as kyber_decapsulate_internal, encapsulate as kyber_encapsulate_internal,
     generate_key_pair as kyber_generate_key_pair_internal, KyberCiphertext, KyberKeypair,
-    KyberPrivateKey, KyberPublicKey, KyberSharedSecret,
+    KyberPrivateKey, KyberPublicKey,
 };
 
-#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
-use auth::{check_authority, Role};
+#[cfg(feature = "ctr-drbg")]
+pub use drbg::CtrDrbg;
+pub use drbg::{Drbg, HmacDrbg};
+pub use entropy::{EntropySource, NoiseSource};
+#[cfg(all(feature = "rand_core", any(feature = "ml-kem", feature = "ml-dsa")))]
+use rand_core::{CryptoRng, RngCore};
+pub use traits::{Kem, SignatureScheme};
+
+#[cfg(feature = "ml-kem")]
+pub use ml_kem::MlKem1024;
+#[cfg(feature = "ml-kem-512")]
+pub use ml_kem::MlKem512;
+#[cfg(feature = "ml-kem-768")]
+pub use ml_kem::MlKem768;
+#[cfg(feature = "kem")]
+pub use ml_kem_kem::{MlKem1024DecapsulationKey, MlKem1024EncapsulationKey};
+
+#[cfg(feature = "ml-dsa-44")]
+pub use ml_dsa::MlDsa44;
+#[cfg(feature = "ml-dsa")]
+pub use ml_dsa::MlDsa65;
+#[cfg(feature = "ml-dsa-87")]
+pub use ml_dsa::MlDsa87;
+#[cfg(feature = "signature")]
+pub use ml_dsa_signature::{MlDsa65Signature, MlDsa65SigningKey, MlDsa65VerifyingKey};
 
 /// Generates a Kyber key pair (Authenticated).
 #[cfg(feature = "ml-kem")]
 pub fn kyber_generate_key_pair(seed: [u8; 64]) -> Result<KyberKeypair> {
-    check_authority(Role::User)?;
-    Ok(kyber_generate_key_pair_internal(seed))
+    FipsModule::global().kyber_generate_key_pair(seed)
 }
 
 /// Encapsulates a shared secret (Authenticated).
@@ -108,30 +208,142 @@ pub fn encapsulate(
     pk: &KyberPublicKey,
     randomness: [u8; 32],
 ) -> Result<(KyberCiphertext, KyberSharedSecret)> {
-    check_authority(Role::User)?;
-    Ok(kyber_encapsulate_internal(pk, randomness))
+    FipsModule::global().encapsulate(pk, randomness)
 }
 
 /// Decapsulates a shared secret (Authenticated).
 #[cfg(feature = "ml-kem")]
 pub fn decapsulate(sk: &KyberPrivateKey, ct: &KyberCiphertext) -> Result<KyberSharedSecret> {
-    check_authority(Role::User)?;
-    Ok(kyber_decapsulate_internal(sk, ct))
+    FipsModule::global().decapsulate(sk, ct)
+}
+
+/// Generates a Kyber key pair with the seed drawn from `drbg` (Authenticated).
+#[cfg(feature = "ml-kem")]
+pub fn kyber_generate_key_pair_with_drbg<D: Drbg>(drbg: &mut D) -> Result<KyberKeypair> {
+    FipsModule::global().kyber_generate_key_pair_with_drbg(drbg)
+}
+
+/// Encapsulates a shared secret with randomness drawn from `drbg` (Authenticated).
+#[cfg(feature = "ml-kem")]
+pub fn encapsulate_with_drbg<D: Drbg>(
+    pk: &KyberPublicKey,
+    drbg: &mut D,
+) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+    FipsModule::global().encapsulate_with_drbg(pk, drbg)
+}
+
+/// Generates a Kyber key pair with the seed drawn from `rng` (Authenticated).
+#[cfg(all(feature = "ml-kem", feature = "rand_core"))]
+pub fn kyber_generate_key_pair_with_rng<R: CryptoRng + RngCore>(
+    rng: &mut R,
+) -> Result<KyberKeypair> {
+    FipsModule::global().kyber_generate_key_pair_with_rng(rng)
+}
+
+/// Encapsulates a shared secret with randomness drawn from `rng` (Authenticated).
+#[cfg(all(feature = "ml-kem", feature = "rand_core"))]
+pub fn encapsulate_with_rng<R: CryptoRng + RngCore>(
+    pk: &KyberPublicKey,
+    rng: &mut R,
+) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+    FipsModule::global().encapsulate_with_rng(pk, rng)
+}
+
+// ML-KEM-768 re-exports
+#[cfg(feature = "ml-kem-768")]
+pub use ml_kem::{
+    decapsulate_768 as kyber768_decapsulate_internal,
+    encapsulate_768 as kyber768_encapsulate_internal,
+    generate_key_pair_768 as kyber768_generate_key_pair_internal, Kyber768Ciphertext,
+    Kyber768Keypair, Kyber768PrivateKey, Kyber768PublicKey,
+};
+
+/// Generates an ML-KEM-768 key pair (Authenticated).
+#[cfg(feature = "ml-kem-768")]
+pub fn kyber768_generate_key_pair(seed: [u8; 64]) -> Result<Kyber768Keypair> {
+    FipsModule::global().kyber768_generate_key_pair(seed)
+}
+
+/// Encapsulates a shared secret with ML-KEM-768 (Authenticated).
+#[cfg(feature = "ml-kem-768")]
+pub fn kyber768_encapsulate(
+    pk: &Kyber768PublicKey,
+    randomness: [u8; 32],
+) -> Result<(Kyber768Ciphertext, KyberSharedSecret)> {
+    FipsModule::global().kyber768_encapsulate(pk, randomness)
+}
+
+/// Decapsulates an ML-KEM-768 shared secret (Authenticated).
+#[cfg(feature = "ml-kem-768")]
+pub fn kyber768_decapsulate(
+    sk: &Kyber768PrivateKey,
+    ct: &Kyber768Ciphertext,
+) -> Result<KyberSharedSecret> {
+    FipsModule::global().kyber768_decapsulate(sk, ct)
+}
+
+// ML-KEM-512 re-exports
+#[cfg(feature = "ml-kem-512")]
+pub use ml_kem::{
+    decapsulate_512 as kyber512_decapsulate_internal,
+    encapsulate_512 as kyber512_encapsulate_internal,
+    generate_key_pair_512 as kyber512_generate_key_pair_internal, Kyber512Ciphertext,
+    Kyber512Keypair, Kyber512PrivateKey, Kyber512PublicKey,
+};
+
+/// Generates an ML-KEM-512 key pair (Authenticated).
+#[cfg(feature = "ml-kem-512")]
+pub fn kyber512_generate_key_pair(seed: [u8; 64]) -> Result<Kyber512Keypair> {
+    FipsModule::global().kyber512_generate_key_pair(seed)
+}
+
+/// Encapsulates a shared secret with ML-KEM-512 (Authenticated).
+#[cfg(feature = "ml-kem-512")]
+pub fn kyber512_encapsulate(
+    pk: &Kyber512PublicKey,
+    randomness: [u8; 32],
+) -> Result<(Kyber512Ciphertext, KyberSharedSecret)> {
+    FipsModule::global().kyber512_encapsulate(pk, randomness)
+}
+
+/// Decapsulates an ML-KEM-512 shared secret (Authenticated).
+#[cfg(feature = "ml-kem-512")]
+pub fn kyber512_decapsulate(
+    sk: &Kyber512PrivateKey,
+    ct: &Kyber512Ciphertext,
+) -> Result<KyberSharedSecret> {
+    FipsModule::global().kyber512_decapsulate(sk, ct)
 }
 
 // ML-DSA re-exports
+#[cfg(any(feature = "ml-dsa", feature = "ml-dsa-44", feature = "ml-dsa-87"))]
+pub use ml_dsa::PreHashAlgorithm;
+#[cfg(any(feature = "ml-dsa", feature = "ml-dsa-44", feature = "ml-dsa-87"))]
+pub use ml_dsa::FIPS_CONTEXT;
 #[cfg(feature = "ml-dsa")]
 pub use ml_dsa::{
+    compute_mu as dilithium_compute_mu_internal,
     generate_key_pair as dilithium_generate_key_pair_internal, sign as dilithium_sign_internal,
-    verify as dilithium_verify_internal, DilithiumKeypair, DilithiumSignature, DilithiumSigningKey,
-    DilithiumVerifyingKey, FIPS_CONTEXT,
+    sign_deterministic as dilithium_sign_deterministic_internal,
+    sign_mu as dilithium_sign_mu_internal, sign_prehashed as dilithium_sign_prehashed_internal,
+    verify as dilithium_verify_internal, verify_mu as dilithium_verify_mu_internal,
+    verify_prehashed as dilithium_verify_prehashed_internal, DilithiumKeypair, DilithiumMu,
+    DilithiumSignature, DilithiumSigner, DilithiumSigningKey, DilithiumVerifier,
+    DilithiumVerifyingKey,
 };
 
 /// Generates a Dilithium key pair (Authenticated).
 #[cfg(feature = "ml-dsa")]
 pub fn dilithium_generate_key_pair(seed: [u8; 32]) -> Result<DilithiumKeypair> {
-    check_authority(Role::User)?;
-    Ok(dilithium_generate_key_pair_internal(seed))
+    FipsModule::global().dilithium_generate_key_pair(seed)
+}
+
+/// Generates a Dilithium key pair with the seed drawn from `rng` (Authenticated).
+#[cfg(all(feature = "ml-dsa", feature = "rand_core"))]
+pub fn dilithium_generate_key_pair_with_rng<R: CryptoRng + RngCore>(
+    rng: &mut R,
+) -> Result<DilithiumKeypair> {
+    FipsModule::global().dilithium_generate_key_pair_with_rng(rng)
 }
 
 /// Signs a message (Authenticated).
@@ -142,9 +354,29 @@ pub fn dilithium_sign(
     ctx: &[u8],
     randomness: [u8; 32],
 ) -> Result<DilithiumSignature> {
-    check_authority(Role::User)?;
-    dilithium_sign_internal(sk, msg, ctx, randomness).map_err(|_| PqcError::FipsErrorState)
-    // Map libcrux error if any
+    FipsModule::global().dilithium_sign(sk, msg, ctx, randomness)
+}
+
+/// Signs a message with rnd drawn from `drbg` (Authenticated).
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_sign_with_drbg<D: Drbg>(
+    sk: &DilithiumSigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+    drbg: &mut D,
+) -> Result<DilithiumSignature> {
+    FipsModule::global().dilithium_sign_with_drbg(sk, msg, ctx, drbg)
+}
+
+/// Signs a message with rnd drawn from `rng` (Authenticated).
+#[cfg(all(feature = "ml-dsa", feature = "rand_core"))]
+pub fn dilithium_sign_with_rng<R: CryptoRng + RngCore>(
+    sk: &DilithiumSigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+    rng: &mut R,
+) -> Result<DilithiumSignature> {
+    FipsModule::global().dilithium_sign_with_rng(sk, msg, ctx, rng)
 }
 
 /// Verifies a signature (Authenticated).
@@ -155,10 +387,550 @@ pub fn dilithium_verify(
     ctx: &[u8],
     sig: &DilithiumSignature,
 ) -> Result<()> {
-    check_authority(Role::User)?;
-    dilithium_verify_internal(pk, msg, ctx, sig).map_err(|_| PqcError::FipsErrorState)
+    FipsModule::global().dilithium_verify(pk, msg, ctx, sig)
+}
+
+/// Signs a message deterministically, with rnd = 0^32 (Authenticated).
+///
+/// The same key, message and context always give the same signature. Only
+/// available after a Crypto Officer enables it with
+/// [`policy::set_deterministic_signing`]; otherwise returns
+/// [`PqcError::ServiceDisabled`].
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_sign_deterministic(
+    sk: &DilithiumSigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+) -> Result<DilithiumSignature> {
+    FipsModule::global().dilithium_sign_deterministic(sk, msg, ctx)
+}
+
+/// Signs a pre-hashed message with HashML-DSA-65 (Authenticated).
+///
+/// `digest` is the caller-computed hash of the message under `hash_alg`, which
+/// allows signing messages too large to buffer (FIPS 204, Section 5.4).
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_sign_prehashed(
+    sk: &DilithiumSigningKey,
+    digest: &[u8],
+    hash_alg: PreHashAlgorithm,
+    ctx: &[u8],
+    randomness: [u8; 32],
+) -> Result<DilithiumSignature> {
+    FipsModule::global().dilithium_sign_prehashed(sk, digest, hash_alg, ctx, randomness)
+}
+
+/// Verifies a HashML-DSA-65 signature over a pre-hashed message (Authenticated).
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_verify_prehashed(
+    pk: &DilithiumVerifyingKey,
+    digest: &[u8],
+    hash_alg: PreHashAlgorithm,
+    ctx: &[u8],
+    sig: &DilithiumSignature,
+) -> Result<()> {
+    FipsModule::global().dilithium_verify_prehashed(pk, digest, hash_alg, ctx, sig)
+}
+
+/// Computes the ML-DSA-65 message representative μ (Authenticated).
+///
+/// Lets a host hash the message while the module holding the signing key
+/// only handles the 64-byte μ (FIPS 204, Section 6.2 "external μ").
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_compute_mu(
+    pk: &DilithiumVerifyingKey,
+    msg: &[u8],
+    ctx: &[u8],
+) -> Result<DilithiumMu> {
+    FipsModule::global().dilithium_compute_mu(pk, msg, ctx)
+}
+
+/// Signs a precomputed μ with ML-DSA-65 (Authenticated).
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_sign_mu(
+    sk: &DilithiumSigningKey,
+    mu: &DilithiumMu,
+    randomness: [u8; 32],
+) -> Result<DilithiumSignature> {
+    FipsModule::global().dilithium_sign_mu(sk, mu, randomness)
+}
+
+/// Verifies an ML-DSA-65 signature against a precomputed μ (Authenticated).
+#[cfg(feature = "ml-dsa")]
+pub fn dilithium_verify_mu(
+    pk: &DilithiumVerifyingKey,
+    mu: &DilithiumMu,
+    sig: &DilithiumSignature,
+) -> Result<()> {
+    FipsModule::global().dilithium_verify_mu(pk, mu, sig)
+}
+
+// ML-DSA-44 re-exports
+#[cfg(feature = "ml-dsa-44")]
+pub use ml_dsa::{
+    generate_key_pair_44 as dilithium44_generate_key_pair_internal,
+    sign_44 as dilithium44_sign_internal, verify_44 as dilithium44_verify_internal,
+    Dilithium44Keypair, Dilithium44Signature, Dilithium44SigningKey, Dilithium44VerifyingKey,
+};
+
+/// Generates an ML-DSA-44 key pair (Authenticated).
+#[cfg(feature = "ml-dsa-44")]
+pub fn dilithium44_generate_key_pair(seed: [u8; 32]) -> Result<Dilithium44Keypair> {
+    FipsModule::global().dilithium44_generate_key_pair(seed)
+}
+
+/// Signs a message with ML-DSA-44 (Authenticated).
+#[cfg(feature = "ml-dsa-44")]
+pub fn dilithium44_sign(
+    sk: &Dilithium44SigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+    randomness: [u8; 32],
+) -> Result<Dilithium44Signature> {
+    FipsModule::global().dilithium44_sign(sk, msg, ctx, randomness)
+}
+
+/// Verifies an ML-DSA-44 signature (Authenticated).
+#[cfg(feature = "ml-dsa-44")]
+pub fn dilithium44_verify(
+    pk: &Dilithium44VerifyingKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &Dilithium44Signature,
+) -> Result<()> {
+    FipsModule::global().dilithium44_verify(pk, msg, ctx, sig)
+}
+
+// ML-DSA-87 re-exports
+#[cfg(feature = "ml-dsa-87")]
+pub use ml_dsa::{
+    generate_key_pair_87 as dilithium87_generate_key_pair_internal,
+    sign_87 as dilithium87_sign_internal, verify_87 as dilithium87_verify_internal,
+    Dilithium87Keypair, Dilithium87Signature, Dilithium87SigningKey, Dilithium87VerifyingKey,
+};
+
+/// Generates an ML-DSA-87 key pair (Authenticated).
+#[cfg(feature = "ml-dsa-87")]
+pub fn dilithium87_generate_key_pair(seed: [u8; 32]) -> Result<Dilithium87Keypair> {
+    FipsModule::global().dilithium87_generate_key_pair(seed)
+}
+
+/// Signs a message with ML-DSA-87 (Authenticated).
+#[cfg(feature = "ml-dsa-87")]
+pub fn dilithium87_sign(
+    sk: &Dilithium87SigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+    randomness: [u8; 32],
+) -> Result<Dilithium87Signature> {
+    FipsModule::global().dilithium87_sign(sk, msg, ctx, randomness)
+}
+
+/// Verifies an ML-DSA-87 signature (Authenticated).
+#[cfg(feature = "ml-dsa-87")]
+pub fn dilithium87_verify(
+    pk: &Dilithium87VerifyingKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &Dilithium87Signature,
+) -> Result<()> {
+    FipsModule::global().dilithium87_verify(pk, msg, ctx, sig)
 }
 
+// SLH-DSA-SHAKE-128s re-exports
+#[cfg(feature = "slh-dsa")]
+pub use slh_dsa::{
+    generate_key_pair_shake_128s as slh_dsa_shake_128s_generate_key_pair_internal,
+    sign_shake_128s as slh_dsa_shake_128s_sign_internal,
+    verify_shake_128s as slh_dsa_shake_128s_verify_internal, SlhDsaShake128sKeypair,
+    SlhDsaShake128sSignature, SlhDsaShake128sSigningKey, SlhDsaShake128sVerifyingKey,
+};
+
+/// Generates an SLH-DSA-SHAKE-128s key pair from SK.seed || SK.prf || PK.seed (Authenticated).
+#[cfg(feature = "slh-dsa")]
+pub fn slh_dsa_shake_128s_generate_key_pair(seed: [u8; 48]) -> Result<SlhDsaShake128sKeypair> {
+    FipsModule::global().slh_dsa_shake_128s_generate_key_pair(seed)
+}
+
+/// Signs a message with SLH-DSA-SHAKE-128s (Authenticated).
+#[cfg(feature = "slh-dsa")]
+pub fn slh_dsa_shake_128s_sign(
+    sk: &SlhDsaShake128sSigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+    randomness: [u8; 16],
+) -> Result<SlhDsaShake128sSignature> {
+    FipsModule::global().slh_dsa_shake_128s_sign(sk, msg, ctx, randomness)
+}
+
+/// Verifies an SLH-DSA-SHAKE-128s signature (Authenticated).
+#[cfg(feature = "slh-dsa")]
+pub fn slh_dsa_shake_128s_verify(
+    pk: &SlhDsaShake128sVerifyingKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &SlhDsaShake128sSignature,
+) -> Result<()> {
+    FipsModule::global().slh_dsa_shake_128s_verify(pk, msg, ctx, sig)
+}
+
+// SLH-DSA-SHAKE-256s re-exports
+#[cfg(feature = "slh-dsa")]
+pub use slh_dsa::{
+    generate_key_pair_shake_256s as slh_dsa_shake_256s_generate_key_pair_internal,
+    sign_shake_256s as slh_dsa_shake_256s_sign_internal,
+    verify_shake_256s as slh_dsa_shake_256s_verify_internal, SlhDsaShake256sKeypair,
+    SlhDsaShake256sSignature, SlhDsaShake256sSigningKey, SlhDsaShake256sVerifyingKey,
+};
+
+/// Generates an SLH-DSA-SHAKE-256s key pair from SK.seed || SK.prf || PK.seed (Authenticated).
+#[cfg(feature = "slh-dsa")]
+pub fn slh_dsa_shake_256s_generate_key_pair(seed: [u8; 96]) -> Result<SlhDsaShake256sKeypair> {
+    FipsModule::global().slh_dsa_shake_256s_generate_key_pair(seed)
+}
+
+/// Signs a message with SLH-DSA-SHAKE-256s (Authenticated).
+#[cfg(feature = "slh-dsa")]
+pub fn slh_dsa_shake_256s_sign(
+    sk: &SlhDsaShake256sSigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+    randomness: [u8; 32],
+) -> Result<SlhDsaShake256sSignature> {
+    FipsModule::global().slh_dsa_shake_256s_sign(sk, msg, ctx, randomness)
+}
+
+/// Verifies an SLH-DSA-SHAKE-256s signature (Authenticated).
+#[cfg(feature = "slh-dsa")]
+pub fn slh_dsa_shake_256s_verify(
+    pk: &SlhDsaShake256sVerifyingKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &SlhDsaShake256sSignature,
+) -> Result<()> {
+    FipsModule::global().slh_dsa_shake_256s_verify(pk, msg, ctx, sig)
+}
+
+// LMS/HSS re-exports
+#[cfg(feature = "lms")]
+pub use lms::{verify as lms_verify_internal, verify_hss as hss_verify_internal};
+
+/// Verifies an LMS signature (Authenticated).
+#[cfg(feature = "lms")]
+pub fn lms_verify(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    FipsModule::global().lms_verify(pk, msg, sig)
+}
+
+/// Verifies an HSS signature (Authenticated).
+#[cfg(feature = "lms")]
+pub fn hss_verify(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    FipsModule::global().hss_verify(pk, msg, sig)
+}
+
+// XMSS/XMSS^MT re-exports
+#[cfg(feature = "xmss")]
+pub use xmss::{verify as xmss_verify_internal, verify_mt as xmssmt_verify_internal};
+
+/// Verifies a detached XMSS signature (Authenticated).
+#[cfg(feature = "xmss")]
+pub fn xmss_verify(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    FipsModule::global().xmss_verify(pk, msg, sig)
+}
+
+/// Verifies a detached XMSS^MT signature (Authenticated).
+#[cfg(feature = "xmss")]
+pub fn xmssmt_verify(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    FipsModule::global().xmssmt_verify(pk, msg, sig)
+}
+
+// X-Wing re-exports
+#[cfg(feature = "xwing")]
+pub use xwing::{
+    decapsulate as xwing_decapsulate_internal, encapsulate as xwing_encapsulate_internal,
+    generate_key_pair as xwing_generate_key_pair_internal, XWingCiphertext, XWingKeypair,
+    XWingPrivateKey, XWingPublicKey, XWingSharedSecret,
+};
+
+/// Generates an X-Wing key pair from a 32-byte seed (Authenticated).
+#[cfg(feature = "xwing")]
+pub fn xwing_generate_key_pair(seed: [u8; XWING_SK_BYTES]) -> Result<XWingKeypair> {
+    FipsModule::global().xwing_generate_key_pair(seed)
+}
+
+/// Encapsulates an X-Wing shared secret (Authenticated).
+#[cfg(feature = "xwing")]
+pub fn xwing_encapsulate(
+    pk: &XWingPublicKey,
+    eseed: [u8; 64],
+) -> Result<(XWingCiphertext, XWingSharedSecret)> {
+    FipsModule::global().xwing_encapsulate(pk, eseed)
+}
+
+/// Decapsulates an X-Wing shared secret (Authenticated).
+#[cfg(feature = "xwing")]
+pub fn xwing_decapsulate(sk: &XWingPrivateKey, ct: &XWingCiphertext) -> Result<XWingSharedSecret> {
+    FipsModule::global().xwing_decapsulate(sk, ct)
+}
+
+// SecP256r1MLKEM768 re-exports
+#[cfg(feature = "ecdh-mlkem")]
+pub use ecdh_mlkem::{
+    decapsulate_p256_mlkem768 as secp256r1_mlkem768_decapsulate_internal,
+    encapsulate_p256_mlkem768 as secp256r1_mlkem768_encapsulate_internal,
+    generate_key_pair_p256_mlkem768 as secp256r1_mlkem768_generate_key_pair_internal,
+    SecP256r1MlKem768ClientShare, SecP256r1MlKem768Keypair, SecP256r1MlKem768PrivateKey,
+    SecP256r1MlKem768ServerShare, SecP256r1MlKem768SharedSecret,
+};
+
+/// Generates a SecP256r1MLKEM768 key pair and client `key_share` (Authenticated).
+#[cfg(feature = "ecdh-mlkem")]
+pub fn secp256r1_mlkem768_generate_key_pair(seed: [u8; 96]) -> Result<SecP256r1MlKem768Keypair> {
+    FipsModule::global().secp256r1_mlkem768_generate_key_pair(seed)
+}
+
+/// Answers a SecP256r1MLKEM768 client `key_share` with a server `key_share` and shared secret (Authenticated).
+#[cfg(feature = "ecdh-mlkem")]
+pub fn secp256r1_mlkem768_encapsulate(
+    client_share: &SecP256r1MlKem768ClientShare,
+    randomness: [u8; 64],
+) -> Result<(SecP256r1MlKem768ServerShare, SecP256r1MlKem768SharedSecret)> {
+    FipsModule::global().secp256r1_mlkem768_encapsulate(client_share, randomness)
+}
+
+/// Derives the SecP256r1MLKEM768 shared secret from a server `key_share` (Authenticated).
+#[cfg(feature = "ecdh-mlkem")]
+pub fn secp256r1_mlkem768_decapsulate(
+    sk: &SecP256r1MlKem768PrivateKey,
+    server_share: &SecP256r1MlKem768ServerShare,
+) -> Result<SecP256r1MlKem768SharedSecret> {
+    FipsModule::global().secp256r1_mlkem768_decapsulate(sk, server_share)
+}
+
+// SecP384r1MLKEM1024 re-exports
+#[cfg(feature = "ecdh-mlkem")]
+pub use ecdh_mlkem::{
+    decapsulate_p384_mlkem1024 as secp384r1_mlkem1024_decapsulate_internal,
+    encapsulate_p384_mlkem1024 as secp384r1_mlkem1024_encapsulate_internal,
+    generate_key_pair_p384_mlkem1024 as secp384r1_mlkem1024_generate_key_pair_internal,
+    SecP384r1MlKem1024ClientShare, SecP384r1MlKem1024Keypair, SecP384r1MlKem1024PrivateKey,
+    SecP384r1MlKem1024ServerShare, SecP384r1MlKem1024SharedSecret,
+};
+
+/// Generates a SecP384r1MLKEM1024 key pair and client `key_share` (Authenticated).
+#[cfg(feature = "ecdh-mlkem")]
+pub fn secp384r1_mlkem1024_generate_key_pair(seed: [u8; 112]) -> Result<SecP384r1MlKem1024Keypair> {
+    FipsModule::global().secp384r1_mlkem1024_generate_key_pair(seed)
+}
+
+/// Answers a SecP384r1MLKEM1024 client `key_share` with a server `key_share` and shared secret (Authenticated).
+#[cfg(feature = "ecdh-mlkem")]
+pub fn secp384r1_mlkem1024_encapsulate(
+    client_share: &SecP384r1MlKem1024ClientShare,
+    randomness: [u8; 80],
+) -> Result<(
+    SecP384r1MlKem1024ServerShare,
+    SecP384r1MlKem1024SharedSecret,
+)> {
+    FipsModule::global().secp384r1_mlkem1024_encapsulate(client_share, randomness)
+}
+
+/// Derives the SecP384r1MLKEM1024 shared secret from a server `key_share` (Authenticated).
+#[cfg(feature = "ecdh-mlkem")]
+pub fn secp384r1_mlkem1024_decapsulate(
+    sk: &SecP384r1MlKem1024PrivateKey,
+    server_share: &SecP384r1MlKem1024ServerShare,
+) -> Result<SecP384r1MlKem1024SharedSecret> {
+    FipsModule::global().secp384r1_mlkem1024_decapsulate(sk, server_share)
+}
+
+// MLDSA65-ECDSA-P256-SHA512 re-exports
+#[cfg(feature = "composite")]
+pub use composite::{
+    generate_key_pair_mldsa65_ecdsa_p256 as mldsa65_ecdsa_p256_generate_key_pair_internal,
+    sign_mldsa65_ecdsa_p256 as mldsa65_ecdsa_p256_sign_internal,
+    verify_mldsa65_ecdsa_p256 as mldsa65_ecdsa_p256_verify_internal, MlDsa65EcdsaP256Keypair,
+    MlDsa65EcdsaP256Signature, MlDsa65EcdsaP256SigningKey, MlDsa65EcdsaP256VerifyingKey,
+};
+
+/// Generates a MLDSA65-ECDSA-P256-SHA512 composite key pair (Authenticated).
+#[cfg(feature = "composite")]
+pub fn mldsa65_ecdsa_p256_generate_key_pair(seed: [u8; 64]) -> Result<MlDsa65EcdsaP256Keypair> {
+    FipsModule::global().mldsa65_ecdsa_p256_generate_key_pair(seed)
+}
+
+/// Signs a message with MLDSA65-ECDSA-P256-SHA512 (Authenticated).
+#[cfg(feature = "composite")]
+pub fn mldsa65_ecdsa_p256_sign(
+    sk: &MlDsa65EcdsaP256SigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+    randomness: [u8; 32],
+) -> Result<MlDsa65EcdsaP256Signature> {
+    FipsModule::global().mldsa65_ecdsa_p256_sign(sk, msg, ctx, randomness)
+}
+
+/// Verifies a MLDSA65-ECDSA-P256-SHA512 signature; both components must verify (Authenticated).
+#[cfg(feature = "composite")]
+pub fn mldsa65_ecdsa_p256_verify(
+    pk: &MlDsa65EcdsaP256VerifyingKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &MlDsa65EcdsaP256Signature,
+) -> Result<()> {
+    FipsModule::global().mldsa65_ecdsa_p256_verify(pk, msg, ctx, sig)
+}
+
+// MLDSA65-Ed25519-SHA512 re-exports
+#[cfg(feature = "composite")]
+pub use composite::{
+    generate_key_pair_mldsa65_ed25519 as mldsa65_ed25519_generate_key_pair_internal,
+    sign_mldsa65_ed25519 as mldsa65_ed25519_sign_internal,
+    verify_mldsa65_ed25519 as mldsa65_ed25519_verify_internal, MlDsa65Ed25519Keypair,
+    MlDsa65Ed25519Signature, MlDsa65Ed25519SigningKey, MlDsa65Ed25519VerifyingKey,
+};
+
+/// Generates a MLDSA65-Ed25519-SHA512 composite key pair (Authenticated).
+#[cfg(feature = "composite")]
+pub fn mldsa65_ed25519_generate_key_pair(seed: [u8; 64]) -> Result<MlDsa65Ed25519Keypair> {
+    FipsModule::global().mldsa65_ed25519_generate_key_pair(seed)
+}
+
+/// Signs a message with MLDSA65-Ed25519-SHA512 (Authenticated).
+#[cfg(feature = "composite")]
+pub fn mldsa65_ed25519_sign(
+    sk: &MlDsa65Ed25519SigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+    randomness: [u8; 32],
+) -> Result<MlDsa65Ed25519Signature> {
+    FipsModule::global().mldsa65_ed25519_sign(sk, msg, ctx, randomness)
+}
+
+/// Verifies a MLDSA65-Ed25519-SHA512 signature; both components must verify (Authenticated).
+#[cfg(feature = "composite")]
+pub fn mldsa65_ed25519_verify(
+    pk: &MlDsa65Ed25519VerifyingKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &MlDsa65Ed25519Signature,
+) -> Result<()> {
+    FipsModule::global().mldsa65_ed25519_verify(pk, msg, ctx, sig)
+}
+
+// HPKE re-exports
+#[cfg(feature = "hpke")]
+pub use hpke::{
+    setup_recipient_mlkem1024 as hpke_setup_recipient_mlkem1024_internal,
+    setup_sender_mlkem1024 as hpke_setup_sender_mlkem1024_internal, HpkeAead, HpkeKdf, HpkeMode,
+    HpkeRecipientContext, HpkeSenderContext, HpkeSuite,
+};
+#[cfg(all(feature = "hpke", feature = "xwing"))]
+pub use hpke::{
+    setup_recipient_xwing as hpke_setup_recipient_xwing_internal,
+    setup_sender_xwing as hpke_setup_sender_xwing_internal,
+};
+
+/// Sets up an HPKE sender context to an ML-KEM-1024 public key and returns
+/// `enc` with it (Authenticated).
+#[cfg(feature = "hpke")]
+pub fn hpke_setup_sender_mlkem1024(
+    suite: HpkeSuite,
+    mode: HpkeMode<'_>,
+    pk: &KyberPublicKey,
+    info: &[u8],
+    randomness: [u8; 32],
+) -> Result<(KyberCiphertext, HpkeSenderContext)> {
+    FipsModule::global().hpke_setup_sender_mlkem1024(suite, mode, pk, info, randomness)
+}
+
+/// Sets up an HPKE recipient context from an ML-KEM-1024 `enc` (Authenticated).
+#[cfg(feature = "hpke")]
+pub fn hpke_setup_recipient_mlkem1024(
+    suite: HpkeSuite,
+    mode: HpkeMode<'_>,
+    sk: &KyberPrivateKey,
+    enc: &KyberCiphertext,
+    info: &[u8],
+) -> Result<HpkeRecipientContext> {
+    FipsModule::global().hpke_setup_recipient_mlkem1024(suite, mode, sk, enc, info)
+}
+
+/// Sets up an HPKE sender context to an X-Wing public key and returns `enc`
+/// with it (Authenticated).
+#[cfg(all(feature = "hpke", feature = "xwing"))]
+pub fn hpke_setup_sender_xwing(
+    suite: HpkeSuite,
+    mode: HpkeMode<'_>,
+    pk: &XWingPublicKey,
+    info: &[u8],
+    eseed: [u8; 64],
+) -> Result<(XWingCiphertext, HpkeSenderContext)> {
+    FipsModule::global().hpke_setup_sender_xwing(suite, mode, pk, info, eseed)
+}
+
+/// Sets up an HPKE recipient context from an X-Wing `enc` (Authenticated).
+#[cfg(all(feature = "hpke", feature = "xwing"))]
+pub fn hpke_setup_recipient_xwing(
+    suite: HpkeSuite,
+    mode: HpkeMode<'_>,
+    sk: &XWingPrivateKey,
+    enc: &XWingCiphertext,
+    info: &[u8],
+) -> Result<HpkeRecipientContext> {
+    FipsModule::global().hpke_setup_recipient_xwing(suite, mode, sk, enc, info)
+}
+
+/// Seals a message with an HPKE sender context into `out`, returning the
+/// ciphertext length (Authenticated).
+#[cfg(feature = "hpke")]
+pub fn hpke_seal(
+    ctx: &mut HpkeSenderContext,
+    aad: &[u8],
+    plaintext: &[u8],
+    out: &mut [u8],
+) -> Result<usize> {
+    FipsModule::global().hpke_seal(ctx, aad, plaintext, out)
+}
+
+/// Opens a message with an HPKE recipient context into `out`, returning the
+/// plaintext length (Authenticated).
+#[cfg(feature = "hpke")]
+pub fn hpke_open(
+    ctx: &mut HpkeRecipientContext,
+    aad: &[u8],
+    ciphertext: &[u8],
+    out: &mut [u8],
+) -> Result<usize> {
+    FipsModule::global().hpke_open(ctx, aad, ciphertext, out)
+}
+
+/// Exports a secret from an HPKE sender context into `out` (Authenticated).
+#[cfg(feature = "hpke")]
+pub fn hpke_sender_export(
+    ctx: &HpkeSenderContext,
+    exporter_context: &[u8],
+    out: &mut [u8],
+) -> Result<()> {
+    FipsModule::global().hpke_sender_export(ctx, exporter_context, out)
+}
+
+/// Exports a secret from an HPKE recipient context into `out` (Authenticated).
+#[cfg(feature = "hpke")]
+pub fn hpke_recipient_export(
+    ctx: &HpkeRecipientContext,
+    exporter_context: &[u8],
+    out: &mut [u8],
+) -> Result<()> {
+    FipsModule::global().hpke_recipient_export(ctx, exporter_context, out)
+}
+
+/// HPKE KEM ID of ML-KEM-1024 (draft-ietf-hpke-pq).
+pub const HPKE_KEM_ML_KEM_1024: u16 = 0x0042;
+/// HPKE KEM ID of X-Wing (draft-connolly-cfrg-xwing-kem).
+pub const HPKE_KEM_XWING: u16 = 0x647A;
+/// HPKE AEAD authentication tag size in bytes.
+pub const HPKE_TAG_BYTES: usize = 16;
+
 /// ML-KEM-1024 public key size in bytes.
 pub const ML_KEM_1024_PK_BYTES: usize = 1568;
 /// ML-KEM-1024 secret key size in bytes.
@@ -168,6 +940,24 @@ pub const ML_KEM_1024_CT_BYTES: usize = 1568;
 /// ML-KEM-1024 shared secret size in bytes.
 pub const ML_KEM_1024_SS_BYTES: usize = 32;
 
+/// ML-KEM-768 public key size in bytes.
+pub const ML_KEM_768_PK_BYTES: usize = 1184;
+/// ML-KEM-768 secret key size in bytes.
+pub const ML_KEM_768_SK_BYTES: usize = 2400;
+/// ML-KEM-768 ciphertext size in bytes.
+pub const ML_KEM_768_CT_BYTES: usize = 1088;
+/// ML-KEM-768 shared secret size in bytes.
+pub const ML_KEM_768_SS_BYTES: usize = 32;
+
+/// ML-KEM-512 public key size in bytes.
+pub const ML_KEM_512_PK_BYTES: usize = 800;
+/// ML-KEM-512 secret key size in bytes.
+pub const ML_KEM_512_SK_BYTES: usize = 1632;
+/// ML-KEM-512 ciphertext size in bytes.
+pub const ML_KEM_512_CT_BYTES: usize = 768;
+/// ML-KEM-512 shared secret size in bytes.
+pub const ML_KEM_512_SS_BYTES: usize = 32;
+
 /// ML-DSA-65 public key size in bytes.
 pub const ML_DSA_65_PK_BYTES: usize = 1952;
 /// ML-DSA-65 secret key size in bytes.
@@ -175,14 +965,105 @@ pub const ML_DSA_65_SK_BYTES: usize = 4032;
 /// ML-DSA-65 signature size in bytes.
 pub const ML_DSA_65_SIG_BYTES: usize = 3309;
 
+/// ML-DSA-44 public key size in bytes.
+pub const ML_DSA_44_PK_BYTES: usize = 1312;
+/// ML-DSA-44 secret key size in bytes.
+pub const ML_DSA_44_SK_BYTES: usize = 2560;
+/// ML-DSA-44 signature size in bytes.
+pub const ML_DSA_44_SIG_BYTES: usize = 2420;
+
+/// ML-DSA-87 public key size in bytes.
+pub const ML_DSA_87_PK_BYTES: usize = 2592;
+/// ML-DSA-87 secret key size in bytes.
+pub const ML_DSA_87_SK_BYTES: usize = 4896;
+/// ML-DSA-87 signature size in bytes.
+pub const ML_DSA_87_SIG_BYTES: usize = 4627;
+
+/// SLH-DSA-SHAKE-128s public key size in bytes.
+pub const SLH_DSA_SHAKE_128S_PK_BYTES: usize = 32;
+/// SLH-DSA-SHAKE-128s secret key size in bytes.
+pub const SLH_DSA_SHAKE_128S_SK_BYTES: usize = 64;
+/// SLH-DSA-SHAKE-128s signature size in bytes.
+pub const SLH_DSA_SHAKE_128S_SIG_BYTES: usize = 7856;
+
+/// SLH-DSA-SHAKE-256s public key size in bytes.
+pub const SLH_DSA_SHAKE_256S_PK_BYTES: usize = 64;
+/// SLH-DSA-SHAKE-256s secret key size in bytes.
+pub const SLH_DSA_SHAKE_256S_SK_BYTES: usize = 128;
+/// SLH-DSA-SHAKE-256s signature size in bytes.
+pub const SLH_DSA_SHAKE_256S_SIG_BYTES: usize = 29792;
+
+/// X-Wing public key size in bytes (ML-KEM-768 key || X25519 key).
+pub const XWING_PK_BYTES: usize = 1216;
+/// X-Wing secret key (seed) size in bytes.
+pub const XWING_SK_BYTES: usize = 32;
+/// X-Wing ciphertext size in bytes (ML-KEM-768 ciphertext || X25519 share).
+pub const XWING_CT_BYTES: usize = 1120;
+/// X-Wing shared secret size in bytes.
+pub const XWING_SS_BYTES: usize = 32;
+
+/// TLS `NamedGroup` code point for SecP256r1MLKEM768.
+pub const SECP256R1_MLKEM768_GROUP_ID: u16 = 0x11EB;
+/// SecP256r1MLKEM768 client `key_share` size in bytes (65-byte point || 1184-byte key).
+pub const SECP256R1_MLKEM768_CLIENT_SHARE_BYTES: usize = 1249;
+/// SecP256r1MLKEM768 server `key_share` size in bytes (65-byte point || 1088-byte ciphertext).
+pub const SECP256R1_MLKEM768_SERVER_SHARE_BYTES: usize = 1153;
+/// SecP256r1MLKEM768 private key size in bytes (32-byte scalar || 2400-byte key).
+pub const SECP256R1_MLKEM768_SK_BYTES: usize = 2432;
+/// SecP256r1MLKEM768 shared secret size in bytes.
+pub const SECP256R1_MLKEM768_SS_BYTES: usize = 64;
+
+/// TLS `NamedGroup` code point for SecP384r1MLKEM1024.
+pub const SECP384R1_MLKEM1024_GROUP_ID: u16 = 0x11ED;
+/// SecP384r1MLKEM1024 client `key_share` size in bytes (97-byte point || 1568-byte key).
+pub const SECP384R1_MLKEM1024_CLIENT_SHARE_BYTES: usize = 1665;
+/// SecP384r1MLKEM1024 server `key_share` size in bytes (97-byte point || 1568-byte ciphertext).
+pub const SECP384R1_MLKEM1024_SERVER_SHARE_BYTES: usize = 1665;
+/// SecP384r1MLKEM1024 private key size in bytes (48-byte scalar || 3168-byte key).
+pub const SECP384R1_MLKEM1024_SK_BYTES: usize = 3216;
+/// SecP384r1MLKEM1024 shared secret size in bytes.
+pub const SECP384R1_MLKEM1024_SS_BYTES: usize = 80;
+
+/// MLDSA65-ECDSA-P256-SHA512 public key size in bytes (1952-byte key || 65-byte point).
+pub const MLDSA65_ECDSA_P256_PK_BYTES: usize = 2017;
+/// MLDSA65-ECDSA-P256-SHA512 secret key size in bytes (32-byte seed || 39-byte `ECPrivateKey`).
+pub const MLDSA65_ECDSA_P256_SK_BYTES: usize = 71;
+/// MLDSA65-ECDSA-P256-SHA512 maximum signature size in bytes (3309 bytes || DER ECDSA).
+pub const MLDSA65_ECDSA_P256_SIG_MAX_BYTES: usize = 3381;
+
+/// MLDSA65-Ed25519-SHA512 public key size in bytes (1952-byte key || 32-byte key).
+pub const MLDSA65_ED25519_PK_BYTES: usize = 1984;
+/// MLDSA65-Ed25519-SHA512 secret key size in bytes (32-byte seed || 32-byte key).
+pub const MLDSA65_ED25519_SK_BYTES: usize = 64;
+/// MLDSA65-Ed25519-SHA512 signature size in bytes (3309 bytes || 64 bytes).
+pub const MLDSA65_ED25519_SIG_BYTES: usize = 3373;
+
 pub use error::{PqcError, Result};
 
-#[cfg(feature = "ml-kem")]
-pub use pct::kyber_pct;
+#[cfg(feature = "ml-dsa-44")]
+pub use pct::dilithium44_pct;
+#[cfg(feature = "ml-dsa-87")]
+pub use pct::dilithium87_pct;
 #[cfg(feature = "ml-dsa")]
 pub use pct::dilithium_pct;
+#[cfg(feature = "ml-kem-512")]
+pub use pct::kyber512_pct;
+#[cfg(feature = "ml-kem-768")]
+pub use pct::kyber768_pct;
+#[cfg(feature = "ml-kem")]
+pub use pct::kyber_pct;
+#[cfg(feature = "xwing")]
+pub use pct::xwing_pct;
+#[cfg(feature = "composite")]
+pub use pct::{mldsa65_ecdsa_p256_pct, mldsa65_ed25519_pct};
+#[cfg(feature = "ecdh-mlkem")]
+pub use pct::{secp256r1_mlkem768_pct, secp384r1_mlkem1024_pct};
+#[cfg(feature = "slh-dsa")]
+pub use pct::{slh_dsa_shake_128s_pct, slh_dsa_shake_256s_pct};
 
-pub use preop::{run_post, run_post_or_panic};
+pub use indicator::{last_service_approved, last_service_indicator, ServiceIndicator};
+pub use module::FipsModule;
+pub use preop::{run_post, run_post_or_panic, run_post_with_entropy};
 pub use state::{get_fips_state, is_operational, FipsState};
 
 // CSP aliases – only one definition each
@@ -0,0 +1,254 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/lms.rs
+//! LMS and HSS stateful hash-based signature verification (RFC 8554,
+//! NIST SP 800-208).
+//!
+//! Covers the SHA-256, SHA-256/192, SHAKE256/256 and SHAKE256/192 parameter
+//! sets of SP 800-208 with tree heights 5 to 25 and Winternitz widths 1, 2,
+//! 4 and 8. Keys and signatures are parsed in place, so verification never
+//! allocates.
+//!
+//! Signing is not offered: SP 800-208 requires LMS/HSS signatures to be
+//! generated in a hardware module that never exports the one-time key state.
+
+use crate::error::{PqcError, Result};
+use crate::hbs::{HashFamily, Hasher};
+
+/// Largest hash output n (= m) in bytes.
+const MAX_N: usize = 32;
+/// Largest number of levels in an HSS tree (RFC 8554, Section 6).
+const MAX_HSS_LEVELS: u32 = 8;
+
+// Domain separators (RFC 8554, Section 7.1)
+const D_PBLC: [u8; 2] = [0x80, 0x80];
+const D_MESG: [u8; 2] = [0x81, 0x81];
+const D_LEAF: [u8; 2] = [0x82, 0x82];
+const D_INTR: [u8; 2] = [0x83, 0x83];
+
+/// LM-OTS parameter set (SP 800-208, Section 4.1).
+struct OtsParams {
+    family: HashFamily,
+    n: usize,
+    w: usize,
+    p: usize,
+    ls: u32,
+}
+
+impl OtsParams {
+    fn from_type(ots_type: u32) -> Option<Self> {
+        let (family, n) = match ots_type {
+            0x01..=0x04 => (HashFamily::Sha256, 32),
+            0x05..=0x08 => (HashFamily::Sha256, 24),
+            0x09..=0x0c => (HashFamily::Shake256, 32),
+            0x0d..=0x10 => (HashFamily::Shake256, 24),
+            _ => return None,
+        };
+        let (w, p, ls) = match ((ots_type - 1) % 4, n) {
+            (0, 32) => (1, 265, 7),
+            (1, 32) => (2, 133, 6),
+            (2, 32) => (4, 67, 4),
+            (3, 32) => (8, 34, 0),
+            (0, _) => (1, 200, 8),
+            (1, _) => (2, 101, 6),
+            (2, _) => (4, 51, 4),
+            _ => (8, 26, 0),
+        };
+        Some(Self {
+            family,
+            n,
+            w,
+            p,
+            ls,
+        })
+    }
+
+    /// Length of an LM-OTS signature: type || C || y[0..p].
+    fn sig_bytes(&self) -> usize {
+        4 + self.n * (1 + self.p)
+    }
+}
+
+/// LMS parameter set (SP 800-208, Section 4.2).
+struct LmsParams {
+    family: HashFamily,
+    m: usize,
+    h: usize,
+}
+
+impl LmsParams {
+    fn from_type(lms_type: u32) -> Option<Self> {
+        let (family, m) = match lms_type {
+            0x05..=0x09 => (HashFamily::Sha256, 32),
+            0x0a..=0x0e => (HashFamily::Sha256, 24),
+            0x0f..=0x13 => (HashFamily::Shake256, 32),
+            0x14..=0x18 => (HashFamily::Shake256, 24),
+            _ => return None,
+        };
+        let h = 5 * (1 + (lms_type - 5) as usize % 5);
+        Some(Self { family, m, h })
+    }
+
+    /// Length of an LMS public key: type || otstype || I || T[1].
+    fn public_key_bytes(&self) -> usize {
+        24 + self.m
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32> {
+    bytes
+        .get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(PqcError::InvalidParameter)
+}
+
+/// Returns the w-bit digit `i` of `s` (RFC 8554, Section 3.1.3).
+fn coef(s: &[u8], i: usize, w: usize) -> usize {
+    let mask = (1 << w) - 1;
+    let shift = 8 - (w * (i % (8 / w)) + w);
+    (s[i * w / 8] as usize >> shift) & mask
+}
+
+/// Computes the LM-OTS public key candidate Kc (RFC 8554, Algorithm 4b).
+fn ots_public_key_candidate(
+    p: &OtsParams,
+    id: &[u8],
+    q: u32,
+    msg: &[u8],
+    sig: &[u8],
+    out: &mut [u8],
+) {
+    let n = p.n;
+    let q = q.to_be_bytes();
+    let (c, y) = sig[4..].split_at(n);
+
+    // Q || Cksm(Q)
+    let mut digits = [0u8; MAX_N + 2];
+    Hasher::new(p.family)
+        .chain(id)
+        .chain(&q)
+        .chain(&D_MESG)
+        .chain(c)
+        .chain(msg)
+        .finalize(&mut digits[..n]);
+    let max = (1 << p.w) - 1;
+    let sum: u32 = (0..n * 8 / p.w)
+        .map(|i| (max - coef(&digits, i, p.w)) as u32)
+        .sum();
+    digits[n..n + 2].copy_from_slice(&((sum << p.ls) as u16).to_be_bytes());
+
+    let mut kc = Hasher::new(p.family).chain(id).chain(&q).chain(&D_PBLC);
+    let mut tmp = [0u8; MAX_N];
+    for (i, y_i) in y.chunks_exact(n).enumerate() {
+        tmp[..n].copy_from_slice(y_i);
+        for j in coef(&digits, i, p.w)..max {
+            Hasher::new(p.family)
+                .chain(id)
+                .chain(&q)
+                .chain(&(i as u16).to_be_bytes())
+                .chain(&[j as u8])
+                .chain(&tmp[..n])
+                .finalize(&mut tmp[..n]);
+        }
+        kc = kc.chain(&tmp[..n]);
+    }
+    kc.finalize(out);
+}
+
+/// Returns the length of the LMS signature at the start of `sig`.
+fn lms_signature_len(sig: &[u8]) -> Result<usize> {
+    let ots = OtsParams::from_type(read_u32(sig, 4)?).ok_or(PqcError::InvalidParameter)?;
+    let ots_end = 4 + ots.sig_bytes();
+    let lms = LmsParams::from_type(read_u32(sig, ots_end)?).ok_or(PqcError::InvalidParameter)?;
+    Ok(ots_end + 4 + lms.h * lms.m)
+}
+
+/// Returns the length of the LMS public key at the start of `pk`.
+fn lms_public_key_len(pk: &[u8]) -> Result<usize> {
+    let lms = LmsParams::from_type(read_u32(pk, 0)?).ok_or(PqcError::InvalidParameter)?;
+    Ok(lms.public_key_bytes())
+}
+
+/// Verifies an LMS signature (RFC 8554, Algorithm 6a) (Unauthenticated).
+///
+/// Malformed keys or signatures, or a signature whose parameter sets do not
+/// match the key, give [`PqcError::InvalidParameter`].
+pub fn verify(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    let lms_type = read_u32(pk, 0)?;
+    let ots_type = read_u32(pk, 4)?;
+    let lms = LmsParams::from_type(lms_type).ok_or(PqcError::InvalidParameter)?;
+    let ots = OtsParams::from_type(ots_type).ok_or(PqcError::InvalidParameter)?;
+    if pk.len() != lms.public_key_bytes() || lms.family != ots.family || lms.m != ots.n {
+        return Err(PqcError::InvalidParameter);
+    }
+    if read_u32(sig, 4)? != ots_type || sig.len() != lms_signature_len(sig)? {
+        return Err(PqcError::InvalidParameter);
+    }
+    let ots_end = 4 + ots.sig_bytes();
+    if read_u32(sig, ots_end)? != lms_type {
+        return Err(PqcError::InvalidParameter);
+    }
+    let q = read_u32(sig, 0)?;
+    if q >= 1 << lms.h {
+        return Err(PqcError::InvalidParameter);
+    }
+
+    let (id, root) = pk[8..].split_at(16);
+    let m = lms.m;
+    let mut node = [0u8; MAX_N];
+    ots_public_key_candidate(&ots, id, q, msg, &sig[4..ots_end], &mut node[..m]);
+
+    // Walk from the leaf to the root along the authentication path.
+    let mut node_num = (1u32 << lms.h) + q;
+    Hasher::new(lms.family)
+        .chain(id)
+        .chain(&node_num.to_be_bytes())
+        .chain(&D_LEAF)
+        .chain(&node[..m])
+        .finalize(&mut node[..m]);
+    for sibling in sig[ots_end + 4..].chunks_exact(m) {
+        let parent = Hasher::new(lms.family)
+            .chain(id)
+            .chain(&(node_num / 2).to_be_bytes())
+            .chain(&D_INTR);
+        let parent = if node_num % 2 == 1 {
+            parent.chain(sibling).chain(&node[..m])
+        } else {
+            parent.chain(&node[..m]).chain(sibling)
+        };
+        parent.finalize(&mut node[..m]);
+        node_num /= 2;
+    }
+
+    if node[..m] == *root {
+        Ok(())
+    } else {
+        Err(PqcError::FipsErrorState)
+    }
+}
+
+/// Verifies an HSS signature (RFC 8554, Algorithm 8) (Unauthenticated).
+///
+/// Every signed intermediate public key is checked before the bottom-level
+/// signature over `msg`. Malformed encodings give [`PqcError::InvalidParameter`].
+pub fn verify_hss(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    let levels = read_u32(pk, 0)?;
+    if !(1..=MAX_HSS_LEVELS).contains(&levels) || read_u32(sig, 0)? != levels - 1 {
+        return Err(PqcError::InvalidParameter);
+    }
+
+    let mut key = &pk[4..];
+    let mut rest = &sig[4..];
+    for _ in 1..levels {
+        let (lms_sig, tail) = rest.split_at(lms_signature_len(rest)?.min(rest.len()));
+        let (next_key, tail) = tail.split_at(lms_public_key_len(tail)?.min(tail.len()));
+        verify(key, next_key, lms_sig)?;
+        key = next_key;
+        rest = tail;
+    }
+    verify(key, msg, rest)
+}
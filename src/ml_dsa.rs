@@ -16,5 +16,415 @@ pub use libcrux_ml_dsa::ml_dsa_65::{
     MLDSA65SigningKey as DilithiumSigningKey, MLDSA65VerificationKey as DilithiumVerifyingKey,
 };
 
+#[cfg(feature = "ml-dsa-44")]
+pub use libcrux_ml_dsa::ml_dsa_44::portable::{
+    generate_key_pair as generate_key_pair_44, sign as sign_44, verify as verify_44,
+};
+
+#[cfg(feature = "ml-dsa-44")]
+pub use libcrux_ml_dsa::ml_dsa_44::{
+    MLDSA44KeyPair as Dilithium44Keypair, MLDSA44Signature as Dilithium44Signature,
+    MLDSA44SigningKey as Dilithium44SigningKey, MLDSA44VerificationKey as Dilithium44VerifyingKey,
+};
+
+#[cfg(feature = "ml-dsa-87")]
+pub use libcrux_ml_dsa::ml_dsa_87::portable::{
+    generate_key_pair as generate_key_pair_87, sign as sign_87, verify as verify_87,
+};
+
+#[cfg(feature = "ml-dsa-87")]
+pub use libcrux_ml_dsa::ml_dsa_87::{
+    MLDSA87KeyPair as Dilithium87Keypair, MLDSA87Signature as Dilithium87Signature,
+    MLDSA87SigningKey as Dilithium87SigningKey, MLDSA87VerificationKey as Dilithium87VerifyingKey,
+};
+
 /// Context string for FIPS 140-3 operations (empty for pure ML-DSA).
 pub const FIPS_CONTEXT: &[u8] = b"";
+
+/// Pre-hash functions approved for HashML-DSA (FIPS 204, Section 5.4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreHashAlgorithm {
+    /// SHA-256 (OID 2.16.840.1.101.3.4.2.1).
+    Sha256,
+    /// SHA-512 (OID 2.16.840.1.101.3.4.2.3).
+    Sha512,
+    /// SHAKE128 with 256-bit output (OID 2.16.840.1.101.3.4.2.11).
+    Shake128,
+}
+
+impl PreHashAlgorithm {
+    /// DER encoding of the hash function OID, as prepended to the digest.
+    pub const fn oid(self) -> &'static [u8; 11] {
+        match self {
+            PreHashAlgorithm::Sha256 => &[
+                0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+            ],
+            PreHashAlgorithm::Sha512 => &[
+                0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03,
+            ],
+            PreHashAlgorithm::Shake128 => &[
+                0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0B,
+            ],
+        }
+    }
+
+    /// Looks up the algorithm identified by a DER-encoded OID.
+    pub fn from_oid(oid: &[u8]) -> Option<Self> {
+        [Self::Sha256, Self::Sha512, Self::Shake128]
+            .into_iter()
+            .find(|alg| alg.oid()[..] == *oid)
+    }
+
+    /// Digest length in bytes expected for this algorithm.
+    pub const fn digest_len(self) -> usize {
+        match self {
+            PreHashAlgorithm::Sha256 => 32,
+            PreHashAlgorithm::Sha512 => 64,
+            PreHashAlgorithm::Shake128 => 32,
+        }
+    }
+}
+
+/// Maximum length of the HashML-DSA formatted message M'.
+#[cfg(feature = "ml-dsa")]
+const MAX_PREHASH_MESSAGE_BYTES: usize = 2 + 255 + 11 + 64;
+
+/// Builds M' = 1 || |ctx| || ctx || OID || PH(M) into `buf` (FIPS 204, Algorithm 4).
+#[cfg(feature = "ml-dsa")]
+fn prehash_message<'a>(
+    buf: &'a mut [u8; MAX_PREHASH_MESSAGE_BYTES],
+    digest: &[u8],
+    hash_alg: PreHashAlgorithm,
+    ctx: &[u8],
+) -> crate::error::Result<&'a [u8]> {
+    if ctx.len() > 255 || digest.len() != hash_alg.digest_len() {
+        return Err(crate::error::PqcError::InvalidParameter);
+    }
+    let oid = hash_alg.oid();
+    let mut len = 0;
+    for part in [&[1u8, ctx.len() as u8][..], ctx, &oid[..], digest] {
+        buf[len..len + part.len()].copy_from_slice(part);
+        len += part.len();
+    }
+    Ok(&buf[..len])
+}
+
+/// Signs a message with the deterministic variant of ML-DSA-65, rnd = 0^32 (Unauthenticated).
+#[cfg(feature = "ml-dsa")]
+pub fn sign_deterministic(
+    sk: &DilithiumSigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+) -> crate::error::Result<DilithiumSignature> {
+    sign(sk, msg, ctx, [0u8; 32]).map_err(|_| crate::error::PqcError::FipsErrorState)
+}
+
+/// Signs a pre-hashed message with HashML-DSA-65 (Unauthenticated).
+#[cfg(feature = "ml-dsa")]
+pub fn sign_prehashed(
+    sk: &DilithiumSigningKey,
+    digest: &[u8],
+    hash_alg: PreHashAlgorithm,
+    ctx: &[u8],
+    randomness: [u8; 32],
+) -> crate::error::Result<DilithiumSignature> {
+    let mut buf = [0u8; MAX_PREHASH_MESSAGE_BYTES];
+    let m_prime = prehash_message(&mut buf, digest, hash_alg, ctx)?;
+    libcrux_ml_dsa::ml_dsa_65::portable::sign_internal(sk, m_prime, randomness)
+        .map_err(|_| crate::error::PqcError::FipsErrorState)
+}
+
+/// Verifies a HashML-DSA-65 signature over a pre-hashed message (Unauthenticated).
+#[cfg(feature = "ml-dsa")]
+pub fn verify_prehashed(
+    pk: &DilithiumVerifyingKey,
+    digest: &[u8],
+    hash_alg: PreHashAlgorithm,
+    ctx: &[u8],
+    sig: &DilithiumSignature,
+) -> crate::error::Result<()> {
+    let mut buf = [0u8; MAX_PREHASH_MESSAGE_BYTES];
+    let m_prime = prehash_message(&mut buf, digest, hash_alg, ctx)?;
+    libcrux_ml_dsa::ml_dsa_65::portable::verify_internal(pk, m_prime, sig)
+        .map_err(|_| crate::error::PqcError::FipsErrorState)
+}
+
+/// Message representative μ for ML-DSA-65 (FIPS 204, Algorithm 7, line 6).
+#[cfg(feature = "ml-dsa")]
+pub type DilithiumMu = [u8; 64];
+
+/// Starts the μ hash H(tr || 0 || |ctx| || ctx || ...) for a key with hash `tr`.
+#[cfg(feature = "ml-dsa")]
+fn mu_hasher(tr: &[u8], ctx: &[u8]) -> crate::error::Result<sha3::Shake256> {
+    use sha3::digest::Update;
+
+    if ctx.len() > 255 {
+        return Err(crate::error::PqcError::InvalidParameter);
+    }
+    let mut h = sha3::Shake256::default();
+    h.update(tr);
+    h.update(&[0, ctx.len() as u8]);
+    h.update(ctx);
+    Ok(h)
+}
+
+/// Finishes a μ hash started by [`mu_hasher`].
+#[cfg(feature = "ml-dsa")]
+fn finalize_mu(h: sha3::Shake256) -> DilithiumMu {
+    use sha3::digest::{ExtendableOutput, XofReader};
+
+    let mut mu = [0u8; 64];
+    h.finalize_xof().read(&mut mu);
+    mu
+}
+
+/// Computes tr = H(pk, 64).
+#[cfg(feature = "ml-dsa")]
+fn public_key_hash(pk: &DilithiumVerifyingKey) -> [u8; 64] {
+    use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+    let mut tr = [0u8; 64];
+    let mut h = sha3::Shake256::default();
+    h.update(pk.as_slice());
+    h.finalize_xof().read(&mut tr);
+    tr
+}
+
+/// Computes μ = H(H(pk) || 0 || |ctx| || ctx || M, 64) for ML-DSA-65 (Unauthenticated).
+///
+/// This is the only step of signing that touches the message, so it can run
+/// on a separate host from [`sign_mu`].
+#[cfg(feature = "ml-dsa")]
+pub fn compute_mu(
+    pk: &DilithiumVerifyingKey,
+    msg: &[u8],
+    ctx: &[u8],
+) -> crate::error::Result<DilithiumMu> {
+    use sha3::digest::Update;
+
+    let mut h = mu_hasher(&public_key_hash(pk), ctx)?;
+    h.update(msg);
+    Ok(finalize_mu(h))
+}
+
+/// Signs a precomputed μ with ML-DSA-65 (Unauthenticated).
+///
+/// For μ from [`compute_mu`] the signature equals [`sign`] over the same
+/// message, context and randomness.
+#[cfg(feature = "ml-dsa")]
+pub fn sign_mu(
+    sk: &DilithiumSigningKey,
+    mu: &DilithiumMu,
+    randomness: [u8; 32],
+) -> crate::error::Result<DilithiumSignature> {
+    let mut sig = DilithiumSignature::zero();
+    crate::ml_dsa_core::sign_mu::<6, 5>(
+        &crate::ml_dsa_core::ML_DSA_65,
+        sk.as_slice(),
+        mu,
+        &randomness,
+        sig.as_mut_slice(),
+    );
+    Ok(sig)
+}
+
+/// Verifies an ML-DSA-65 signature against a precomputed μ (Unauthenticated).
+#[cfg(feature = "ml-dsa")]
+pub fn verify_mu(
+    pk: &DilithiumVerifyingKey,
+    mu: &DilithiumMu,
+    sig: &DilithiumSignature,
+) -> crate::error::Result<()> {
+    if crate::ml_dsa_core::verify_mu::<6, 5>(
+        &crate::ml_dsa_core::ML_DSA_65,
+        pk.as_slice(),
+        mu,
+        sig.as_slice(),
+    ) {
+        Ok(())
+    } else {
+        Err(crate::error::PqcError::FipsErrorState)
+    }
+}
+
+/// Incremental ML-DSA-65 signer for messages that do not fit in memory (Authenticated).
+///
+/// The message is absorbed chunk by chunk into the μ hash, so only the
+/// SHAKE256 state is buffered. The result equals [`sign`] over the
+/// concatenated chunks.
+#[cfg(feature = "ml-dsa")]
+pub struct DilithiumSigner<'a> {
+    module: &'a crate::module::FipsModule,
+    sk: &'a DilithiumSigningKey,
+    hasher: sha3::Shake256,
+    approved: bool,
+}
+
+#[cfg(feature = "ml-dsa")]
+impl<'a> DilithiumSigner<'a> {
+    /// Starts signing with `sk` under context `ctx` on the global module.
+    pub fn new(sk: &'a DilithiumSigningKey, ctx: &[u8]) -> crate::error::Result<Self> {
+        crate::module::FipsModule::global().dilithium_signer(sk, ctx)
+    }
+
+    /// Absorbs the next chunk of the message.
+    pub fn update(&mut self, chunk: &[u8]) {
+        sha3::digest::Update::update(&mut self.hasher, chunk);
+    }
+
+    /// Signs the absorbed message.
+    pub fn finalize(self, randomness: [u8; 32]) -> crate::error::Result<DilithiumSignature> {
+        self.module.begin_service(self.approved)?;
+        sign_mu(self.sk, &finalize_mu(self.hasher), randomness)
+    }
+}
+
+/// Incremental ML-DSA-65 verifier, the counterpart of [`DilithiumSigner`] (Authenticated).
+#[cfg(feature = "ml-dsa")]
+pub struct DilithiumVerifier<'a> {
+    module: &'a crate::module::FipsModule,
+    pk: &'a DilithiumVerifyingKey,
+    hasher: sha3::Shake256,
+    approved: bool,
+}
+
+#[cfg(feature = "ml-dsa")]
+impl<'a> DilithiumVerifier<'a> {
+    /// Starts verifying against `pk` under context `ctx` on the global module.
+    pub fn new(pk: &'a DilithiumVerifyingKey, ctx: &[u8]) -> crate::error::Result<Self> {
+        crate::module::FipsModule::global().dilithium_verifier(pk, ctx)
+    }
+
+    /// Absorbs the next chunk of the message.
+    pub fn update(&mut self, chunk: &[u8]) {
+        sha3::digest::Update::update(&mut self.hasher, chunk);
+    }
+
+    /// Checks `sig` against the absorbed message.
+    pub fn finalize(self, sig: &DilithiumSignature) -> crate::error::Result<()> {
+        self.module.begin_service(self.approved)?;
+        verify_mu(self.pk, &finalize_mu(self.hasher), sig)
+    }
+}
+
+#[cfg(feature = "ml-dsa")]
+impl crate::module::FipsModule {
+    /// Starts an incremental ML-DSA-65 signature with `sk` under context `ctx`
+    /// (Authenticated).
+    pub fn dilithium_signer<'a>(
+        &'a self,
+        sk: &'a DilithiumSigningKey,
+        ctx: &[u8],
+    ) -> crate::error::Result<DilithiumSigner<'a>> {
+        self.begin_service(ctx.is_empty())?;
+        // sk = ρ || K || tr || ...
+        let hasher = mu_hasher(&sk.as_slice()[64..128], ctx)?;
+        Ok(DilithiumSigner {
+            module: self,
+            sk,
+            hasher,
+            approved: ctx.is_empty(),
+        })
+    }
+
+    /// Starts an incremental ML-DSA-65 verification against `pk` under context
+    /// `ctx` (Authenticated).
+    pub fn dilithium_verifier<'a>(
+        &'a self,
+        pk: &'a DilithiumVerifyingKey,
+        ctx: &[u8],
+    ) -> crate::error::Result<DilithiumVerifier<'a>> {
+        self.begin_service(ctx.is_empty())?;
+        let hasher = mu_hasher(&public_key_hash(pk), ctx)?;
+        Ok(DilithiumVerifier {
+            module: self,
+            pk,
+            hasher,
+            approved: ctx.is_empty(),
+        })
+    }
+}
+
+/// Implements [`SignatureScheme`](crate::traits::SignatureScheme) for a libcrux ML-DSA
+/// parameter set.
+macro_rules! impl_signature_scheme {
+    (
+        $(#[$doc:meta])*
+        $marker:ident, $name:literal, $module:ident,
+        $sk:ty, $vk:ty, $sig:ty, $kp:ty,
+        $pk_bytes:path, $sk_bytes:path, $sig_bytes:path
+    ) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $marker;
+
+        impl crate::traits::SignatureScheme for $marker {
+            const NAME: &'static str = $name;
+            const PK_BYTES: usize = $pk_bytes;
+            const SK_BYTES: usize = $sk_bytes;
+            const SIG_BYTES: usize = $sig_bytes;
+
+            type SigningKey = $sk;
+            type VerifyingKey = $vk;
+            type Signature = $sig;
+            type Keypair = $kp;
+
+            fn generate_key_pair_internal(seed: [u8; 32]) -> Self::Keypair {
+                libcrux_ml_dsa::$module::portable::generate_key_pair(seed)
+            }
+
+            fn sign_internal(
+                sk: &Self::SigningKey,
+                msg: &[u8],
+                ctx: &[u8],
+                randomness: [u8; 32],
+            ) -> crate::error::Result<Self::Signature> {
+                // Map libcrux error if any
+                libcrux_ml_dsa::$module::portable::sign(sk, msg, ctx, randomness)
+                    .map_err(|_| crate::error::PqcError::FipsErrorState)
+            }
+
+            fn verify_internal(
+                pk: &Self::VerifyingKey,
+                msg: &[u8],
+                ctx: &[u8],
+                sig: &Self::Signature,
+            ) -> crate::error::Result<()> {
+                libcrux_ml_dsa::$module::portable::verify(pk, msg, ctx, sig)
+                    .map_err(|_| crate::error::PqcError::FipsErrorState)
+            }
+
+            fn signing_key(kp: &Self::Keypair) -> &Self::SigningKey {
+                &kp.signing_key
+            }
+
+            fn verifying_key(kp: &Self::Keypair) -> &Self::VerifyingKey {
+                &kp.verification_key
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ml-dsa")]
+impl_signature_scheme!(
+    /// ML-DSA-65 parameter set marker.
+    MlDsa65, "ML-DSA-65", ml_dsa_65,
+    DilithiumSigningKey, DilithiumVerifyingKey, DilithiumSignature, DilithiumKeypair,
+    crate::ML_DSA_65_PK_BYTES, crate::ML_DSA_65_SK_BYTES, crate::ML_DSA_65_SIG_BYTES
+);
+
+#[cfg(feature = "ml-dsa-44")]
+impl_signature_scheme!(
+    /// ML-DSA-44 parameter set marker.
+    MlDsa44, "ML-DSA-44", ml_dsa_44,
+    Dilithium44SigningKey, Dilithium44VerifyingKey, Dilithium44Signature, Dilithium44Keypair,
+    crate::ML_DSA_44_PK_BYTES, crate::ML_DSA_44_SK_BYTES, crate::ML_DSA_44_SIG_BYTES
+);
+
+#[cfg(feature = "ml-dsa-87")]
+impl_signature_scheme!(
+    /// ML-DSA-87 parameter set marker.
+    MlDsa87, "ML-DSA-87", ml_dsa_87,
+    Dilithium87SigningKey, Dilithium87VerifyingKey, Dilithium87Signature, Dilithium87Keypair,
+    crate::ML_DSA_87_PK_BYTES, crate::ML_DSA_87_SK_BYTES, crate::ML_DSA_87_SIG_BYTES
+);
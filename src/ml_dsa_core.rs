@@ -0,0 +1,556 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/ml_dsa_core.rs
+//! ML-DSA signing and verification core starting from the message
+//! representative μ (FIPS 204, Algorithms 7 and 8 after μ is computed).
+//!
+//! libcrux only signs complete formatted messages, so the external-μ services
+//! run on this portable core instead. Arithmetic follows the FIPS 204
+//! reference implementation (Montgomery NTT, branch-free reductions). The
+//! matrix Â and the secret vectors are expanded one polynomial at a time, so
+//! signing needs roughly 17 KiB of stack for ML-DSA-65 and never allocates.
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Shake128, Shake256};
+use zeroize::Zeroize;
+
+const N: usize = 256;
+const Q: i32 = 8380417;
+const D: usize = 13;
+/// q^(-1) mod 2^32.
+const QINV: i32 = 58728449;
+/// 2^32 mod q.
+const MONT: i64 = 4193792;
+/// mont^2 / 256 mod q, folds the 1/256 of the inverse NTT into Montgomery form.
+const INV_NTT_F: i64 = 41978;
+
+type Poly = [i32; N];
+
+/// ML-DSA parameters that do not determine array sizes (FIPS 204, Table 1).
+pub(crate) struct Params {
+    pub eta: i32,
+    pub tau: usize,
+    pub beta: i32,
+    pub gamma1: i32,
+    pub gamma2: i32,
+    pub omega: usize,
+    /// Length of the commitment hash c̃ (λ/4 bytes).
+    pub c_tilde_bytes: usize,
+}
+
+impl Params {
+    const fn eta_bits(&self) -> usize {
+        if self.eta == 2 {
+            3
+        } else {
+            4
+        }
+    }
+
+    const fn z_bits(&self) -> usize {
+        if self.gamma1 == 1 << 17 {
+            18
+        } else {
+            20
+        }
+    }
+
+    const fn w1_bits(&self) -> usize {
+        if self.gamma2 == (Q - 1) / 88 {
+            6
+        } else {
+            4
+        }
+    }
+}
+
+/// ML-DSA-65 parameters (K = 6, L = 5).
+pub(crate) const ML_DSA_65: Params = Params {
+    eta: 4,
+    tau: 49,
+    beta: 196,
+    gamma1: 1 << 19,
+    gamma2: (Q - 1) / 32,
+    omega: 55,
+    c_tilde_bytes: 48,
+};
+
+/// ζ^brv8(k) · 2^32 mod q for k = 0..256, centred around zero.
+const ZETAS: [i32; N] = compute_zetas();
+
+const fn compute_zetas() -> [i32; N] {
+    let mut zetas = [0i32; N];
+    let mut k = 0;
+    while k < N {
+        let mut brv = 0;
+        let mut i = 0;
+        while i < 8 {
+            brv |= ((k >> i) & 1) << (7 - i);
+            i += 1;
+        }
+        let mut pow: i64 = 1;
+        let mut e = 0;
+        while e < brv {
+            pow = pow * 1753 % Q as i64;
+            e += 1;
+        }
+        let mut z = pow * MONT % Q as i64;
+        if z > (Q as i64) / 2 {
+            z -= Q as i64;
+        }
+        zetas[k] = z as i32;
+        k += 1;
+    }
+    zetas
+}
+
+fn montgomery_reduce(a: i64) -> i32 {
+    let t = (a as i32).wrapping_mul(QINV);
+    ((a - t as i64 * Q as i64) >> 32) as i32
+}
+
+fn reduce32(a: i32) -> i32 {
+    let t = (a + (1 << 22)) >> 23;
+    a - t * Q
+}
+
+fn caddq(a: i32) -> i32 {
+    a + ((a >> 31) & Q)
+}
+
+fn ntt(a: &mut Poly) {
+    let mut k = 0;
+    let mut len = 128;
+    while len > 0 {
+        let mut start = 0;
+        while start < N {
+            k += 1;
+            let zeta = ZETAS[k] as i64;
+            for j in start..start + len {
+                let t = montgomery_reduce(zeta * a[j + len] as i64);
+                a[j + len] = a[j] - t;
+                a[j] += t;
+            }
+            start += 2 * len;
+        }
+        len >>= 1;
+    }
+}
+
+fn invntt_tomont(a: &mut Poly) {
+    let mut k = N;
+    let mut len = 1;
+    while len < N {
+        let mut start = 0;
+        while start < N {
+            k -= 1;
+            let zeta = -ZETAS[k] as i64;
+            for j in start..start + len {
+                let t = a[j];
+                a[j] = t + a[j + len];
+                a[j + len] = montgomery_reduce(zeta * (t - a[j + len]) as i64);
+            }
+            start += 2 * len;
+        }
+        len <<= 1;
+    }
+    for c in a.iter_mut() {
+        *c = montgomery_reduce(INV_NTT_F * *c as i64);
+    }
+}
+
+fn pointwise_montgomery(out: &mut Poly, a: &Poly, b: &Poly) {
+    for i in 0..N {
+        out[i] = montgomery_reduce(a[i] as i64 * b[i] as i64);
+    }
+}
+
+fn pointwise_acc_montgomery(acc: &mut Poly, a: &Poly, b: &Poly) {
+    for i in 0..N {
+        acc[i] += montgomery_reduce(a[i] as i64 * b[i] as i64);
+    }
+}
+
+/// Returns true if any coefficient has |a_i| >= `bound` (input must be reduced).
+///
+/// Leaking which coefficient fails is fine: the rejection probability of each
+/// coefficient is independent of the secret.
+fn exceeds_norm(a: &Poly, bound: i32) -> bool {
+    a.iter().any(|&c| c - ((c >> 31) & (2 * c)) >= bound)
+}
+
+/// Splits `a` in [0, q) into (r1, r0) with a = r1·2γ2 + r0 (FIPS 204, Algorithm 36).
+fn decompose(p: &Params, a: i32) -> (i32, i32) {
+    let mut a1 = (a + 127) >> 7;
+    if p.gamma2 == (Q - 1) / 32 {
+        a1 = (a1 * 1025 + (1 << 21)) >> 22;
+        a1 &= 15;
+    } else {
+        a1 = (a1 * 11275 + (1 << 23)) >> 24;
+        a1 ^= ((43 - a1) >> 31) & a1;
+    }
+    let mut a0 = a - a1 * 2 * p.gamma2;
+    a0 -= (((Q - 1) / 2 - a0) >> 31) & Q;
+    (a1, a0)
+}
+
+fn make_hint(p: &Params, a0: i32, a1: i32) -> bool {
+    a0 > p.gamma2 || a0 < -p.gamma2 || (a0 == -p.gamma2 && a1 != 0)
+}
+
+fn use_hint(p: &Params, a: i32, hint: bool) -> i32 {
+    let (a1, a0) = decompose(p, a);
+    if !hint {
+        return a1;
+    }
+    if p.gamma2 == (Q - 1) / 32 {
+        if a0 > 0 {
+            (a1 + 1) & 15
+        } else {
+            (a1 - 1) & 15
+        }
+    } else if a0 > 0 {
+        if a1 == 43 {
+            0
+        } else {
+            a1 + 1
+        }
+    } else if a1 == 0 {
+        43
+    } else {
+        a1 - 1
+    }
+}
+
+/// Reads the `i`-th `bits`-wide little-endian field of `bytes`.
+fn unpack_bits(bytes: &[u8], bits: usize, i: usize) -> u32 {
+    let bit = i * bits;
+    let mut v: u64 = 0;
+    for (k, b) in bytes[bit / 8..=(bit + bits - 1) / 8].iter().enumerate() {
+        v |= (*b as u64) << (8 * k);
+    }
+    ((v >> (bit % 8)) & ((1u64 << bits) - 1)) as u32
+}
+
+/// Packs 256 `bits`-wide fields produced by `f` into `out` (little-endian).
+fn pack_bits(out: &mut [u8], bits: usize, f: impl Fn(usize) -> u32) {
+    out[..32 * bits].fill(0);
+    for i in 0..N {
+        let bit = i * bits;
+        let v = (f(i) as u64) << (bit % 8);
+        for k in 0..=(bit % 8 + bits - 1) / 8 {
+            out[bit / 8 + k] |= (v >> (8 * k)) as u8;
+        }
+    }
+}
+
+/// Samples Â[row][col] from ρ (FIPS 204, Algorithm 30 RejNTTPoly).
+fn rej_ntt_poly(rho: &[u8], row: usize, col: usize, out: &mut Poly) {
+    let mut h = Shake128::default();
+    h.update(rho);
+    h.update(&[col as u8, row as u8]);
+    let mut reader = h.finalize_xof();
+    let mut b = [0u8; 3];
+    let mut ctr = 0;
+    while ctr < N {
+        reader.read(&mut b);
+        let t = b[0] as i32 | (b[1] as i32) << 8 | ((b[2] & 0x7F) as i32) << 16;
+        if t < Q {
+            out[ctr] = t;
+            ctr += 1;
+        }
+    }
+}
+
+/// Samples the mask polynomial y[r] for κ + r = `nonce` (FIPS 204, Algorithm 34).
+fn expand_mask(p: &Params, rho_pp: &[u8; 64], nonce: u16, out: &mut Poly) {
+    let mut h = Shake256::default();
+    h.update(rho_pp);
+    h.update(&nonce.to_le_bytes());
+    let bits = p.z_bits();
+    let mut buf = [0u8; 32 * 20];
+    h.finalize_xof().read(&mut buf[..32 * bits]);
+    for (i, c) in out.iter_mut().enumerate() {
+        *c = p.gamma1 - unpack_bits(&buf, bits, i) as i32;
+    }
+    buf.zeroize();
+}
+
+/// Samples the challenge polynomial c from c̃ (FIPS 204, Algorithm 29).
+fn sample_in_ball(p: &Params, seed: &[u8], c: &mut Poly) {
+    let mut h = Shake256::default();
+    h.update(seed);
+    let mut reader = h.finalize_xof();
+    let mut s = [0u8; 8];
+    reader.read(&mut s);
+    let mut signs = u64::from_le_bytes(s);
+    c.fill(0);
+    for i in N - p.tau..N {
+        let mut b = [0u8; 1];
+        loop {
+            reader.read(&mut b);
+            if b[0] as usize <= i {
+                break;
+            }
+        }
+        let b = b[0] as usize;
+        c[i] = c[b];
+        c[b] = 1 - 2 * (signs & 1) as i32;
+        signs >>= 1;
+    }
+}
+
+/// Decodes a polynomial with coefficients in [-η, η] and maps it to the NTT domain.
+fn eta_poly_ntt(p: &Params, bytes: &[u8], out: &mut Poly) {
+    let bits = p.eta_bits();
+    for (i, c) in out.iter_mut().enumerate() {
+        *c = p.eta - unpack_bits(bytes, bits, i) as i32;
+    }
+    ntt(out);
+}
+
+/// Decodes t0 (13-bit fields) and maps it to the NTT domain.
+fn t0_poly_ntt(bytes: &[u8], out: &mut Poly) {
+    for (i, c) in out.iter_mut().enumerate() {
+        *c = (1 << (D - 1)) - unpack_bits(bytes, D, i) as i32;
+    }
+    ntt(out);
+}
+
+/// Computes row `row` of Â ∘ v̂ into `acc` (NTT domain, reduced).
+fn matrix_row_times<const L: usize>(rho: &[u8], row: usize, v_hat: &[Poly; L], acc: &mut Poly) {
+    let mut a = [0i32; N];
+    acc.fill(0);
+    for (col, v) in v_hat.iter().enumerate() {
+        rej_ntt_poly(rho, row, col, &mut a);
+        pointwise_acc_montgomery(acc, &a, v);
+    }
+    for c in acc.iter_mut() {
+        *c = reduce32(*c);
+    }
+}
+
+/// Computes the NTT⁻¹ of ĉ ∘ x̂ into `out`.
+fn challenge_times(c_hat: &Poly, x_hat: &Poly, out: &mut Poly) {
+    pointwise_montgomery(out, c_hat, x_hat);
+    invntt_tomont(out);
+}
+
+/// Signs the message representative `mu` (FIPS 204, Algorithm 7 steps 5–33).
+///
+/// `sk` and `sig` must have the encoded sizes of the parameter set.
+pub(crate) fn sign_mu<const K: usize, const L: usize>(
+    p: &Params,
+    sk: &[u8],
+    mu: &[u8; 64],
+    rnd: &[u8; 32],
+    sig: &mut [u8],
+) {
+    let eta_bytes = 32 * p.eta_bits();
+    let z_bytes = 32 * p.z_bits();
+    let w1_bytes = 32 * p.w1_bits();
+    let rho = &sk[..32];
+    let key = &sk[32..64];
+    let s1 = &sk[128..128 + L * eta_bytes];
+    let s2 = &sk[128 + L * eta_bytes..128 + (L + K) * eta_bytes];
+    let t0 = &sk[128 + (L + K) * eta_bytes..];
+    let ctb = p.c_tilde_bytes;
+    let hint_off = ctb + L * z_bytes;
+
+    // ρ'' = H(K || rnd || μ, 64)
+    let mut rho_pp = [0u8; 64];
+    let mut h = Shake256::default();
+    h.update(key);
+    h.update(rnd);
+    h.update(mu);
+    h.finalize_xof().read(&mut rho_pp);
+
+    let mut y_hat = [[0i32; N]; L];
+    let mut w0 = [[0i32; N]; K];
+    let mut w1 = [[0u8; N]; K];
+    let mut c_hat = [0i32; N];
+    let mut tmp = [0i32; N];
+    let mut tmp2 = [0i32; N];
+    let mut packed = [0u8; 32 * 6];
+    let mut kappa: u16 = 0;
+
+    'rejection: loop {
+        let base = kappa;
+        kappa = kappa.wrapping_add(L as u16);
+
+        // w = NTT⁻¹(Â ∘ NTT(y)), split into (w1, w0)
+        for (r, y) in y_hat.iter_mut().enumerate() {
+            expand_mask(p, &rho_pp, base + r as u16, y);
+            ntt(y);
+        }
+        let mut hc = Shake256::default();
+        hc.update(mu);
+        for i in 0..K {
+            matrix_row_times(rho, i, &y_hat, &mut tmp);
+            invntt_tomont(&mut tmp);
+            for j in 0..N {
+                let (a1, a0) = decompose(p, caddq(tmp[j]));
+                w1[i][j] = a1 as u8;
+                w0[i][j] = a0;
+            }
+            pack_bits(&mut packed, p.w1_bits(), |j| w1[i][j] as u32);
+            hc.update(&packed[..w1_bytes]);
+        }
+
+        // c̃ = H(μ || w1Encode(w1), λ/4)
+        hc.finalize_xof().read(&mut sig[..ctb]);
+        sample_in_ball(p, &sig[..ctb], &mut c_hat);
+        ntt(&mut c_hat);
+
+        // z = y + cs1
+        for r in 0..L {
+            eta_poly_ntt(p, &s1[r * eta_bytes..(r + 1) * eta_bytes], &mut tmp2);
+            challenge_times(&c_hat, &tmp2, &mut tmp);
+            expand_mask(p, &rho_pp, base + r as u16, &mut tmp2);
+            for j in 0..N {
+                tmp[j] = reduce32(tmp[j] + tmp2[j]);
+            }
+            if exceeds_norm(&tmp, p.gamma1 - p.beta) {
+                continue 'rejection;
+            }
+            pack_bits(
+                &mut sig[ctb + r * z_bytes..ctb + (r + 1) * z_bytes],
+                p.z_bits(),
+                |j| (p.gamma1 - tmp[j]) as u32,
+            );
+        }
+
+        // r0 = LowBits(w - cs2), ct0 and the hint vector h
+        sig[hint_off..hint_off + p.omega + K].fill(0);
+        let mut hints = 0;
+        for i in 0..K {
+            eta_poly_ntt(p, &s2[i * eta_bytes..(i + 1) * eta_bytes], &mut tmp2);
+            challenge_times(&c_hat, &tmp2, &mut tmp);
+            for j in 0..N {
+                w0[i][j] = reduce32(w0[i][j] - tmp[j]);
+            }
+            if exceeds_norm(&w0[i], p.gamma2 - p.beta) {
+                continue 'rejection;
+            }
+
+            t0_poly_ntt(&t0[i * 32 * D..(i + 1) * 32 * D], &mut tmp2);
+            challenge_times(&c_hat, &tmp2, &mut tmp);
+            for c in tmp.iter_mut() {
+                *c = reduce32(*c);
+            }
+            if exceeds_norm(&tmp, p.gamma2) {
+                continue 'rejection;
+            }
+
+            for j in 0..N {
+                if make_hint(p, w0[i][j] + tmp[j], w1[i][j] as i32) {
+                    if hints == p.omega {
+                        continue 'rejection;
+                    }
+                    sig[hint_off + hints] = j as u8;
+                    hints += 1;
+                }
+            }
+            sig[hint_off + p.omega + i] = hints as u8;
+        }
+        break;
+    }
+
+    rho_pp.zeroize();
+    y_hat.zeroize();
+    w0.zeroize();
+    tmp.zeroize();
+    tmp2.zeroize();
+}
+
+/// Decodes the hint vector into per-row bitmaps (FIPS 204, Algorithm 21).
+///
+/// Rejects non-canonical encodings so that signatures are not malleable.
+fn decode_hints<const K: usize>(p: &Params, y: &[u8], h: &mut [[bool; N]; K]) -> bool {
+    let mut index = 0;
+    for (i, row) in h.iter_mut().enumerate() {
+        let end = y[p.omega + i] as usize;
+        if end < index || end > p.omega {
+            return false;
+        }
+        let first = index;
+        while index < end {
+            if index > first && y[index - 1] >= y[index] {
+                return false;
+            }
+            row[y[index] as usize] = true;
+            index += 1;
+        }
+    }
+    y[index..p.omega].iter().all(|&b| b == 0)
+}
+
+/// Verifies `sig` against the message representative `mu` (FIPS 204, Algorithm 8).
+///
+/// `pk` and `sig` must have the encoded sizes of the parameter set.
+pub(crate) fn verify_mu<const K: usize, const L: usize>(
+    p: &Params,
+    pk: &[u8],
+    mu: &[u8; 64],
+    sig: &[u8],
+) -> bool {
+    let z_bytes = 32 * p.z_bits();
+    let w1_bytes = 32 * p.w1_bits();
+    let ctb = p.c_tilde_bytes;
+    let rho = &pk[..32];
+    let c_tilde = &sig[..ctb];
+
+    let mut h = [[false; N]; K];
+    if !decode_hints(p, &sig[ctb + L * z_bytes..], &mut h) {
+        return false;
+    }
+
+    let mut z_hat = [[0i32; N]; L];
+    for (r, z) in z_hat.iter_mut().enumerate() {
+        let bytes = &sig[ctb + r * z_bytes..ctb + (r + 1) * z_bytes];
+        for (j, c) in z.iter_mut().enumerate() {
+            *c = p.gamma1 - unpack_bits(bytes, p.z_bits(), j) as i32;
+        }
+        if exceeds_norm(z, p.gamma1 - p.beta) {
+            return false;
+        }
+        ntt(z);
+    }
+
+    let mut c_hat = [0i32; N];
+    sample_in_ball(p, c_tilde, &mut c_hat);
+    ntt(&mut c_hat);
+
+    let mut w = [0i32; N];
+    let mut t1 = [0i32; N];
+    let mut packed = [0u8; 32 * 6];
+    let mut hc = Shake256::default();
+    hc.update(mu);
+    for i in 0..K {
+        // w'approx = NTT⁻¹(Â ∘ NTT(z) − NTT(c) ∘ NTT(t1 · 2^d))
+        matrix_row_times(rho, i, &z_hat, &mut w);
+        let t1_bytes = &pk[32 + i * 320..32 + (i + 1) * 320];
+        for (j, c) in t1.iter_mut().enumerate() {
+            *c = (unpack_bits(t1_bytes, 10, j) as i32) << D;
+        }
+        ntt(&mut t1);
+        let t1_hat = t1;
+        pointwise_montgomery(&mut t1, &c_hat, &t1_hat);
+        for j in 0..N {
+            w[j] = reduce32(w[j] - t1[j]);
+        }
+        invntt_tomont(&mut w);
+        pack_bits(&mut packed, p.w1_bits(), |j| {
+            use_hint(p, caddq(w[j]), h[i][j]) as u32
+        });
+        hc.update(&packed[..w1_bytes]);
+    }
+
+    let mut c_prime = [0u8; 64];
+    hc.finalize_xof().read(&mut c_prime[..ctb]);
+    c_prime[..ctb] == *c_tilde
+}
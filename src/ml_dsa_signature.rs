@@ -0,0 +1,154 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/ml_dsa_signature.rs
+//! RustCrypto [`signature`] trait implementations for ML-DSA-65.
+//!
+//! The wrapper types route every operation through the authenticated
+//! services in the crate root, so the FIPS state and role checks still
+//! apply; any [`PqcError`](crate::PqcError) becomes an opaque
+//! [`signature::Error`]. All operations use the empty context string
+//! ([`FIPS_CONTEXT`]).
+
+use crate::{
+    dilithium_sign, dilithium_sign_deterministic, dilithium_verify, DilithiumKeypair,
+    DilithiumSignature, DilithiumSigningKey, DilithiumVerifyingKey, FIPS_CONTEXT,
+    ML_DSA_65_PK_BYTES, ML_DSA_65_SIG_BYTES,
+};
+use signature::rand_core::CryptoRngCore;
+use signature::{Error, Keypair, RandomizedSigner, SignatureEncoding, Signer, Verifier};
+use zeroize::Zeroize;
+
+/// ML-DSA-65 signing key together with its verification key.
+///
+/// [`Signer`] produces deterministic signatures, so it returns
+/// [`signature::Error`] until a Crypto Officer enables deterministic signing
+/// (see [`crate::policy::set_deterministic_signing`]); use
+/// [`Signer::try_sign`] rather than [`Signer::sign`], which panics on error.
+/// [`RandomizedSigner`] produces hedged signatures and is always available.
+pub struct MlDsa65SigningKey {
+    signing_key: DilithiumSigningKey,
+    verifying_key: MlDsa65VerifyingKey,
+}
+
+impl MlDsa65SigningKey {
+    /// Wraps a key pair from [`crate::dilithium_generate_key_pair`].
+    pub fn from_keypair(kp: DilithiumKeypair) -> Self {
+        Self {
+            signing_key: kp.signing_key,
+            verifying_key: MlDsa65VerifyingKey(kp.verification_key),
+        }
+    }
+
+    /// Borrows the underlying signing key.
+    pub fn signing_key(&self) -> &DilithiumSigningKey {
+        &self.signing_key
+    }
+}
+
+impl Signer<MlDsa65Signature> for MlDsa65SigningKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<MlDsa65Signature, Error> {
+        dilithium_sign_deterministic(&self.signing_key, msg, FIPS_CONTEXT)
+            .map(MlDsa65Signature)
+            .map_err(|_| Error::new())
+    }
+}
+
+impl RandomizedSigner<MlDsa65Signature> for MlDsa65SigningKey {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<MlDsa65Signature, Error> {
+        let mut randomness = [0u8; 32];
+        rng.try_fill_bytes(&mut randomness)?;
+        let sig = dilithium_sign(&self.signing_key, msg, FIPS_CONTEXT, randomness);
+        randomness.zeroize();
+        sig.map(MlDsa65Signature).map_err(|_| Error::new())
+    }
+}
+
+impl Keypair for MlDsa65SigningKey {
+    type VerifyingKey = MlDsa65VerifyingKey;
+
+    fn verifying_key(&self) -> MlDsa65VerifyingKey {
+        self.verifying_key.clone()
+    }
+}
+
+/// ML-DSA-65 verification key.
+#[derive(Clone)]
+pub struct MlDsa65VerifyingKey(DilithiumVerifyingKey);
+
+impl MlDsa65VerifyingKey {
+    /// Borrows the underlying verification key.
+    pub fn verifying_key(&self) -> &DilithiumVerifyingKey {
+        &self.0
+    }
+}
+
+impl From<DilithiumVerifyingKey> for MlDsa65VerifyingKey {
+    fn from(vk: DilithiumVerifyingKey) -> Self {
+        Self(vk)
+    }
+}
+
+impl TryFrom<&[u8]> for MlDsa65VerifyingKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes: [u8; ML_DSA_65_PK_BYTES] = bytes.try_into().map_err(|_| Error::new())?;
+        Ok(Self(DilithiumVerifyingKey::new(bytes)))
+    }
+}
+
+impl AsRef<[u8]> for MlDsa65VerifyingKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl Verifier<MlDsa65Signature> for MlDsa65VerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &MlDsa65Signature) -> Result<(), Error> {
+        dilithium_verify(&self.0, msg, FIPS_CONTEXT, &signature.0).map_err(|_| Error::new())
+    }
+}
+
+/// ML-DSA-65 signature.
+#[derive(Clone)]
+pub struct MlDsa65Signature(DilithiumSignature);
+
+impl MlDsa65Signature {
+    /// Borrows the underlying signature.
+    pub fn signature(&self) -> &DilithiumSignature {
+        &self.0
+    }
+}
+
+impl From<DilithiumSignature> for MlDsa65Signature {
+    fn from(sig: DilithiumSignature) -> Self {
+        Self(sig)
+    }
+}
+
+impl TryFrom<&[u8]> for MlDsa65Signature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes: [u8; ML_DSA_65_SIG_BYTES] = bytes.try_into().map_err(|_| Error::new())?;
+        Ok(Self(DilithiumSignature::new(bytes)))
+    }
+}
+
+impl From<MlDsa65Signature> for [u8; ML_DSA_65_SIG_BYTES] {
+    fn from(sig: MlDsa65Signature) -> Self {
+        *sig.0.as_ref()
+    }
+}
+
+impl SignatureEncoding for MlDsa65Signature {
+    type Repr = [u8; ML_DSA_65_SIG_BYTES];
+}
@@ -8,7 +8,9 @@
 // libcrux-ml-kem 0.0.4 exact API
 
 #[cfg(feature = "ml-kem")]
-pub use libcrux_ml_kem::mlkem1024::portable::{decapsulate, encapsulate, generate_key_pair};
+pub use libcrux_ml_kem::mlkem1024::portable::{
+    decapsulate, encapsulate, generate_key_pair, validate_public_key,
+};
 
 #[cfg(feature = "ml-kem")]
 pub use libcrux_ml_kem::mlkem1024::{
@@ -16,5 +18,110 @@ pub use libcrux_ml_kem::mlkem1024::{
     MlKem1024PrivateKey as KyberPrivateKey, MlKem1024PublicKey as KyberPublicKey,
 };
 
-/// ML-KEM-1024 shared secret type (32 bytes).
+#[cfg(feature = "ml-kem-768")]
+pub use libcrux_ml_kem::mlkem768::portable::{
+    decapsulate as decapsulate_768, encapsulate as encapsulate_768,
+    generate_key_pair as generate_key_pair_768, validate_public_key as validate_public_key_768,
+};
+
+#[cfg(feature = "ml-kem-768")]
+pub use libcrux_ml_kem::mlkem768::{
+    MlKem768Ciphertext as Kyber768Ciphertext, MlKem768KeyPair as Kyber768Keypair,
+    MlKem768PrivateKey as Kyber768PrivateKey, MlKem768PublicKey as Kyber768PublicKey,
+};
+
+#[cfg(feature = "ml-kem-512")]
+pub use libcrux_ml_kem::mlkem512::portable::{
+    decapsulate as decapsulate_512, encapsulate as encapsulate_512,
+    generate_key_pair as generate_key_pair_512,
+};
+
+#[cfg(feature = "ml-kem-512")]
+pub use libcrux_ml_kem::mlkem512::{
+    MlKem512Ciphertext as Kyber512Ciphertext, MlKem512KeyPair as Kyber512Keypair,
+    MlKem512PrivateKey as Kyber512PrivateKey, MlKem512PublicKey as Kyber512PublicKey,
+};
+
+/// ML-KEM shared secret type (32 bytes for every parameter set).
 pub type KyberSharedSecret = [u8; 32];
+
+/// Implements [`Kem`](crate::traits::Kem) for a libcrux ML-KEM parameter set.
+macro_rules! impl_kem {
+    (
+        $(#[$doc:meta])*
+        $marker:ident, $name:literal, $module:ident,
+        $pk:ty, $sk:ty, $ct:ty, $kp:ty,
+        $pk_bytes:path, $sk_bytes:path, $ct_bytes:path, $ss_bytes:path
+    ) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $marker;
+
+        impl crate::traits::Kem for $marker {
+            const NAME: &'static str = $name;
+            const PK_BYTES: usize = $pk_bytes;
+            const SK_BYTES: usize = $sk_bytes;
+            const CT_BYTES: usize = $ct_bytes;
+            const SS_BYTES: usize = $ss_bytes;
+
+            type PublicKey = $pk;
+            type PrivateKey = $sk;
+            type Ciphertext = $ct;
+            type Keypair = $kp;
+            type SharedSecret = KyberSharedSecret;
+
+            fn generate_key_pair_internal(seed: [u8; 64]) -> Self::Keypair {
+                libcrux_ml_kem::$module::portable::generate_key_pair(seed)
+            }
+
+            fn encapsulate_internal(
+                pk: &Self::PublicKey,
+                randomness: [u8; 32],
+            ) -> (Self::Ciphertext, Self::SharedSecret) {
+                libcrux_ml_kem::$module::portable::encapsulate(pk, randomness)
+            }
+
+            fn decapsulate_internal(
+                sk: &Self::PrivateKey,
+                ct: &Self::Ciphertext,
+            ) -> Self::SharedSecret {
+                libcrux_ml_kem::$module::portable::decapsulate(sk, ct)
+            }
+
+            fn public_key(kp: &Self::Keypair) -> &Self::PublicKey {
+                kp.public_key()
+            }
+
+            fn private_key(kp: &Self::Keypair) -> &Self::PrivateKey {
+                kp.private_key()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ml-kem")]
+impl_kem!(
+    /// ML-KEM-1024 parameter set marker.
+    MlKem1024, "ML-KEM-1024", mlkem1024,
+    KyberPublicKey, KyberPrivateKey, KyberCiphertext, KyberKeypair,
+    crate::ML_KEM_1024_PK_BYTES, crate::ML_KEM_1024_SK_BYTES,
+    crate::ML_KEM_1024_CT_BYTES, crate::ML_KEM_1024_SS_BYTES
+);
+
+#[cfg(feature = "ml-kem-768")]
+impl_kem!(
+    /// ML-KEM-768 parameter set marker.
+    MlKem768, "ML-KEM-768", mlkem768,
+    Kyber768PublicKey, Kyber768PrivateKey, Kyber768Ciphertext, Kyber768Keypair,
+    crate::ML_KEM_768_PK_BYTES, crate::ML_KEM_768_SK_BYTES,
+    crate::ML_KEM_768_CT_BYTES, crate::ML_KEM_768_SS_BYTES
+);
+
+#[cfg(feature = "ml-kem-512")]
+impl_kem!(
+    /// ML-KEM-512 parameter set marker.
+    MlKem512, "ML-KEM-512", mlkem512,
+    Kyber512PublicKey, Kyber512PrivateKey, Kyber512Ciphertext, Kyber512Keypair,
+    crate::ML_KEM_512_PK_BYTES, crate::ML_KEM_512_SK_BYTES,
+    crate::ML_KEM_512_CT_BYTES, crate::ML_KEM_512_SS_BYTES
+);
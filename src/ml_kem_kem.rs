@@ -0,0 +1,100 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/ml_kem_kem.rs
+//! RustCrypto [`kem`] trait implementations for ML-KEM-1024.
+//!
+//! The wrapper types route through the authenticated [`crate::encapsulate`]
+//! and [`crate::decapsulate`] services, so the FIPS state and role checks
+//! still apply and their [`PqcError`] is returned as the trait error.
+
+use crate::error::{PqcError, Result};
+use crate::{
+    decapsulate, encapsulate, KyberCiphertext, KyberKeypair, KyberPrivateKey, KyberPublicKey,
+    KyberSharedSecret,
+};
+use kem::{Decapsulate, Encapsulate};
+use rand_core::CryptoRngCore;
+use zeroize::Zeroize;
+
+/// ML-KEM-1024 encapsulation (public) key.
+pub struct MlKem1024EncapsulationKey(KyberPublicKey);
+
+impl MlKem1024EncapsulationKey {
+    /// Borrows the underlying public key.
+    pub fn public_key(&self) -> &KyberPublicKey {
+        &self.0
+    }
+}
+
+impl From<KyberPublicKey> for MlKem1024EncapsulationKey {
+    fn from(pk: KyberPublicKey) -> Self {
+        Self(pk)
+    }
+}
+
+impl TryFrom<&[u8]> for MlKem1024EncapsulationKey {
+    type Error = PqcError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        KyberPublicKey::try_from(bytes)
+            .map(Self)
+            .map_err(|_| PqcError::InvalidParameter)
+    }
+}
+
+impl AsRef<[u8]> for MlKem1024EncapsulationKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Encapsulate<KyberCiphertext, KyberSharedSecret> for MlKem1024EncapsulationKey {
+    type Error = PqcError;
+
+    fn encapsulate(
+        &self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+        let mut randomness = [0u8; 32];
+        rng.try_fill_bytes(&mut randomness)
+            .map_err(|_| PqcError::PlatformError)?;
+        let result = encapsulate(&self.0, randomness);
+        randomness.zeroize();
+        result
+    }
+}
+
+/// ML-KEM-1024 decapsulation (private) key.
+pub struct MlKem1024DecapsulationKey(KyberPrivateKey);
+
+impl MlKem1024DecapsulationKey {
+    /// Splits a key pair from [`crate::kyber_generate_key_pair`] into its
+    /// decapsulation and encapsulation keys.
+    pub fn from_keypair(kp: KyberKeypair) -> (Self, MlKem1024EncapsulationKey) {
+        let (sk, pk) = kp.into_parts();
+        (Self(sk), MlKem1024EncapsulationKey(pk))
+    }
+
+    /// Borrows the underlying private key.
+    pub fn private_key(&self) -> &KyberPrivateKey {
+        &self.0
+    }
+}
+
+impl From<KyberPrivateKey> for MlKem1024DecapsulationKey {
+    fn from(sk: KyberPrivateKey) -> Self {
+        Self(sk)
+    }
+}
+
+impl Decapsulate<KyberCiphertext, KyberSharedSecret> for MlKem1024DecapsulationKey {
+    type Error = PqcError;
+
+    fn decapsulate(&self, encapsulated_key: &KyberCiphertext) -> Result<KyberSharedSecret> {
+        decapsulate(&self.0, encapsulated_key)
+    }
+}
@@ -0,0 +1,118 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/module.rs
+//! The FIPS module context.
+//!
+//! A [`FipsModule`] owns everything the module tracks between calls: the
+//! operational state, the logged-in role, the password verifiers, the
+//! approved service indicator and the Crypto Officer policy. Every service is
+//! a method on it, so one binary can host several independent modules, e.g.
+//! one per session or per test.
+//!
+//! The free functions at the crate root and in [`crate::state`],
+//! [`crate::auth`], [`crate::indicator`], [`crate::policy`] and
+//! [`crate::preop`] act on the process-wide instance returned by
+//! [`FipsModule::global`].
+//!
+//! ```rust
+//! use pqc_nostd::auth::{Role, StoredCredentials};
+//! use pqc_nostd::FipsModule;
+//!
+//! let stored = StoredCredentials::from_passwords(
+//!     b"user password", [0x11u8; 16], b"officer password", [0x22u8; 16],
+//! ).unwrap();
+//! let module = FipsModule::with_credentials(&stored);
+//! module.run_post().unwrap();
+//! module.login(Role::User, b"user password").unwrap();
+//! let kp = module.kyber_generate_key_pair([0x01u8; 64]).unwrap();
+//! let (ct, ss) = module.encapsulate(kp.public_key(), [0x02u8; 32]).unwrap();
+//! assert_eq!(module.decapsulate(kp.private_key(), &ct).unwrap(), ss);
+//! ```
+
+use crate::auth::{Credential, Role};
+use crate::error::{PqcError, Result};
+use crate::policy::LockoutPolicy;
+use core::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicU8};
+
+/// A FIPS 140-3 module instance: state, authentication and policy.
+pub struct FipsModule {
+    /// Current [`crate::FipsState`].
+    pub(crate) state: AtomicU8,
+    /// Currently logged-in role.
+    pub(crate) auth: AtomicU8,
+    /// User password verifier.
+    pub(crate) user_credential: Credential,
+    /// Crypto Officer password verifier.
+    pub(crate) co_credential: Credential,
+    /// Approval status of the last service.
+    pub(crate) indicator: AtomicU8,
+    /// Crypto Officer setting for deterministic ML-DSA signing.
+    pub(crate) deterministic_signing: AtomicBool,
+    /// [`LockoutPolicy::lockout_threshold`].
+    pub(crate) lockout_threshold: AtomicU8,
+    /// [`LockoutPolicy::backoff_threshold`].
+    pub(crate) backoff_threshold: AtomicU8,
+    /// [`LockoutPolicy::backoff_delay_ms`].
+    pub(crate) backoff_delay_ms: AtomicU32,
+    /// [`LockoutPolicy::max_backoff_shift`].
+    pub(crate) max_backoff_shift: AtomicU8,
+    /// Latest reading of the caller's monotonic clock, in milliseconds.
+    pub(crate) clock_ms: AtomicU64,
+}
+
+/// The module behind the free-function API.
+static GLOBAL: FipsModule = FipsModule::new();
+
+impl FipsModule {
+    /// Creates a module in the Uninitialized state with nobody logged in.
+    ///
+    /// Run [`FipsModule::run_post`] and log in before using its services.
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(0),
+            auth: AtomicU8::new(0),
+            user_credential: Credential::default_user(),
+            co_credential: Credential::default_co(),
+            indicator: AtomicU8::new(0),
+            deterministic_signing: AtomicBool::new(false),
+            lockout_threshold: AtomicU8::new(LockoutPolicy::DEFAULT.lockout_threshold),
+            backoff_threshold: AtomicU8::new(LockoutPolicy::DEFAULT.backoff_threshold),
+            backoff_delay_ms: AtomicU32::new(LockoutPolicy::DEFAULT.backoff_delay_ms),
+            max_backoff_shift: AtomicU8::new(LockoutPolicy::DEFAULT.max_backoff_shift),
+            clock_ms: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the process-wide module used by the free functions.
+    pub fn global() -> &'static FipsModule {
+        &GLOBAL
+    }
+
+    /// The gate every service passes before it runs.
+    ///
+    /// Checks the state first, so a module that is not Operational reports
+    /// why ([`crate::PqcError::FipsNotInitialized`],
+    /// [`crate::PqcError::FipsPostInProgress`] or
+    /// [`crate::PqcError::FipsErrorState`]) whoever is logged in, and then
+    /// that `role` is logged in ([`crate::PqcError::AuthenticationFailure`]).
+    /// Until both default passwords are replaced it refuses every service
+    /// ([`crate::PqcError::DefaultCredentials`]).
+    pub fn check_service(&self, role: Role) -> Result<()> {
+        self.check_operational()?;
+        self.check_authority(role)?;
+        if self.has_default_credentials() {
+            return Err(PqcError::DefaultCredentials);
+        }
+        Ok(())
+    }
+}
+
+impl Default for FipsModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
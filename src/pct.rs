@@ -5,26 +5,60 @@
 // License: MIT (publicly auditable for FIPS/CMVP verification)
 // Contact: aaronschnacky@gmail.com
 // src/pct.rs – FINAL
-#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
 use crate::error::{PqcError, Result};
+use crate::traits::{Kem, SignatureScheme};
 
 #[cfg(feature = "ml-kem")]
+use crate::{KyberPrivateKey, KyberPublicKey, MlKem1024};
+
+#[cfg(feature = "ml-kem-768")]
+use crate::{Kyber768PrivateKey, Kyber768PublicKey, MlKem768};
+
+#[cfg(feature = "ml-kem-512")]
+use crate::{Kyber512PrivateKey, Kyber512PublicKey, MlKem512};
+
+#[cfg(feature = "ml-dsa")]
+use crate::{DilithiumSigningKey, DilithiumVerifyingKey, MlDsa65};
+
+#[cfg(feature = "ml-dsa-44")]
+use crate::{Dilithium44SigningKey, Dilithium44VerifyingKey, MlDsa44};
+
+#[cfg(feature = "ml-dsa-87")]
+use crate::{Dilithium87SigningKey, Dilithium87VerifyingKey, MlDsa87};
+
+#[cfg(feature = "slh-dsa")]
 use crate::{
-    kyber_decapsulate_internal, kyber_encapsulate_internal, KyberPrivateKey, KyberPublicKey,
+    slh_dsa_shake_128s_sign_internal, slh_dsa_shake_128s_verify_internal,
+    slh_dsa_shake_256s_sign_internal, slh_dsa_shake_256s_verify_internal,
+    SlhDsaShake128sSigningKey, SlhDsaShake128sVerifyingKey, SlhDsaShake256sSigningKey,
+    SlhDsaShake256sVerifyingKey,
 };
 
-#[cfg(feature = "ml-dsa")]
+#[cfg(feature = "xwing")]
 use crate::{
-    dilithium_sign_internal, dilithium_verify_internal, DilithiumSigningKey, DilithiumVerifyingKey,
-    FIPS_CONTEXT,
+    xwing_decapsulate_internal, xwing_encapsulate_internal, XWingPrivateKey, XWingPublicKey,
 };
 
-/// Runs the Pair-wise Consistency Test (PCT) for ML-KEM-1024.
-#[cfg(feature = "ml-kem")]
-pub fn kyber_pct(pk: &KyberPublicKey, sk: &KyberPrivateKey) -> Result<()> {
+#[cfg(feature = "ecdh-mlkem")]
+use crate::{
+    secp256r1_mlkem768_decapsulate_internal, secp256r1_mlkem768_encapsulate_internal,
+    secp384r1_mlkem1024_decapsulate_internal, secp384r1_mlkem1024_encapsulate_internal,
+    SecP256r1MlKem768ClientShare, SecP256r1MlKem768PrivateKey, SecP384r1MlKem1024ClientShare,
+    SecP384r1MlKem1024PrivateKey,
+};
+
+#[cfg(feature = "composite")]
+use crate::{
+    mldsa65_ecdsa_p256_sign_internal, mldsa65_ecdsa_p256_verify_internal,
+    mldsa65_ed25519_sign_internal, mldsa65_ed25519_verify_internal, MlDsa65EcdsaP256SigningKey,
+    MlDsa65EcdsaP256VerifyingKey, MlDsa65Ed25519SigningKey, MlDsa65Ed25519VerifyingKey,
+};
+
+/// Runs the Pair-wise Consistency Test (PCT) for any KEM parameter set.
+pub fn kem_pct<K: Kem>(pk: &K::PublicKey, sk: &K::PrivateKey) -> Result<()> {
     let randomness = [0x55u8; 32];
-    let (ct, ss1) = kyber_encapsulate_internal(pk, randomness);
-    let ss2 = kyber_decapsulate_internal(sk, &ct);
+    let (ct, ss1) = K::encapsulate_internal(pk, randomness);
+    let ss2 = K::decapsulate_internal(sk, &ct);
     if ss1 == ss2 {
         Ok(())
     } else {
@@ -32,13 +66,157 @@ pub fn kyber_pct(pk: &KyberPublicKey, sk: &KyberPrivateKey) -> Result<()> {
     }
 }
 
+/// Runs the Pair-wise Consistency Test (PCT) for any signature parameter set.
+pub fn signature_pct<S: SignatureScheme>(pk: &S::VerifyingKey, sk: &S::SigningKey) -> Result<()> {
+    let randomness = [0x77u8; 32];
+    let msg = b"FIPS 140-3 PCT";
+    let ctx = b""; // Same as FIPS_CONTEXT (pure signing)
+    let sig = S::sign_internal(sk, msg, ctx, randomness)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)?;
+    S::verify_internal(pk, msg, ctx, &sig).map_err(|_| PqcError::PairwiseConsistencyTestFailure)
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for ML-KEM-1024.
+#[cfg(feature = "ml-kem")]
+pub fn kyber_pct(pk: &KyberPublicKey, sk: &KyberPrivateKey) -> Result<()> {
+    kem_pct::<MlKem1024>(pk, sk)
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for ML-KEM-768.
+#[cfg(feature = "ml-kem-768")]
+pub fn kyber768_pct(pk: &Kyber768PublicKey, sk: &Kyber768PrivateKey) -> Result<()> {
+    kem_pct::<MlKem768>(pk, sk)
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for ML-KEM-512.
+#[cfg(feature = "ml-kem-512")]
+pub fn kyber512_pct(pk: &Kyber512PublicKey, sk: &Kyber512PrivateKey) -> Result<()> {
+    kem_pct::<MlKem512>(pk, sk)
+}
+
 /// Runs the Pair-wise Consistency Test (PCT) for ML-DSA-65.
 #[cfg(feature = "ml-dsa")]
 pub fn dilithium_pct(pk: &DilithiumVerifyingKey, sk: &DilithiumSigningKey) -> Result<()> {
+    signature_pct::<MlDsa65>(pk, sk)
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for ML-DSA-44.
+#[cfg(feature = "ml-dsa-44")]
+pub fn dilithium44_pct(pk: &Dilithium44VerifyingKey, sk: &Dilithium44SigningKey) -> Result<()> {
+    signature_pct::<MlDsa44>(pk, sk)
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for ML-DSA-87.
+#[cfg(feature = "ml-dsa-87")]
+pub fn dilithium87_pct(pk: &Dilithium87VerifyingKey, sk: &Dilithium87SigningKey) -> Result<()> {
+    signature_pct::<MlDsa87>(pk, sk)
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for SLH-DSA-SHAKE-128s.
+#[cfg(feature = "slh-dsa")]
+pub fn slh_dsa_shake_128s_pct(
+    pk: &SlhDsaShake128sVerifyingKey,
+    sk: &SlhDsaShake128sSigningKey,
+) -> Result<()> {
+    let randomness = [0x77u8; 16];
+    let msg = b"FIPS 140-3 PCT";
+    let ctx = b""; // Same as FIPS_CONTEXT (pure signing)
+    let sig = slh_dsa_shake_128s_sign_internal(sk, msg, ctx, randomness)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)?;
+    slh_dsa_shake_128s_verify_internal(pk, msg, ctx, &sig)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for SLH-DSA-SHAKE-256s.
+#[cfg(feature = "slh-dsa")]
+pub fn slh_dsa_shake_256s_pct(
+    pk: &SlhDsaShake256sVerifyingKey,
+    sk: &SlhDsaShake256sSigningKey,
+) -> Result<()> {
+    let randomness = [0x77u8; 32];
+    let msg = b"FIPS 140-3 PCT";
+    let ctx = b""; // Same as FIPS_CONTEXT (pure signing)
+    let sig = slh_dsa_shake_256s_sign_internal(sk, msg, ctx, randomness)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)?;
+    slh_dsa_shake_256s_verify_internal(pk, msg, ctx, &sig)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for X-Wing.
+#[cfg(feature = "xwing")]
+pub fn xwing_pct(pk: &XWingPublicKey, sk: &XWingPrivateKey) -> Result<()> {
+    let eseed = [0x55u8; 64];
+    let (ct, ss1) = xwing_encapsulate_internal(pk, eseed);
+    let ss2 = xwing_decapsulate_internal(sk, &ct);
+    if ss1 == ss2 {
+        Ok(())
+    } else {
+        Err(PqcError::PairwiseConsistencyTestFailure)
+    }
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for SecP256r1MLKEM768.
+#[cfg(feature = "ecdh-mlkem")]
+pub fn secp256r1_mlkem768_pct(
+    client_share: &SecP256r1MlKem768ClientShare,
+    sk: &SecP256r1MlKem768PrivateKey,
+) -> Result<()> {
+    let randomness = [0x55u8; 64];
+    let (server_share, ss1) = secp256r1_mlkem768_encapsulate_internal(client_share, randomness)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)?;
+    let ss2 = secp256r1_mlkem768_decapsulate_internal(sk, &server_share)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)?;
+    if ss1 == ss2 {
+        Ok(())
+    } else {
+        Err(PqcError::PairwiseConsistencyTestFailure)
+    }
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for SecP384r1MLKEM1024.
+#[cfg(feature = "ecdh-mlkem")]
+pub fn secp384r1_mlkem1024_pct(
+    client_share: &SecP384r1MlKem1024ClientShare,
+    sk: &SecP384r1MlKem1024PrivateKey,
+) -> Result<()> {
+    let randomness = [0x55u8; 80];
+    let (server_share, ss1) = secp384r1_mlkem1024_encapsulate_internal(client_share, randomness)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)?;
+    let ss2 = secp384r1_mlkem1024_decapsulate_internal(sk, &server_share)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)?;
+    if ss1 == ss2 {
+        Ok(())
+    } else {
+        Err(PqcError::PairwiseConsistencyTestFailure)
+    }
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for MLDSA65-ECDSA-P256-SHA512.
+#[cfg(feature = "composite")]
+pub fn mldsa65_ecdsa_p256_pct(
+    pk: &MlDsa65EcdsaP256VerifyingKey,
+    sk: &MlDsa65EcdsaP256SigningKey,
+) -> Result<()> {
+    let randomness = [0x77u8; 32];
+    let msg = b"FIPS 140-3 PCT";
+    let ctx = b"";
+    let sig = mldsa65_ecdsa_p256_sign_internal(sk, msg, ctx, randomness)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)?;
+    mldsa65_ecdsa_p256_verify_internal(pk, msg, ctx, &sig)
+        .map_err(|_| PqcError::PairwiseConsistencyTestFailure)
+}
+
+/// Runs the Pair-wise Consistency Test (PCT) for MLDSA65-Ed25519-SHA512.
+#[cfg(feature = "composite")]
+pub fn mldsa65_ed25519_pct(
+    pk: &MlDsa65Ed25519VerifyingKey,
+    sk: &MlDsa65Ed25519SigningKey,
+) -> Result<()> {
     let randomness = [0x77u8; 32];
     let msg = b"FIPS 140-3 PCT";
-    let sig = dilithium_sign_internal(sk, msg, FIPS_CONTEXT, randomness)
+    let ctx = b"";
+    let sig = mldsa65_ed25519_sign_internal(sk, msg, ctx, randomness)
         .map_err(|_| PqcError::PairwiseConsistencyTestFailure)?;
-    dilithium_verify_internal(pk, msg, FIPS_CONTEXT, &sig)
+    mldsa65_ed25519_verify_internal(pk, msg, ctx, &sig)
         .map_err(|_| PqcError::PairwiseConsistencyTestFailure)
 }
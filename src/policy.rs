@@ -0,0 +1,141 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/policy.rs
+//! Crypto Officer configurable security policy.
+//!
+//! Settings default to the most conservative choice and can only be changed
+//! by an authenticated Crypto Officer.
+
+use crate::auth::Role;
+use crate::error::{PqcError, Result};
+use crate::module::FipsModule;
+use core::sync::atomic::Ordering;
+
+/// Largest [`LockoutPolicy::max_backoff_shift`] (a 1024-fold delay).
+pub const MAX_BACKOFF_SHIFT: u8 = 10;
+
+/// Limits on failed login attempts, applied to each role separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockoutPolicy {
+    /// Consecutive failed logins that lock the role until a Crypto Officer
+    /// unlocks it. Must be at least 1.
+    pub lockout_threshold: u8,
+    /// Consecutive failed logins after which every further attempt must wait
+    /// for the back-off delay.
+    pub backoff_threshold: u8,
+    /// Delay in milliseconds of the module clock (see
+    /// [`FipsModule::update_clock`]) before the first attempt past
+    /// `backoff_threshold`. 0 disables the back-off.
+    pub backoff_delay_ms: u32,
+    /// Each further failure doubles the delay, up to
+    /// `backoff_delay_ms << max_backoff_shift`.
+    pub max_backoff_shift: u8,
+}
+
+impl LockoutPolicy {
+    /// Lock after 10 failures; after 3, wait 1 s before the next attempt,
+    /// doubling up to 64 s.
+    pub const DEFAULT: Self = Self {
+        lockout_threshold: 10,
+        backoff_threshold: 3,
+        backoff_delay_ms: 1000,
+        max_backoff_shift: 6,
+    };
+
+    /// The delay before the next attempt once `failures` have been counted.
+    pub(crate) fn backoff_delay(&self, failures: u8) -> u64 {
+        match failures.checked_sub(self.backoff_threshold) {
+            Some(excess) => u64::from(self.backoff_delay_ms) << excess.min(self.max_backoff_shift),
+            None => 0,
+        }
+    }
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl FipsModule {
+    /// Sets the failed login limits (Crypto Officer only).
+    ///
+    /// Returns [`PqcError::InvalidParameter`] for a zero lockout threshold or
+    /// a shift above [`MAX_BACKOFF_SHIFT`]. Counts and delays already
+    /// recorded are kept, so lowering the threshold can lock a role at once.
+    pub fn set_lockout_policy(&self, policy: LockoutPolicy) -> Result<()> {
+        self.check_service(Role::CryptoOfficer)?;
+        if policy.lockout_threshold == 0 || policy.max_backoff_shift > MAX_BACKOFF_SHIFT {
+            return Err(PqcError::InvalidParameter);
+        }
+        self.lockout_threshold
+            .store(policy.lockout_threshold, Ordering::Release);
+        self.backoff_threshold
+            .store(policy.backoff_threshold, Ordering::Release);
+        self.backoff_delay_ms
+            .store(policy.backoff_delay_ms, Ordering::Release);
+        self.max_backoff_shift
+            .store(policy.max_backoff_shift, Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns the current failed login limits.
+    pub fn lockout_policy(&self) -> LockoutPolicy {
+        LockoutPolicy {
+            lockout_threshold: self.lockout_threshold.load(Ordering::Acquire),
+            backoff_threshold: self.backoff_threshold.load(Ordering::Acquire),
+            backoff_delay_ms: self.backoff_delay_ms.load(Ordering::Acquire),
+            max_backoff_shift: self.max_backoff_shift.load(Ordering::Acquire),
+        }
+    }
+
+    /// Allows or forbids deterministic ML-DSA signing (Crypto Officer only).
+    ///
+    /// FIPS 204 recommends hedged signing; deterministic signing (rnd = 0^32)
+    /// is permitted but must be enabled explicitly, e.g. for reproducible-build
+    /// pipelines that need stable signatures. Disabled by default.
+    pub fn set_deterministic_signing(&self, allowed: bool) -> Result<()> {
+        self.check_service(Role::CryptoOfficer)?;
+        self.deterministic_signing.store(allowed, Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns true if deterministic ML-DSA signing is currently allowed.
+    pub fn deterministic_signing_allowed(&self) -> bool {
+        self.deterministic_signing.load(Ordering::Acquire)
+    }
+
+    /// Returns Ok(()) if deterministic signing is allowed by the policy.
+    #[cfg(feature = "ml-dsa")]
+    pub(crate) fn check_deterministic_signing(&self) -> Result<()> {
+        if self.deterministic_signing_allowed() {
+            Ok(())
+        } else {
+            Err(PqcError::ServiceDisabled)
+        }
+    }
+}
+
+/// Allows or forbids deterministic ML-DSA signing on the global module (Crypto Officer only).
+pub fn set_deterministic_signing(allowed: bool) -> Result<()> {
+    FipsModule::global().set_deterministic_signing(allowed)
+}
+
+/// Returns true if deterministic ML-DSA signing is currently allowed on the global module.
+pub fn deterministic_signing_allowed() -> bool {
+    FipsModule::global().deterministic_signing_allowed()
+}
+
+/// Sets the failed login limits of the global module (Crypto Officer only).
+pub fn set_lockout_policy(policy: LockoutPolicy) -> Result<()> {
+    FipsModule::global().set_lockout_policy(policy)
+}
+
+/// Returns the current failed login limits of the global module.
+pub fn lockout_policy() -> LockoutPolicy {
+    FipsModule::global().lockout_policy()
+}
@@ -7,80 +7,140 @@
 // src/preop.rs – FINAL
 #[cfg(feature = "fips_140_3")]
 use crate::cast::run_hash_casts;
+use crate::entropy::{EntropySource, NoiseSource};
 use crate::error::Result;
-use crate::state::{enter_error_state, enter_operational_state, enter_post_state};
+use crate::module::FipsModule;
 
-#[cfg(feature = "ml-kem")]
-use crate::{kyber_generate_key_pair_internal, kyber_pct, KyberKeypair};
+#[cfg(any(feature = "ml-kem", feature = "ml-kem-512", feature = "ml-kem-768"))]
+use crate::{pct::kem_pct, traits::Kem};
 
-#[cfg(feature = "ml-dsa")]
-use crate::{dilithium_generate_key_pair_internal, dilithium_pct, DilithiumKeypair};
+#[cfg(any(feature = "ml-dsa", feature = "ml-dsa-44", feature = "ml-dsa-87"))]
+use crate::{pct::signature_pct, traits::SignatureScheme};
 
-/// Runs the full suite of Power-On Self-Tests (POST).
-#[allow(unsafe_code)]
-pub fn run_post() -> Result<()> {
-    enter_post_state();
-
-    let result = (|| {
-        // Run hash CASTs
-        #[cfg(feature = "fips_140_3")]
-        run_hash_casts()?;
-
-        // Run Known Answer Tests (KATs)
-        #[cfg(feature = "fips_140_3")]
-        crate::kat::run_kats()?;
-
-        #[cfg(feature = "ml-kem")]
-        {
-            let seed64 = [0x42u8; 64];
-            let kp: KyberKeypair = kyber_generate_key_pair_internal(seed64);
-            kyber_pct(kp.public_key(), kp.private_key())?;
-        }
+impl FipsModule {
+    /// Runs the full suite of Power-On Self-Tests (POST).
+    pub fn run_post(&self) -> Result<()> {
+        self.enter_post_state();
+        self.finish_post(self_tests())
+    }
 
-        #[cfg(feature = "ml-dsa")]
-        {
-            let seed32 = [0x42u8; 32];
-            let kp: DilithiumKeypair = dilithium_generate_key_pair_internal(seed32);
-            dilithium_pct(&kp.verification_key, &kp.signing_key)?;
-        }
+    /// Runs POST followed by the SP 800-90B startup health tests on `source`.
+    ///
+    /// The module only becomes Operational if both pass.
+    pub fn run_post_with_entropy<N: NoiseSource>(
+        &self,
+        source: &mut EntropySource<N>,
+    ) -> Result<()> {
+        self.enter_post_state();
+        self.finish_post(self_tests().and_then(|()| source.startup_test()))
+    }
+
+    /// Runs POST and panics if any test fails.
+    pub fn run_post_or_panic(&self) {
+        self.run_post().expect("FIPS 140-3 POST failed");
+    }
 
-        // Run Software Integrity Test
-        #[cfg(feature = "fips_140_3")]
-        {
-            use crate::integrity::{get_code_segment, integrity_check};
-            use crate::integrity_data::EXPECTED_HMAC;
-
-            // Skip integrity check if HMAC is still the placeholder (e.g., during testing)
-            const PLACEHOLDER: [u8; 32] = *b"__PQC_NOSTD_HMAC_PLACEHOLDER__\x00\x00";
-            if EXPECTED_HMAC != PLACEHOLDER {
-                // Note: In a real embedded system, we might panic here if detection fails,
-                // but returning an error transitions to the Error state which is correct.
-                let (code_start, code_len) =
-                    get_code_segment().map_err(|_| crate::error::PqcError::PlatformError)?;
-
-                // Safety: get_code_segment returns valid boundaries for the running executable.
-                unsafe {
-                    integrity_check(code_start, code_len, &EXPECTED_HMAC)?;
-                }
+    /// Moves the module to Operational or Error according to the POST result.
+    fn finish_post(&self, result: Result<()>) -> Result<()> {
+        match result {
+            Ok(()) => {
+                self.enter_operational_state();
+                Ok(())
+            }
+            Err(e) => {
+                self.enter_error_state();
+                Err(e)
             }
         }
+    }
+}
+
+/// Runs the full suite of Power-On Self-Tests (POST) on the global module.
+pub fn run_post() -> Result<()> {
+    FipsModule::global().run_post()
+}
 
-        Ok(())
-    })();
+/// Runs POST followed by the SP 800-90B startup health tests on `source`,
+/// for the global module.
+///
+/// The module only becomes Operational if both pass.
+pub fn run_post_with_entropy<N: NoiseSource>(source: &mut EntropySource<N>) -> Result<()> {
+    FipsModule::global().run_post_with_entropy(source)
+}
 
-    match result {
-        Ok(()) => {
-            enter_operational_state();
-            Ok(())
-        }
-        Err(e) => {
-            enter_error_state();
-            Err(e)
+/// Runs every pre-operational self-test.
+#[allow(unsafe_code)]
+fn self_tests() -> Result<()> {
+    // Run hash CASTs
+    #[cfg(feature = "fips_140_3")]
+    run_hash_casts()?;
+
+    // Run Known Answer Tests (KATs)
+    #[cfg(feature = "fips_140_3")]
+    crate::kat::run_kats()?;
+
+    #[cfg(feature = "ml-kem")]
+    kem_post_pct::<crate::MlKem1024>()?;
+
+    #[cfg(feature = "ml-kem-768")]
+    kem_post_pct::<crate::MlKem768>()?;
+
+    #[cfg(feature = "ml-kem-512")]
+    kem_post_pct::<crate::MlKem512>()?;
+
+    #[cfg(feature = "ml-dsa")]
+    signature_post_pct::<crate::MlDsa65>()?;
+
+    #[cfg(feature = "ml-dsa-44")]
+    signature_post_pct::<crate::MlDsa44>()?;
+
+    #[cfg(feature = "ml-dsa-87")]
+    signature_post_pct::<crate::MlDsa87>()?;
+
+    // SLH-DSA signing already runs in its KAT; its PCT runs on every
+    // generated key pair instead of repeating a slow signature here.
+
+    // Run Software Integrity Test
+    #[cfg(feature = "fips_140_3")]
+    {
+        use crate::integrity::{get_code_segment, integrity_check};
+        use crate::integrity_data::EXPECTED_HMAC;
+
+        // Skip integrity check if HMAC is still the placeholder (e.g., during testing)
+        const PLACEHOLDER: [u8; 32] = *b"__PQC_NOSTD_HMAC_PLACEHOLDER__\x00\x00";
+        if EXPECTED_HMAC != PLACEHOLDER {
+            // Note: In a real embedded system, we might panic here if detection fails,
+            // but returning an error transitions to the Error state which is correct.
+            let (code_start, code_len) =
+                get_code_segment().map_err(|_| crate::error::PqcError::PlatformError)?;
+
+            // Safety: get_code_segment returns valid boundaries for the running executable.
+            unsafe {
+                integrity_check(code_start, code_len, &EXPECTED_HMAC)?;
+            }
         }
     }
+
+    Ok(())
+}
+
+/// Generates a fixed key pair for `K` and runs its PCT.
+#[cfg(any(feature = "ml-kem", feature = "ml-kem-512", feature = "ml-kem-768"))]
+fn kem_post_pct<K: Kem>() -> Result<()> {
+    let seed64 = [0x42u8; 64];
+    let kp = K::generate_key_pair_internal(seed64);
+    kem_pct::<K>(K::public_key(&kp), K::private_key(&kp))
+}
+
+/// Generates a fixed key pair for `S` and runs its PCT.
+#[cfg(any(feature = "ml-dsa", feature = "ml-dsa-44", feature = "ml-dsa-87"))]
+fn signature_post_pct<S: SignatureScheme>() -> Result<()> {
+    let seed32 = [0x42u8; 32];
+    let kp = S::generate_key_pair_internal(seed32);
+    signature_pct::<S>(S::verifying_key(&kp), S::signing_key(&kp))
 }
 
-/// Runs POST and panics if any test fails.
+/// Runs POST on the global module and panics if any test fails.
 pub fn run_post_or_panic() {
-    run_post().expect("FIPS 140-3 POST failed");
+    FipsModule::global().run_post_or_panic();
 }
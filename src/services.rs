@@ -0,0 +1,792 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/services.rs
+//! The cryptographic services of a [`FipsModule`].
+//!
+//! Each service checks this module's state and role, records its approved
+//! service indicator and runs the key generation PCT where one applies. The
+//! functions of the same name at the crate root call these on
+//! [`FipsModule::global`].
+
+#[allow(unused_imports)]
+use crate::*;
+#[cfg(any(feature = "ml-kem", feature = "ml-dsa"))]
+use zeroize::Zeroize;
+
+/// Fills `buf` from a caller-supplied RNG, reporting its failures as
+/// [`PqcError::PlatformError`].
+#[cfg(all(feature = "rand_core", any(feature = "ml-kem", feature = "ml-dsa")))]
+fn fill_from_rng<R: CryptoRng + RngCore>(rng: &mut R, buf: &mut [u8]) -> Result<()> {
+    rng.try_fill_bytes(buf).map_err(|_| PqcError::PlatformError)
+}
+
+impl FipsModule {
+    /// Generates a Kyber key pair (Authenticated).
+    #[cfg(feature = "ml-kem")]
+    pub fn kyber_generate_key_pair(&self, seed: [u8; 64]) -> Result<KyberKeypair> {
+        self.kem_generate_key_pair::<MlKem1024>(seed)
+    }
+
+    /// Encapsulates a shared secret (Authenticated).
+    #[cfg(feature = "ml-kem")]
+    pub fn encapsulate(
+        &self,
+        pk: &KyberPublicKey,
+        randomness: [u8; 32],
+    ) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+        self.kem_encapsulate::<MlKem1024>(pk, randomness)
+    }
+
+    /// Decapsulates a shared secret (Authenticated).
+    #[cfg(feature = "ml-kem")]
+    pub fn decapsulate(
+        &self,
+        sk: &KyberPrivateKey,
+        ct: &KyberCiphertext,
+    ) -> Result<KyberSharedSecret> {
+        self.kem_decapsulate::<MlKem1024>(sk, ct)
+    }
+
+    /// Generates a Kyber key pair with the seed drawn from `drbg` (Authenticated).
+    #[cfg(feature = "ml-kem")]
+    pub fn kyber_generate_key_pair_with_drbg<D: Drbg>(&self, drbg: &mut D) -> Result<KyberKeypair> {
+        let mut seed = [0u8; 64];
+        self.drbg_generate(drbg, &mut seed, &[])?;
+        let kp = self.kyber_generate_key_pair(seed);
+        seed.zeroize();
+        kp
+    }
+
+    /// Encapsulates a shared secret with randomness drawn from `drbg` (Authenticated).
+    #[cfg(feature = "ml-kem")]
+    pub fn encapsulate_with_drbg<D: Drbg>(
+        &self,
+        pk: &KyberPublicKey,
+        drbg: &mut D,
+    ) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+        let mut randomness = [0u8; 32];
+        self.drbg_generate(drbg, &mut randomness, &[])?;
+        let result = self.encapsulate(pk, randomness);
+        randomness.zeroize();
+        result
+    }
+
+    /// Generates a Kyber key pair with the seed drawn from `rng` (Authenticated).
+    #[cfg(all(feature = "ml-kem", feature = "rand_core"))]
+    pub fn kyber_generate_key_pair_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        rng: &mut R,
+    ) -> Result<KyberKeypair> {
+        let mut seed = [0u8; 64];
+        fill_from_rng(rng, &mut seed)?;
+        let kp = self.kyber_generate_key_pair(seed);
+        seed.zeroize();
+        kp
+    }
+
+    /// Encapsulates a shared secret with randomness drawn from `rng` (Authenticated).
+    #[cfg(all(feature = "ml-kem", feature = "rand_core"))]
+    pub fn encapsulate_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        pk: &KyberPublicKey,
+        rng: &mut R,
+    ) -> Result<(KyberCiphertext, KyberSharedSecret)> {
+        let mut randomness = [0u8; 32];
+        fill_from_rng(rng, &mut randomness)?;
+        let result = self.encapsulate(pk, randomness);
+        randomness.zeroize();
+        result
+    }
+
+    /// Generates an ML-KEM-768 key pair (Authenticated).
+    #[cfg(feature = "ml-kem-768")]
+    pub fn kyber768_generate_key_pair(&self, seed: [u8; 64]) -> Result<Kyber768Keypair> {
+        self.kem_generate_key_pair::<MlKem768>(seed)
+    }
+
+    /// Encapsulates a shared secret with ML-KEM-768 (Authenticated).
+    #[cfg(feature = "ml-kem-768")]
+    pub fn kyber768_encapsulate(
+        &self,
+        pk: &Kyber768PublicKey,
+        randomness: [u8; 32],
+    ) -> Result<(Kyber768Ciphertext, KyberSharedSecret)> {
+        self.kem_encapsulate::<MlKem768>(pk, randomness)
+    }
+
+    /// Decapsulates an ML-KEM-768 shared secret (Authenticated).
+    #[cfg(feature = "ml-kem-768")]
+    pub fn kyber768_decapsulate(
+        &self,
+        sk: &Kyber768PrivateKey,
+        ct: &Kyber768Ciphertext,
+    ) -> Result<KyberSharedSecret> {
+        self.kem_decapsulate::<MlKem768>(sk, ct)
+    }
+
+    /// Generates an ML-KEM-512 key pair (Authenticated).
+    #[cfg(feature = "ml-kem-512")]
+    pub fn kyber512_generate_key_pair(&self, seed: [u8; 64]) -> Result<Kyber512Keypair> {
+        self.kem_generate_key_pair::<MlKem512>(seed)
+    }
+
+    /// Encapsulates a shared secret with ML-KEM-512 (Authenticated).
+    #[cfg(feature = "ml-kem-512")]
+    pub fn kyber512_encapsulate(
+        &self,
+        pk: &Kyber512PublicKey,
+        randomness: [u8; 32],
+    ) -> Result<(Kyber512Ciphertext, KyberSharedSecret)> {
+        self.kem_encapsulate::<MlKem512>(pk, randomness)
+    }
+
+    /// Decapsulates an ML-KEM-512 shared secret (Authenticated).
+    #[cfg(feature = "ml-kem-512")]
+    pub fn kyber512_decapsulate(
+        &self,
+        sk: &Kyber512PrivateKey,
+        ct: &Kyber512Ciphertext,
+    ) -> Result<KyberSharedSecret> {
+        self.kem_decapsulate::<MlKem512>(sk, ct)
+    }
+
+    /// Generates a Dilithium key pair (Authenticated).
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_generate_key_pair(&self, seed: [u8; 32]) -> Result<DilithiumKeypair> {
+        self.signature_generate_key_pair::<MlDsa65>(seed)
+    }
+
+    /// Generates a Dilithium key pair with the seed drawn from `rng` (Authenticated).
+    #[cfg(all(feature = "ml-dsa", feature = "rand_core"))]
+    pub fn dilithium_generate_key_pair_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        rng: &mut R,
+    ) -> Result<DilithiumKeypair> {
+        let mut seed = [0u8; 32];
+        fill_from_rng(rng, &mut seed)?;
+        let kp = self.dilithium_generate_key_pair(seed);
+        seed.zeroize();
+        kp
+    }
+
+    /// Signs a message (Authenticated).
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_sign(
+        &self,
+        sk: &DilithiumSigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<DilithiumSignature> {
+        self.signature_sign::<MlDsa65>(sk, msg, ctx, randomness)
+    }
+
+    /// Signs a message with rnd drawn from `drbg` (Authenticated).
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_sign_with_drbg<D: Drbg>(
+        &self,
+        sk: &DilithiumSigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        drbg: &mut D,
+    ) -> Result<DilithiumSignature> {
+        let mut randomness = [0u8; 32];
+        self.drbg_generate(drbg, &mut randomness, &[])?;
+        let sig = self.dilithium_sign(sk, msg, ctx, randomness);
+        randomness.zeroize();
+        sig
+    }
+
+    /// Signs a message with rnd drawn from `rng` (Authenticated).
+    #[cfg(all(feature = "ml-dsa", feature = "rand_core"))]
+    pub fn dilithium_sign_with_rng<R: CryptoRng + RngCore>(
+        &self,
+        sk: &DilithiumSigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        rng: &mut R,
+    ) -> Result<DilithiumSignature> {
+        let mut randomness = [0u8; 32];
+        fill_from_rng(rng, &mut randomness)?;
+        let sig = self.dilithium_sign(sk, msg, ctx, randomness);
+        randomness.zeroize();
+        sig
+    }
+
+    /// Verifies a signature (Authenticated).
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_verify(
+        &self,
+        pk: &DilithiumVerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+        sig: &DilithiumSignature,
+    ) -> Result<()> {
+        self.signature_verify::<MlDsa65>(pk, msg, ctx, sig)
+    }
+
+    /// Signs a message deterministically, with rnd = 0^32 (Authenticated).
+    ///
+    /// The same key, message and context always give the same signature. Only
+    /// available after a Crypto Officer enables it with
+    /// [`policy::set_deterministic_signing`]; otherwise returns
+    /// [`PqcError::ServiceDisabled`].
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_sign_deterministic(
+        &self,
+        sk: &DilithiumSigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+    ) -> Result<DilithiumSignature> {
+        self.begin_service(ctx.is_empty() && self.deterministic_signing_allowed())?;
+        self.check_deterministic_signing()?;
+        dilithium_sign_deterministic_internal(sk, msg, ctx)
+    }
+
+    /// Signs a pre-hashed message with HashML-DSA-65 (Authenticated).
+    ///
+    /// `digest` is the caller-computed hash of the message under `hash_alg`, which
+    /// allows signing messages too large to buffer (FIPS 204, Section 5.4).
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_sign_prehashed(
+        &self,
+        sk: &DilithiumSigningKey,
+        digest: &[u8],
+        hash_alg: PreHashAlgorithm,
+        ctx: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<DilithiumSignature> {
+        self.begin_service(ctx.is_empty())?;
+        dilithium_sign_prehashed_internal(sk, digest, hash_alg, ctx, randomness)
+    }
+
+    /// Verifies a HashML-DSA-65 signature over a pre-hashed message (Authenticated).
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_verify_prehashed(
+        &self,
+        pk: &DilithiumVerifyingKey,
+        digest: &[u8],
+        hash_alg: PreHashAlgorithm,
+        ctx: &[u8],
+        sig: &DilithiumSignature,
+    ) -> Result<()> {
+        self.begin_service(ctx.is_empty())?;
+        dilithium_verify_prehashed_internal(pk, digest, hash_alg, ctx, sig)
+    }
+
+    /// Computes the ML-DSA-65 message representative μ (Authenticated).
+    ///
+    /// Lets a host hash the message while the module holding the signing key
+    /// only handles the 64-byte μ (FIPS 204, Section 6.2 "external μ").
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_compute_mu(
+        &self,
+        pk: &DilithiumVerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+    ) -> Result<DilithiumMu> {
+        self.begin_service(ctx.is_empty())?;
+        dilithium_compute_mu_internal(pk, msg, ctx)
+    }
+
+    /// Signs a precomputed μ with ML-DSA-65 (Authenticated).
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_sign_mu(
+        &self,
+        sk: &DilithiumSigningKey,
+        mu: &DilithiumMu,
+        randomness: [u8; 32],
+    ) -> Result<DilithiumSignature> {
+        self.begin_service(true)?;
+        dilithium_sign_mu_internal(sk, mu, randomness)
+    }
+
+    /// Verifies an ML-DSA-65 signature against a precomputed μ (Authenticated).
+    #[cfg(feature = "ml-dsa")]
+    pub fn dilithium_verify_mu(
+        &self,
+        pk: &DilithiumVerifyingKey,
+        mu: &DilithiumMu,
+        sig: &DilithiumSignature,
+    ) -> Result<()> {
+        self.begin_service(true)?;
+        dilithium_verify_mu_internal(pk, mu, sig)
+    }
+
+    /// Generates an ML-DSA-44 key pair (Authenticated).
+    #[cfg(feature = "ml-dsa-44")]
+    pub fn dilithium44_generate_key_pair(&self, seed: [u8; 32]) -> Result<Dilithium44Keypair> {
+        self.signature_generate_key_pair::<MlDsa44>(seed)
+    }
+
+    /// Signs a message with ML-DSA-44 (Authenticated).
+    #[cfg(feature = "ml-dsa-44")]
+    pub fn dilithium44_sign(
+        &self,
+        sk: &Dilithium44SigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<Dilithium44Signature> {
+        self.signature_sign::<MlDsa44>(sk, msg, ctx, randomness)
+    }
+
+    /// Verifies an ML-DSA-44 signature (Authenticated).
+    #[cfg(feature = "ml-dsa-44")]
+    pub fn dilithium44_verify(
+        &self,
+        pk: &Dilithium44VerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+        sig: &Dilithium44Signature,
+    ) -> Result<()> {
+        self.signature_verify::<MlDsa44>(pk, msg, ctx, sig)
+    }
+
+    /// Generates an ML-DSA-87 key pair (Authenticated).
+    #[cfg(feature = "ml-dsa-87")]
+    pub fn dilithium87_generate_key_pair(&self, seed: [u8; 32]) -> Result<Dilithium87Keypair> {
+        self.signature_generate_key_pair::<MlDsa87>(seed)
+    }
+
+    /// Signs a message with ML-DSA-87 (Authenticated).
+    #[cfg(feature = "ml-dsa-87")]
+    pub fn dilithium87_sign(
+        &self,
+        sk: &Dilithium87SigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<Dilithium87Signature> {
+        self.signature_sign::<MlDsa87>(sk, msg, ctx, randomness)
+    }
+
+    /// Verifies an ML-DSA-87 signature (Authenticated).
+    #[cfg(feature = "ml-dsa-87")]
+    pub fn dilithium87_verify(
+        &self,
+        pk: &Dilithium87VerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+        sig: &Dilithium87Signature,
+    ) -> Result<()> {
+        self.signature_verify::<MlDsa87>(pk, msg, ctx, sig)
+    }
+
+    /// Generates an SLH-DSA-SHAKE-128s key pair from SK.seed || SK.prf || PK.seed (Authenticated).
+    #[cfg(feature = "slh-dsa")]
+    pub fn slh_dsa_shake_128s_generate_key_pair(
+        &self,
+        seed: [u8; 48],
+    ) -> Result<SlhDsaShake128sKeypair> {
+        self.begin_service(true)?;
+        let kp = slh_dsa_shake_128s_generate_key_pair_internal(seed);
+        if let Err(e) = slh_dsa_shake_128s_pct(&kp.verification_key, &kp.signing_key) {
+            self.enter_error_state();
+            return Err(e);
+        }
+        Ok(kp)
+    }
+
+    /// Signs a message with SLH-DSA-SHAKE-128s (Authenticated).
+    #[cfg(feature = "slh-dsa")]
+    pub fn slh_dsa_shake_128s_sign(
+        &self,
+        sk: &SlhDsaShake128sSigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        randomness: [u8; 16],
+    ) -> Result<SlhDsaShake128sSignature> {
+        self.begin_service(ctx.is_empty())?;
+        slh_dsa_shake_128s_sign_internal(sk, msg, ctx, randomness)
+    }
+
+    /// Verifies an SLH-DSA-SHAKE-128s signature (Authenticated).
+    #[cfg(feature = "slh-dsa")]
+    pub fn slh_dsa_shake_128s_verify(
+        &self,
+        pk: &SlhDsaShake128sVerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+        sig: &SlhDsaShake128sSignature,
+    ) -> Result<()> {
+        self.begin_service(ctx.is_empty())?;
+        slh_dsa_shake_128s_verify_internal(pk, msg, ctx, sig)
+    }
+
+    /// Generates an SLH-DSA-SHAKE-256s key pair from SK.seed || SK.prf || PK.seed (Authenticated).
+    #[cfg(feature = "slh-dsa")]
+    pub fn slh_dsa_shake_256s_generate_key_pair(
+        &self,
+        seed: [u8; 96],
+    ) -> Result<SlhDsaShake256sKeypair> {
+        self.begin_service(true)?;
+        let kp = slh_dsa_shake_256s_generate_key_pair_internal(seed);
+        if let Err(e) = slh_dsa_shake_256s_pct(&kp.verification_key, &kp.signing_key) {
+            self.enter_error_state();
+            return Err(e);
+        }
+        Ok(kp)
+    }
+
+    /// Signs a message with SLH-DSA-SHAKE-256s (Authenticated).
+    #[cfg(feature = "slh-dsa")]
+    pub fn slh_dsa_shake_256s_sign(
+        &self,
+        sk: &SlhDsaShake256sSigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<SlhDsaShake256sSignature> {
+        self.begin_service(ctx.is_empty())?;
+        slh_dsa_shake_256s_sign_internal(sk, msg, ctx, randomness)
+    }
+
+    /// Verifies an SLH-DSA-SHAKE-256s signature (Authenticated).
+    #[cfg(feature = "slh-dsa")]
+    pub fn slh_dsa_shake_256s_verify(
+        &self,
+        pk: &SlhDsaShake256sVerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+        sig: &SlhDsaShake256sSignature,
+    ) -> Result<()> {
+        self.begin_service(ctx.is_empty())?;
+        slh_dsa_shake_256s_verify_internal(pk, msg, ctx, sig)
+    }
+
+    /// Verifies an LMS signature (Authenticated).
+    #[cfg(feature = "lms")]
+    pub fn lms_verify(&self, pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+        self.begin_service(true)?;
+        lms_verify_internal(pk, msg, sig)
+    }
+
+    /// Verifies an HSS signature (Authenticated).
+    #[cfg(feature = "lms")]
+    pub fn hss_verify(&self, pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+        self.begin_service(true)?;
+        hss_verify_internal(pk, msg, sig)
+    }
+
+    /// Verifies a detached XMSS signature (Authenticated).
+    #[cfg(feature = "xmss")]
+    pub fn xmss_verify(&self, pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+        self.begin_service(true)?;
+        xmss_verify_internal(pk, msg, sig)
+    }
+
+    /// Verifies a detached XMSS^MT signature (Authenticated).
+    #[cfg(feature = "xmss")]
+    pub fn xmssmt_verify(&self, pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+        self.begin_service(true)?;
+        xmssmt_verify_internal(pk, msg, sig)
+    }
+
+    /// Generates an X-Wing key pair from a 32-byte seed (Authenticated).
+    #[cfg(feature = "xwing")]
+    pub fn xwing_generate_key_pair(&self, seed: [u8; XWING_SK_BYTES]) -> Result<XWingKeypair> {
+        self.begin_service(false)?;
+        let kp = xwing_generate_key_pair_internal(seed);
+        if let Err(e) = xwing_pct(&kp.public_key, &kp.private_key) {
+            self.enter_error_state();
+            return Err(e);
+        }
+        Ok(kp)
+    }
+
+    /// Encapsulates an X-Wing shared secret (Authenticated).
+    #[cfg(feature = "xwing")]
+    pub fn xwing_encapsulate(
+        &self,
+        pk: &XWingPublicKey,
+        eseed: [u8; 64],
+    ) -> Result<(XWingCiphertext, XWingSharedSecret)> {
+        self.begin_service(false)?;
+        Ok(xwing_encapsulate_internal(pk, eseed))
+    }
+
+    /// Decapsulates an X-Wing shared secret (Authenticated).
+    #[cfg(feature = "xwing")]
+    pub fn xwing_decapsulate(
+        &self,
+        sk: &XWingPrivateKey,
+        ct: &XWingCiphertext,
+    ) -> Result<XWingSharedSecret> {
+        self.begin_service(false)?;
+        Ok(xwing_decapsulate_internal(sk, ct))
+    }
+
+    /// Generates a SecP256r1MLKEM768 key pair and client `key_share` (Authenticated).
+    #[cfg(feature = "ecdh-mlkem")]
+    pub fn secp256r1_mlkem768_generate_key_pair(
+        &self,
+        seed: [u8; 96],
+    ) -> Result<SecP256r1MlKem768Keypair> {
+        self.begin_service(true)?;
+        let kp = secp256r1_mlkem768_generate_key_pair_internal(seed)?;
+        if let Err(e) = secp256r1_mlkem768_pct(&kp.client_share, &kp.private_key) {
+            self.enter_error_state();
+            return Err(e);
+        }
+        Ok(kp)
+    }
+
+    /// Answers a SecP256r1MLKEM768 client `key_share` with a server `key_share` and shared secret (Authenticated).
+    #[cfg(feature = "ecdh-mlkem")]
+    pub fn secp256r1_mlkem768_encapsulate(
+        &self,
+        client_share: &SecP256r1MlKem768ClientShare,
+        randomness: [u8; 64],
+    ) -> Result<(SecP256r1MlKem768ServerShare, SecP256r1MlKem768SharedSecret)> {
+        self.begin_service(true)?;
+        secp256r1_mlkem768_encapsulate_internal(client_share, randomness)
+    }
+
+    /// Derives the SecP256r1MLKEM768 shared secret from a server `key_share` (Authenticated).
+    #[cfg(feature = "ecdh-mlkem")]
+    pub fn secp256r1_mlkem768_decapsulate(
+        &self,
+        sk: &SecP256r1MlKem768PrivateKey,
+        server_share: &SecP256r1MlKem768ServerShare,
+    ) -> Result<SecP256r1MlKem768SharedSecret> {
+        self.begin_service(true)?;
+        secp256r1_mlkem768_decapsulate_internal(sk, server_share)
+    }
+
+    /// Generates a SecP384r1MLKEM1024 key pair and client `key_share` (Authenticated).
+    #[cfg(feature = "ecdh-mlkem")]
+    pub fn secp384r1_mlkem1024_generate_key_pair(
+        &self,
+        seed: [u8; 112],
+    ) -> Result<SecP384r1MlKem1024Keypair> {
+        self.begin_service(true)?;
+        let kp = secp384r1_mlkem1024_generate_key_pair_internal(seed)?;
+        if let Err(e) = secp384r1_mlkem1024_pct(&kp.client_share, &kp.private_key) {
+            self.enter_error_state();
+            return Err(e);
+        }
+        Ok(kp)
+    }
+
+    /// Answers a SecP384r1MLKEM1024 client `key_share` with a server `key_share` and shared secret (Authenticated).
+    #[cfg(feature = "ecdh-mlkem")]
+    pub fn secp384r1_mlkem1024_encapsulate(
+        &self,
+        client_share: &SecP384r1MlKem1024ClientShare,
+        randomness: [u8; 80],
+    ) -> Result<(
+        SecP384r1MlKem1024ServerShare,
+        SecP384r1MlKem1024SharedSecret,
+    )> {
+        self.begin_service(true)?;
+        secp384r1_mlkem1024_encapsulate_internal(client_share, randomness)
+    }
+
+    /// Derives the SecP384r1MLKEM1024 shared secret from a server `key_share` (Authenticated).
+    #[cfg(feature = "ecdh-mlkem")]
+    pub fn secp384r1_mlkem1024_decapsulate(
+        &self,
+        sk: &SecP384r1MlKem1024PrivateKey,
+        server_share: &SecP384r1MlKem1024ServerShare,
+    ) -> Result<SecP384r1MlKem1024SharedSecret> {
+        self.begin_service(true)?;
+        secp384r1_mlkem1024_decapsulate_internal(sk, server_share)
+    }
+
+    /// Generates a MLDSA65-ECDSA-P256-SHA512 composite key pair (Authenticated).
+    #[cfg(feature = "composite")]
+    pub fn mldsa65_ecdsa_p256_generate_key_pair(
+        &self,
+        seed: [u8; 64],
+    ) -> Result<MlDsa65EcdsaP256Keypair> {
+        self.begin_service(true)?;
+        let kp = mldsa65_ecdsa_p256_generate_key_pair_internal(seed)?;
+        if let Err(e) = mldsa65_ecdsa_p256_pct(&kp.verification_key, &kp.signing_key) {
+            self.enter_error_state();
+            return Err(e);
+        }
+        Ok(kp)
+    }
+
+    /// Signs a message with MLDSA65-ECDSA-P256-SHA512 (Authenticated).
+    #[cfg(feature = "composite")]
+    pub fn mldsa65_ecdsa_p256_sign(
+        &self,
+        sk: &MlDsa65EcdsaP256SigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<MlDsa65EcdsaP256Signature> {
+        self.begin_service(ctx.is_empty())?;
+        mldsa65_ecdsa_p256_sign_internal(sk, msg, ctx, randomness)
+    }
+
+    /// Verifies a MLDSA65-ECDSA-P256-SHA512 signature; both components must verify (Authenticated).
+    #[cfg(feature = "composite")]
+    pub fn mldsa65_ecdsa_p256_verify(
+        &self,
+        pk: &MlDsa65EcdsaP256VerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+        sig: &MlDsa65EcdsaP256Signature,
+    ) -> Result<()> {
+        self.begin_service(ctx.is_empty())?;
+        mldsa65_ecdsa_p256_verify_internal(pk, msg, ctx, sig)
+    }
+
+    /// Generates a MLDSA65-Ed25519-SHA512 composite key pair (Authenticated).
+    #[cfg(feature = "composite")]
+    pub fn mldsa65_ed25519_generate_key_pair(
+        &self,
+        seed: [u8; 64],
+    ) -> Result<MlDsa65Ed25519Keypair> {
+        self.begin_service(true)?;
+        let kp = mldsa65_ed25519_generate_key_pair_internal(seed)?;
+        if let Err(e) = mldsa65_ed25519_pct(&kp.verification_key, &kp.signing_key) {
+            self.enter_error_state();
+            return Err(e);
+        }
+        Ok(kp)
+    }
+
+    /// Signs a message with MLDSA65-Ed25519-SHA512 (Authenticated).
+    #[cfg(feature = "composite")]
+    pub fn mldsa65_ed25519_sign(
+        &self,
+        sk: &MlDsa65Ed25519SigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<MlDsa65Ed25519Signature> {
+        self.begin_service(ctx.is_empty())?;
+        mldsa65_ed25519_sign_internal(sk, msg, ctx, randomness)
+    }
+
+    /// Verifies a MLDSA65-Ed25519-SHA512 signature; both components must verify (Authenticated).
+    #[cfg(feature = "composite")]
+    pub fn mldsa65_ed25519_verify(
+        &self,
+        pk: &MlDsa65Ed25519VerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+        sig: &MlDsa65Ed25519Signature,
+    ) -> Result<()> {
+        self.begin_service(ctx.is_empty())?;
+        mldsa65_ed25519_verify_internal(pk, msg, ctx, sig)
+    }
+
+    /// Sets up an HPKE sender context to an ML-KEM-1024 public key and returns
+    /// `enc` with it (Authenticated).
+    #[cfg(feature = "hpke")]
+    pub fn hpke_setup_sender_mlkem1024(
+        &self,
+        suite: HpkeSuite,
+        mode: HpkeMode<'_>,
+        pk: &KyberPublicKey,
+        info: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<(KyberCiphertext, HpkeSenderContext)> {
+        self.begin_service(suite.aead != HpkeAead::ChaCha20Poly1305)?;
+        hpke_setup_sender_mlkem1024_internal(suite, mode, pk, info, randomness)
+    }
+
+    /// Sets up an HPKE recipient context from an ML-KEM-1024 `enc` (Authenticated).
+    #[cfg(feature = "hpke")]
+    pub fn hpke_setup_recipient_mlkem1024(
+        &self,
+        suite: HpkeSuite,
+        mode: HpkeMode<'_>,
+        sk: &KyberPrivateKey,
+        enc: &KyberCiphertext,
+        info: &[u8],
+    ) -> Result<HpkeRecipientContext> {
+        self.begin_service(suite.aead != HpkeAead::ChaCha20Poly1305)?;
+        hpke_setup_recipient_mlkem1024_internal(suite, mode, sk, enc, info)
+    }
+
+    /// Sets up an HPKE sender context to an X-Wing public key and returns `enc`
+    /// with it (Authenticated).
+    #[cfg(all(feature = "hpke", feature = "xwing"))]
+    pub fn hpke_setup_sender_xwing(
+        &self,
+        suite: HpkeSuite,
+        mode: HpkeMode<'_>,
+        pk: &XWingPublicKey,
+        info: &[u8],
+        eseed: [u8; 64],
+    ) -> Result<(XWingCiphertext, HpkeSenderContext)> {
+        self.begin_service(false)?;
+        hpke_setup_sender_xwing_internal(suite, mode, pk, info, eseed)
+    }
+
+    /// Sets up an HPKE recipient context from an X-Wing `enc` (Authenticated).
+    #[cfg(all(feature = "hpke", feature = "xwing"))]
+    pub fn hpke_setup_recipient_xwing(
+        &self,
+        suite: HpkeSuite,
+        mode: HpkeMode<'_>,
+        sk: &XWingPrivateKey,
+        enc: &XWingCiphertext,
+        info: &[u8],
+    ) -> Result<HpkeRecipientContext> {
+        self.begin_service(false)?;
+        hpke_setup_recipient_xwing_internal(suite, mode, sk, enc, info)
+    }
+
+    /// Seals a message with an HPKE sender context into `out`, returning the
+    /// ciphertext length (Authenticated).
+    #[cfg(feature = "hpke")]
+    pub fn hpke_seal(
+        &self,
+        ctx: &mut HpkeSenderContext,
+        aad: &[u8],
+        plaintext: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize> {
+        self.begin_service(ctx.is_approved())?;
+        ctx.seal(aad, plaintext, out)
+    }
+
+    /// Opens a message with an HPKE recipient context into `out`, returning the
+    /// plaintext length (Authenticated).
+    #[cfg(feature = "hpke")]
+    pub fn hpke_open(
+        &self,
+        ctx: &mut HpkeRecipientContext,
+        aad: &[u8],
+        ciphertext: &[u8],
+        out: &mut [u8],
+    ) -> Result<usize> {
+        self.begin_service(ctx.is_approved())?;
+        ctx.open(aad, ciphertext, out)
+    }
+
+    /// Exports a secret from an HPKE sender context into `out` (Authenticated).
+    #[cfg(feature = "hpke")]
+    pub fn hpke_sender_export(
+        &self,
+        ctx: &HpkeSenderContext,
+        exporter_context: &[u8],
+        out: &mut [u8],
+    ) -> Result<()> {
+        self.begin_service(ctx.is_approved())?;
+        ctx.export(exporter_context, out)
+    }
+
+    /// Exports a secret from an HPKE recipient context into `out` (Authenticated).
+    #[cfg(feature = "hpke")]
+    pub fn hpke_recipient_export(
+        &self,
+        ctx: &HpkeRecipientContext,
+        exporter_context: &[u8],
+        out: &mut [u8],
+    ) -> Result<()> {
+        self.begin_service(ctx.is_approved())?;
+        ctx.export(exporter_context, out)
+    }
+}
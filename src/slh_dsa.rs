@@ -0,0 +1,734 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/slh_dsa.rs
+//! SLH-DSA (FIPS 205) with the SHAKE instantiation, parameter sets
+//! SLH-DSA-SHAKE-128s and SLH-DSA-SHAKE-256s.
+//!
+//! Trees are recomputed from the seeds on demand, so signing needs only a
+//! few KiB of stack besides the signature itself and never allocates.
+
+use crate::error::{PqcError, Result};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Largest security parameter n in bytes.
+const MAX_N: usize = 32;
+/// Largest WOTS+ chain count len (n = 32, lg_w = 4).
+const MAX_LEN: usize = 67;
+/// Largest number of FORS trees k.
+const MAX_K: usize = 22;
+/// Winternitz parameter w = 2^lg_w with lg_w = 4 for all parameter sets.
+const W: u32 = 16;
+
+type Node = [u8; MAX_N];
+
+/// SLH-DSA parameter set (FIPS 205, Table 2).
+struct Params {
+    n: usize,
+    h: usize,
+    d: usize,
+    hp: usize,
+    a: usize,
+    k: usize,
+    len: usize,
+    m: usize,
+}
+
+impl Params {
+    const fn sig_bytes(&self) -> usize {
+        (1 + self.k * (1 + self.a) + self.h + self.d * self.len) * self.n
+    }
+
+    const fn xmss_bytes(&self) -> usize {
+        (self.len + self.hp) * self.n
+    }
+
+    const fn fors_bytes(&self) -> usize {
+        self.k * (1 + self.a) * self.n
+    }
+}
+
+const SHAKE_128S: Params = Params {
+    n: 16,
+    h: 63,
+    d: 7,
+    hp: 9,
+    a: 12,
+    k: 14,
+    len: 35,
+    m: 30,
+};
+
+const SHAKE_256S: Params = Params {
+    n: 32,
+    h: 64,
+    d: 8,
+    hp: 8,
+    a: 14,
+    k: 22,
+    len: 67,
+    m: 47,
+};
+
+// ADRS types (FIPS 205, Section 4.2)
+const WOTS_HASH: u32 = 0;
+const WOTS_PK: u32 = 1;
+const TREE: u32 = 2;
+const FORS_TREE: u32 = 3;
+const FORS_ROOTS: u32 = 4;
+const WOTS_PRF: u32 = 5;
+const FORS_PRF: u32 = 6;
+
+/// 32-byte hash address ADRS.
+#[derive(Clone, Copy)]
+struct Adrs([u8; 32]);
+
+impl Adrs {
+    fn new() -> Self {
+        Adrs([0u8; 32])
+    }
+
+    fn set_word(&mut self, offset: usize, value: u32) {
+        self.0[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+    }
+
+    fn word(&self, offset: usize) -> u32 {
+        u32::from_be_bytes([
+            self.0[offset],
+            self.0[offset + 1],
+            self.0[offset + 2],
+            self.0[offset + 3],
+        ])
+    }
+
+    fn set_layer(&mut self, layer: u32) {
+        self.set_word(0, layer);
+    }
+
+    fn set_tree(&mut self, tree: u64) {
+        self.0[4..8].fill(0);
+        self.0[8..16].copy_from_slice(&tree.to_be_bytes());
+    }
+
+    fn set_type_and_clear(&mut self, ty: u32) {
+        self.set_word(16, ty);
+        self.0[20..].fill(0);
+    }
+
+    fn set_key_pair(&mut self, idx: u32) {
+        self.set_word(20, idx);
+    }
+
+    fn key_pair(&self) -> u32 {
+        self.word(20)
+    }
+
+    fn set_chain(&mut self, i: u32) {
+        self.set_word(24, i);
+    }
+
+    fn set_tree_height(&mut self, z: u32) {
+        self.set_word(24, z);
+    }
+
+    fn set_hash(&mut self, i: u32) {
+        self.set_word(28, i);
+    }
+
+    fn set_tree_index(&mut self, i: u32) {
+        self.set_word(28, i);
+    }
+
+    fn tree_index(&self) -> u32 {
+        self.word(28)
+    }
+}
+
+/// Keyed hash state shared by F, H, T_l and PRF: SHAKE256(PK.seed || ADRS || ...).
+struct Hasher<'a> {
+    p: &'static Params,
+    pk_seed: &'a [u8],
+    sk_seed: &'a [u8],
+}
+
+impl Hasher<'_> {
+    /// F, H and T_l of FIPS 205, Section 11.1.
+    fn thash(&self, adrs: &Adrs, inputs: &[&[u8]]) -> Node {
+        let mut h = Shake256::default();
+        h.update(self.pk_seed);
+        h.update(&adrs.0);
+        for input in inputs {
+            h.update(input);
+        }
+        let mut out = [0u8; MAX_N];
+        h.finalize_xof().read(&mut out[..self.p.n]);
+        out
+    }
+
+    /// PRF(PK.seed, SK.seed, ADRS).
+    fn prf(&self, adrs: &Adrs) -> Node {
+        self.thash(adrs, &[self.sk_seed])
+    }
+
+    /// Iterates F `steps` times starting at chain position `start` (Algorithm 5).
+    fn chain(&self, x: &mut Node, start: u32, steps: u32, adrs: &mut Adrs) {
+        let n = self.p.n;
+        for j in start..start + steps {
+            adrs.set_hash(j);
+            *x = self.thash(adrs, &[&x[..n]]);
+        }
+    }
+
+    /// Base-16 message digits followed by the checksum digits (Algorithms 7–8).
+    fn wots_digits(&self, msg: &[u8]) -> [u8; MAX_LEN] {
+        let len1 = 2 * self.p.n;
+        let mut digits = [0u8; MAX_LEN];
+        let mut csum: u32 = 0;
+        for i in 0..len1 {
+            digits[i] = (msg[i / 2] >> (4 - 4 * (i % 2))) & 0x0F;
+            csum += W - 1 - digits[i] as u32;
+        }
+        // len2 = 3 digits, csum shifted left by 4 into two bytes
+        csum <<= 4;
+        digits[len1] = ((csum >> 12) & 0x0F) as u8;
+        digits[len1 + 1] = ((csum >> 8) & 0x0F) as u8;
+        digits[len1 + 2] = ((csum >> 4) & 0x0F) as u8;
+        digits
+    }
+
+    /// Compresses the chain ends into a WOTS+ public key.
+    fn wots_compress(&self, ends: &[u8], adrs: &Adrs) -> Node {
+        let mut pk_adrs = *adrs;
+        pk_adrs.set_type_and_clear(WOTS_PK);
+        pk_adrs.set_key_pair(adrs.key_pair());
+        self.thash(&pk_adrs, &[&ends[..self.p.len * self.p.n]])
+    }
+
+    /// Secret value of chain `i` for the key pair in `adrs`.
+    fn wots_sk(&self, adrs: &Adrs, i: u32) -> Node {
+        let mut sk_adrs = *adrs;
+        sk_adrs.set_type_and_clear(WOTS_PRF);
+        sk_adrs.set_key_pair(adrs.key_pair());
+        sk_adrs.set_chain(i);
+        self.prf(&sk_adrs)
+    }
+
+    /// WOTS+ public key generation (Algorithm 6).
+    fn wots_pk_gen(&self, adrs: &mut Adrs) -> Node {
+        let n = self.p.n;
+        let mut ends = [0u8; MAX_LEN * MAX_N];
+        for i in 0..self.p.len {
+            let mut x = self.wots_sk(adrs, i as u32);
+            adrs.set_chain(i as u32);
+            self.chain(&mut x, 0, W - 1, adrs);
+            ends[i * n..(i + 1) * n].copy_from_slice(&x[..n]);
+            x.zeroize();
+        }
+        self.wots_compress(&ends, adrs)
+    }
+
+    /// WOTS+ signature generation (Algorithm 7).
+    fn wots_sign(&self, msg: &[u8], adrs: &mut Adrs, sig: &mut [u8]) {
+        let n = self.p.n;
+        let digits = self.wots_digits(msg);
+        for i in 0..self.p.len {
+            let mut x = self.wots_sk(adrs, i as u32);
+            adrs.set_chain(i as u32);
+            self.chain(&mut x, 0, digits[i] as u32, adrs);
+            sig[i * n..(i + 1) * n].copy_from_slice(&x[..n]);
+            x.zeroize();
+        }
+    }
+
+    /// WOTS+ public key from a signature (Algorithm 8).
+    fn wots_pk_from_sig(&self, sig: &[u8], msg: &[u8], adrs: &mut Adrs) -> Node {
+        let n = self.p.n;
+        let digits = self.wots_digits(msg);
+        let mut ends = [0u8; MAX_LEN * MAX_N];
+        for i in 0..self.p.len {
+            let mut x = [0u8; MAX_N];
+            x[..n].copy_from_slice(&sig[i * n..(i + 1) * n]);
+            adrs.set_chain(i as u32);
+            self.chain(&mut x, digits[i] as u32, W - 1 - digits[i] as u32, adrs);
+            ends[i * n..(i + 1) * n].copy_from_slice(&x[..n]);
+        }
+        self.wots_compress(&ends, adrs)
+    }
+
+    /// Root of the XMSS subtree of height `z` at index `i` (Algorithm 9).
+    fn xmss_node(&self, i: u32, z: u32, adrs: &mut Adrs) -> Node {
+        if z == 0 {
+            adrs.set_type_and_clear(WOTS_HASH);
+            adrs.set_key_pair(i);
+            return self.wots_pk_gen(adrs);
+        }
+        let n = self.p.n;
+        let left = self.xmss_node(2 * i, z - 1, adrs);
+        let right = self.xmss_node(2 * i + 1, z - 1, adrs);
+        adrs.set_type_and_clear(TREE);
+        adrs.set_tree_height(z);
+        adrs.set_tree_index(i);
+        self.thash(adrs, &[&left[..n], &right[..n]])
+    }
+
+    /// XMSS signature generation (Algorithm 10).
+    fn xmss_sign(&self, msg: &[u8], idx: u32, adrs: &mut Adrs, sig: &mut [u8]) {
+        let n = self.p.n;
+        let auth_off = self.p.len * n;
+        for j in 0..self.p.hp {
+            let k = (idx >> j) ^ 1;
+            let node = self.xmss_node(k, j as u32, adrs);
+            sig[auth_off + j * n..auth_off + (j + 1) * n].copy_from_slice(&node[..n]);
+        }
+        adrs.set_type_and_clear(WOTS_HASH);
+        adrs.set_key_pair(idx);
+        self.wots_sign(msg, adrs, &mut sig[..auth_off]);
+    }
+
+    /// XMSS root from a signature (Algorithm 11).
+    fn xmss_pk_from_sig(&self, idx: u32, sig: &[u8], msg: &[u8], adrs: &mut Adrs) -> Node {
+        let n = self.p.n;
+        let auth_off = self.p.len * n;
+        adrs.set_type_and_clear(WOTS_HASH);
+        adrs.set_key_pair(idx);
+        let mut node = self.wots_pk_from_sig(&sig[..auth_off], msg, adrs);
+        adrs.set_type_and_clear(TREE);
+        adrs.set_tree_index(idx);
+        for k in 0..self.p.hp {
+            let auth = &sig[auth_off + k * n..auth_off + (k + 1) * n];
+            adrs.set_tree_height(k as u32 + 1);
+            if (idx >> k) & 1 == 0 {
+                adrs.set_tree_index(adrs.tree_index() / 2);
+                node = self.thash(adrs, &[&node[..n], auth]);
+            } else {
+                adrs.set_tree_index((adrs.tree_index() - 1) / 2);
+                node = self.thash(adrs, &[auth, &node[..n]]);
+            }
+        }
+        node
+    }
+
+    /// Hypertree signature generation (Algorithm 12).
+    fn ht_sign(&self, msg: &[u8], mut idx_tree: u64, mut idx_leaf: u32, sig: &mut [u8]) {
+        let n = self.p.n;
+        let xmss_bytes = self.p.xmss_bytes();
+        let mut adrs = Adrs::new();
+        adrs.set_tree(idx_tree);
+        self.xmss_sign(msg, idx_leaf, &mut adrs, &mut sig[..xmss_bytes]);
+        let mut root = self.xmss_pk_from_sig(idx_leaf, &sig[..xmss_bytes], msg, &mut adrs);
+        for j in 1..self.p.d {
+            idx_leaf = (idx_tree & ((1 << self.p.hp) - 1)) as u32;
+            idx_tree >>= self.p.hp;
+            adrs.set_layer(j as u32);
+            adrs.set_tree(idx_tree);
+            let layer_sig = &mut sig[j * xmss_bytes..(j + 1) * xmss_bytes];
+            self.xmss_sign(&root[..n], idx_leaf, &mut adrs, layer_sig);
+            if j < self.p.d - 1 {
+                root = self.xmss_pk_from_sig(idx_leaf, layer_sig, &root[..n], &mut adrs);
+            }
+        }
+    }
+
+    /// Hypertree signature verification (Algorithm 13).
+    fn ht_verify(
+        &self,
+        msg: &[u8],
+        sig: &[u8],
+        mut idx_tree: u64,
+        mut idx_leaf: u32,
+        pk_root: &[u8],
+    ) -> bool {
+        let n = self.p.n;
+        let xmss_bytes = self.p.xmss_bytes();
+        let mut adrs = Adrs::new();
+        adrs.set_tree(idx_tree);
+        let mut node = self.xmss_pk_from_sig(idx_leaf, &sig[..xmss_bytes], msg, &mut adrs);
+        for j in 1..self.p.d {
+            idx_leaf = (idx_tree & ((1 << self.p.hp) - 1)) as u32;
+            idx_tree >>= self.p.hp;
+            adrs.set_layer(j as u32);
+            adrs.set_tree(idx_tree);
+            let layer_sig = &sig[j * xmss_bytes..(j + 1) * xmss_bytes];
+            node = self.xmss_pk_from_sig(idx_leaf, layer_sig, &node[..n], &mut adrs);
+        }
+        node[..n] == *pk_root
+    }
+
+    /// FORS secret value at leaf `idx` (Algorithm 14).
+    fn fors_sk_gen(&self, adrs: &Adrs, idx: u32) -> Node {
+        let mut sk_adrs = *adrs;
+        sk_adrs.set_type_and_clear(FORS_PRF);
+        sk_adrs.set_key_pair(adrs.key_pair());
+        sk_adrs.set_tree_index(idx);
+        self.prf(&sk_adrs)
+    }
+
+    /// Root of the FORS subtree of height `z` at index `i` (Algorithm 15).
+    fn fors_node(&self, i: u32, z: u32, adrs: &mut Adrs) -> Node {
+        let n = self.p.n;
+        if z == 0 {
+            let mut sk = self.fors_sk_gen(adrs, i);
+            adrs.set_tree_height(0);
+            adrs.set_tree_index(i);
+            let node = self.thash(adrs, &[&sk[..n]]);
+            sk.zeroize();
+            return node;
+        }
+        let left = self.fors_node(2 * i, z - 1, adrs);
+        let right = self.fors_node(2 * i + 1, z - 1, adrs);
+        adrs.set_tree_height(z);
+        adrs.set_tree_index(i);
+        self.thash(adrs, &[&left[..n], &right[..n]])
+    }
+
+    /// Leaf index of FORS tree `i`: the i-th a-bit field of `md` (base_2b).
+    fn fors_index(&self, md: &[u8], i: usize) -> u32 {
+        let a = self.p.a;
+        let mut v: u32 = 0;
+        for bit in i * a..(i + 1) * a {
+            v = (v << 1) | ((md[bit / 8] >> (7 - bit % 8)) & 1) as u32;
+        }
+        v
+    }
+
+    /// FORS signature generation (Algorithm 16).
+    fn fors_sign(&self, md: &[u8], adrs: &mut Adrs, sig: &mut [u8]) {
+        let (n, a) = (self.p.n, self.p.a);
+        for i in 0..self.p.k {
+            let idx = self.fors_index(md, i);
+            let base = i * (1 + a) * n;
+            let mut sk = self.fors_sk_gen(adrs, ((i as u32) << a) + idx);
+            sig[base..base + n].copy_from_slice(&sk[..n]);
+            sk.zeroize();
+            for j in 0..a {
+                let s = (idx >> j) ^ 1;
+                let node = self.fors_node(((i as u32) << (a - j)) + s, j as u32, adrs);
+                sig[base + (1 + j) * n..base + (2 + j) * n].copy_from_slice(&node[..n]);
+            }
+        }
+    }
+
+    /// FORS public key from a signature (Algorithm 17).
+    fn fors_pk_from_sig(&self, sig: &[u8], md: &[u8], adrs: &mut Adrs) -> Node {
+        let (n, a) = (self.p.n, self.p.a);
+        let mut roots = [0u8; MAX_K * MAX_N];
+        for i in 0..self.p.k {
+            let idx = self.fors_index(md, i);
+            let base = i * (1 + a) * n;
+            adrs.set_tree_height(0);
+            adrs.set_tree_index(((i as u32) << a) + idx);
+            let mut node = self.thash(adrs, &[&sig[base..base + n]]);
+            for j in 0..a {
+                let auth = &sig[base + (1 + j) * n..base + (2 + j) * n];
+                adrs.set_tree_height(j as u32 + 1);
+                if (idx >> j) & 1 == 0 {
+                    adrs.set_tree_index(adrs.tree_index() / 2);
+                    node = self.thash(adrs, &[&node[..n], auth]);
+                } else {
+                    adrs.set_tree_index((adrs.tree_index() - 1) / 2);
+                    node = self.thash(adrs, &[auth, &node[..n]]);
+                }
+            }
+            roots[i * n..(i + 1) * n].copy_from_slice(&node[..n]);
+        }
+        let mut pk_adrs = *adrs;
+        pk_adrs.set_type_and_clear(FORS_ROOTS);
+        pk_adrs.set_key_pair(adrs.key_pair());
+        self.thash(&pk_adrs, &[&roots[..self.p.k * n]])
+    }
+}
+
+/// Absorbs M' = 0 || |ctx| || ctx || M (FIPS 205, Algorithm 22).
+fn absorb_message(h: &mut Shake256, msg: &[u8], ctx: &[u8]) {
+    h.update(&[0, ctx.len() as u8]);
+    h.update(ctx);
+    h.update(msg);
+}
+
+/// Splits H_msg into (md, idx_tree, idx_leaf) (Algorithm 19, lines 7–12).
+fn split_digest<'a>(p: &Params, digest: &'a [u8]) -> (&'a [u8], u64, u32) {
+    let md_len = (p.k * p.a).div_ceil(8);
+    let tree_bits = p.h - p.h / p.d;
+    let tree_len = tree_bits.div_ceil(8);
+    let leaf_len = (p.h / p.d).div_ceil(8);
+    let mut idx_tree: u64 = 0;
+    for b in &digest[md_len..md_len + tree_len] {
+        idx_tree = (idx_tree << 8) | *b as u64;
+    }
+    let mut idx_leaf: u32 = 0;
+    for b in &digest[md_len + tree_len..md_len + tree_len + leaf_len] {
+        idx_leaf = (idx_leaf << 8) | *b as u32;
+    }
+    let tree_mask = if tree_bits == 64 {
+        u64::MAX
+    } else {
+        (1u64 << tree_bits) - 1
+    };
+    (
+        &digest[..md_len],
+        idx_tree & tree_mask,
+        idx_leaf & ((1u32 << (p.h / p.d)) - 1),
+    )
+}
+
+/// SLH-DSA key generation from SK.seed || SK.prf || PK.seed (Algorithm 18).
+///
+/// Writes SK.seed || SK.prf || PK.seed || PK.root to `sk`.
+fn keygen(p: &'static Params, seed: &[u8], sk: &mut [u8]) {
+    let n = p.n;
+    let hasher = Hasher {
+        p,
+        pk_seed: &seed[2 * n..3 * n],
+        sk_seed: &seed[..n],
+    };
+    let mut adrs = Adrs::new();
+    adrs.set_layer(p.d as u32 - 1);
+    let root = hasher.xmss_node(0, p.hp as u32, &mut adrs);
+    sk[..3 * n].copy_from_slice(&seed[..3 * n]);
+    sk[3 * n..4 * n].copy_from_slice(&root[..n]);
+}
+
+/// SLH-DSA signature generation over M' (Algorithms 19 and 22).
+fn sign(p: &'static Params, sk: &[u8], msg: &[u8], ctx: &[u8], opt_rand: &[u8], sig: &mut [u8]) {
+    let n = p.n;
+    let (sk_seed, sk_prf) = (&sk[..n], &sk[n..2 * n]);
+    let (pk_seed, pk_root) = (&sk[2 * n..3 * n], &sk[3 * n..4 * n]);
+
+    // R = PRF_msg(SK.prf, opt_rand, M')
+    let mut h = Shake256::default();
+    h.update(sk_prf);
+    h.update(opt_rand);
+    absorb_message(&mut h, msg, ctx);
+    h.finalize_xof().read(&mut sig[..n]);
+
+    // digest = H_msg(R, PK.seed, PK.root, M')
+    let mut digest = [0u8; 64];
+    let mut h = Shake256::default();
+    h.update(&sig[..n]);
+    h.update(pk_seed);
+    h.update(pk_root);
+    absorb_message(&mut h, msg, ctx);
+    h.finalize_xof().read(&mut digest[..p.m]);
+    let (md, idx_tree, idx_leaf) = split_digest(p, &digest[..p.m]);
+
+    let hasher = Hasher {
+        p,
+        pk_seed,
+        sk_seed,
+    };
+    let mut adrs = Adrs::new();
+    adrs.set_tree(idx_tree);
+    adrs.set_type_and_clear(FORS_TREE);
+    adrs.set_key_pair(idx_leaf);
+    let (fors_sig, ht_sig) = sig[n..].split_at_mut(p.fors_bytes());
+    hasher.fors_sign(md, &mut adrs, fors_sig);
+    let fors_pk = hasher.fors_pk_from_sig(fors_sig, md, &mut adrs);
+    hasher.ht_sign(&fors_pk[..n], idx_tree, idx_leaf, ht_sig);
+}
+
+/// SLH-DSA signature verification over M' (Algorithms 20 and 24).
+fn verify(p: &'static Params, pk: &[u8], msg: &[u8], ctx: &[u8], sig: &[u8]) -> bool {
+    let n = p.n;
+    if sig.len() != p.sig_bytes() {
+        return false;
+    }
+    let (pk_seed, pk_root) = (&pk[..n], &pk[n..2 * n]);
+
+    let mut digest = [0u8; 64];
+    let mut h = Shake256::default();
+    h.update(&sig[..n]);
+    h.update(pk_seed);
+    h.update(pk_root);
+    absorb_message(&mut h, msg, ctx);
+    h.finalize_xof().read(&mut digest[..p.m]);
+    let (md, idx_tree, idx_leaf) = split_digest(p, &digest[..p.m]);
+
+    let hasher = Hasher {
+        p,
+        pk_seed,
+        sk_seed: &[],
+    };
+    let mut adrs = Adrs::new();
+    adrs.set_tree(idx_tree);
+    adrs.set_type_and_clear(FORS_TREE);
+    adrs.set_key_pair(idx_leaf);
+    let (fors_sig, ht_sig) = sig[n..].split_at(p.fors_bytes());
+    let fors_pk = hasher.fors_pk_from_sig(fors_sig, md, &mut adrs);
+    hasher.ht_verify(&fors_pk[..n], ht_sig, idx_tree, idx_leaf, pk_root)
+}
+
+/// Defines the key, signature and service types of one SLH-DSA parameter set.
+macro_rules! slh_dsa_parameter_set {
+    (
+        $name:literal, $params:ident,
+        $sk:ident, $vk:ident, $sig:ident, $kp:ident,
+        $keygen:ident, $sign:ident, $verify:ident,
+        $n:literal, $pk_bytes:literal, $sk_bytes:literal, $sig_bytes:literal
+    ) => {
+        #[doc = concat!($name, " signing key (SK.seed || SK.prf || PK.seed || PK.root).")]
+        #[derive(Clone, Zeroize, ZeroizeOnDrop)]
+        pub struct $sk([u8; $sk_bytes]);
+
+        #[doc = concat!($name, " verification key (PK.seed || PK.root).")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $vk([u8; $pk_bytes]);
+
+        #[doc = concat!($name, " signature.")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $sig([u8; $sig_bytes]);
+
+        #[doc = concat!($name, " key pair.")]
+        #[derive(Clone)]
+        pub struct $kp {
+            /// Signing key.
+            pub signing_key: $sk,
+            /// Verification key.
+            pub verification_key: $vk,
+        }
+
+        impl $sk {
+            /// Size of the encoded key in bytes.
+            pub const SIZE: usize = $sk_bytes;
+
+            /// Wraps an encoded signing key.
+            pub fn new(bytes: [u8; $sk_bytes]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns the encoded key.
+            pub fn as_slice(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $sk {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl $vk {
+            /// Size of the encoded key in bytes.
+            pub const SIZE: usize = $pk_bytes;
+
+            /// Wraps an encoded verification key.
+            pub fn new(bytes: [u8; $pk_bytes]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns the encoded key.
+            pub fn as_slice(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $vk {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl $sig {
+            /// Size of the encoded signature in bytes.
+            pub const SIZE: usize = $sig_bytes;
+
+            /// Wraps an encoded signature.
+            pub fn new(bytes: [u8; $sig_bytes]) -> Self {
+                Self(bytes)
+            }
+
+            /// Returns the encoded signature.
+            pub fn as_slice(&self) -> &[u8] {
+                &self.0
+            }
+
+            /// Returns the encoded signature for modification.
+            pub fn as_mut_slice(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $sig {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        #[doc = concat!("Generates an ", $name, " key pair from SK.seed || SK.prf || PK.seed (Unauthenticated).")]
+        pub fn $keygen(mut seed: [u8; 3 * $n]) -> $kp {
+            let mut sk = [0u8; $sk_bytes];
+            keygen(&$params, &seed, &mut sk);
+            seed.zeroize();
+            let mut vk = [0u8; $pk_bytes];
+            vk.copy_from_slice(&sk[2 * $n..]);
+            $kp {
+                signing_key: $sk(sk),
+                verification_key: $vk(vk),
+            }
+        }
+
+        #[doc = concat!("Signs a message with ", $name, " using the hedged variant (Unauthenticated).")]
+        pub fn $sign(
+            sk: &$sk,
+            msg: &[u8],
+            ctx: &[u8],
+            randomness: [u8; $n],
+        ) -> Result<$sig> {
+            if ctx.len() > 255 {
+                return Err(PqcError::InvalidParameter);
+            }
+            let mut sig = $sig([0u8; $sig_bytes]);
+            sign(&$params, &sk.0, msg, ctx, &randomness, &mut sig.0);
+            Ok(sig)
+        }
+
+        #[doc = concat!("Verifies an ", $name, " signature (Unauthenticated).")]
+        pub fn $verify(pk: &$vk, msg: &[u8], ctx: &[u8], sig: &$sig) -> Result<()> {
+            if ctx.len() > 255 {
+                return Err(PqcError::InvalidParameter);
+            }
+            if verify(&$params, &pk.0, msg, ctx, &sig.0) {
+                Ok(())
+            } else {
+                Err(PqcError::FipsErrorState)
+            }
+        }
+    };
+}
+
+slh_dsa_parameter_set!(
+    "SLH-DSA-SHAKE-128s",
+    SHAKE_128S,
+    SlhDsaShake128sSigningKey,
+    SlhDsaShake128sVerifyingKey,
+    SlhDsaShake128sSignature,
+    SlhDsaShake128sKeypair,
+    generate_key_pair_shake_128s,
+    sign_shake_128s,
+    verify_shake_128s,
+    16,
+    32,
+    64,
+    7856
+);
+
+slh_dsa_parameter_set!(
+    "SLH-DSA-SHAKE-256s",
+    SHAKE_256S,
+    SlhDsaShake256sSigningKey,
+    SlhDsaShake256sVerifyingKey,
+    SlhDsaShake256sSignature,
+    SlhDsaShake256sKeypair,
+    generate_key_pair_shake_256s,
+    sign_shake_256s,
+    verify_shake_256s,
+    32,
+    64,
+    128,
+    29792
+);
@@ -6,7 +6,8 @@
 // Contact: aaronschnacky@gmail.com
 // src/state.rs
 use crate::error::{PqcError, Result};
-use core::sync::atomic::{AtomicU8, Ordering};
+use crate::module::FipsModule;
+use core::sync::atomic::Ordering;
 
 /// Represents the current state of the FIPS 140-3 module.
 #[repr(u8)]
@@ -22,43 +23,67 @@ pub enum FipsState {
     Error = 3,
 }
 
-static FIPS_STATE: AtomicU8 = AtomicU8::new(FipsState::Uninitialized as u8);
+impl FipsModule {
+    /// Returns the current FIPS state.
+    pub fn state(&self) -> FipsState {
+        FipsState::from(self.state.load(Ordering::Acquire))
+    }
 
-/// Returns the current FIPS state.
-pub fn get_fips_state() -> FipsState {
-    FipsState::from(FIPS_STATE.load(Ordering::Acquire))
-}
+    /// Checks if the module is in the Operational state.
+    pub fn is_operational(&self) -> bool {
+        self.state() == FipsState::Operational
+    }
 
-/// Checks if the module is in the Operational state.
-pub fn is_operational() -> bool {
-    get_fips_state() == FipsState::Operational
-}
+    /// Returns Ok(()) if operational, otherwise returns the specific error state.
+    pub fn check_operational(&self) -> Result<()> {
+        match self.state() {
+            FipsState::Operational => Ok(()),
+            FipsState::Uninitialized => Err(PqcError::FipsNotInitialized),
+            FipsState::POST => Err(PqcError::FipsPostInProgress),
+            FipsState::Error => Err(PqcError::FipsErrorState),
+        }
+    }
 
-/// Returns Ok(()) if operational, otherwise returns the specific error state.
-pub fn check_operational() -> Result<()> {
-    match get_fips_state() {
-        FipsState::Operational => Ok(()),
-        FipsState::Uninitialized => Err(PqcError::FipsNotInitialized),
-        FipsState::POST => Err(PqcError::FipsPostInProgress),
-        FipsState::Error => Err(PqcError::FipsErrorState),
+    /// Resets the FIPS state to Uninitialized.
+    pub fn reset_state(&self) {
+        self.set_state(FipsState::Uninitialized);
+    }
+
+    pub(crate) fn enter_post_state(&self) {
+        self.set_state(FipsState::POST);
+    }
+
+    pub(crate) fn enter_operational_state(&self) {
+        self.set_state(FipsState::Operational);
+    }
+
+    pub(crate) fn enter_error_state(&self) {
+        self.set_state(FipsState::Error);
+    }
+
+    fn set_state(&self, state: FipsState) {
+        self.state.store(state as u8, Ordering::Release);
     }
 }
 
-/// Resets the FIPS state to Uninitialized.
-pub fn reset_fips_state() {
-    FIPS_STATE.store(FipsState::Uninitialized as u8, Ordering::Release);
+/// Returns the current FIPS state of the global module.
+pub fn get_fips_state() -> FipsState {
+    FipsModule::global().state()
 }
 
-pub(crate) fn enter_post_state() {
-    FIPS_STATE.store(FipsState::POST as u8, Ordering::Release);
+/// Checks if the global module is in the Operational state.
+pub fn is_operational() -> bool {
+    FipsModule::global().is_operational()
 }
 
-pub(crate) fn enter_operational_state() {
-    FIPS_STATE.store(FipsState::Operational as u8, Ordering::Release);
+/// Returns Ok(()) if the global module is operational, otherwise returns the specific error state.
+pub fn check_operational() -> Result<()> {
+    FipsModule::global().check_operational()
 }
 
-pub(crate) fn enter_error_state() {
-    FIPS_STATE.store(FipsState::Error as u8, Ordering::Release);
+/// Resets the FIPS state of the global module to Uninitialized.
+pub fn reset_fips_state() {
+    FipsModule::global().reset_state();
 }
 
 impl From<u8> for FipsState {
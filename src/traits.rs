@@ -0,0 +1,216 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/traits.rs
+//! Generic parameter-set abstraction over KEM and signature algorithms.
+//!
+//! Every ML-KEM and ML-DSA parameter set implements [`Kem`] or
+//! [`SignatureScheme`]. The authenticated [`FipsModule`] services below
+//! perform the state check, the authority check and the key generation PCT
+//! once for all of them; the per-parameter-set services delegate here.
+
+use crate::error::Result;
+use crate::module::FipsModule;
+use crate::pct::{kem_pct, signature_pct};
+
+/// A Key Encapsulation Mechanism parameter set (e.g. ML-KEM-1024).
+pub trait Kem {
+    /// Parameter set name.
+    const NAME: &'static str;
+    /// Public key size in bytes.
+    const PK_BYTES: usize;
+    /// Secret key size in bytes.
+    const SK_BYTES: usize;
+    /// Ciphertext size in bytes.
+    const CT_BYTES: usize;
+    /// Shared secret size in bytes.
+    const SS_BYTES: usize;
+
+    /// Encapsulation (public) key.
+    type PublicKey;
+    /// Decapsulation (private) key.
+    type PrivateKey;
+    /// Ciphertext.
+    type Ciphertext;
+    /// Key pair.
+    type Keypair;
+    /// Shared secret.
+    type SharedSecret: PartialEq;
+
+    /// Generates a key pair from a 64-byte seed (unauthenticated).
+    fn generate_key_pair_internal(seed: [u8; 64]) -> Self::Keypair;
+    /// Encapsulates a shared secret (unauthenticated).
+    fn encapsulate_internal(
+        pk: &Self::PublicKey,
+        randomness: [u8; 32],
+    ) -> (Self::Ciphertext, Self::SharedSecret);
+    /// Decapsulates a shared secret (unauthenticated).
+    fn decapsulate_internal(sk: &Self::PrivateKey, ct: &Self::Ciphertext) -> Self::SharedSecret;
+    /// Borrows the public key of a key pair.
+    fn public_key(kp: &Self::Keypair) -> &Self::PublicKey;
+    /// Borrows the private key of a key pair.
+    fn private_key(kp: &Self::Keypair) -> &Self::PrivateKey;
+}
+
+/// A digital signature parameter set (e.g. ML-DSA-65).
+pub trait SignatureScheme {
+    /// Parameter set name.
+    const NAME: &'static str;
+    /// Public (verification) key size in bytes.
+    const PK_BYTES: usize;
+    /// Secret (signing) key size in bytes.
+    const SK_BYTES: usize;
+    /// Signature size in bytes.
+    const SIG_BYTES: usize;
+
+    /// Signing key.
+    type SigningKey;
+    /// Verification key.
+    type VerifyingKey;
+    /// Signature.
+    type Signature;
+    /// Key pair.
+    type Keypair;
+
+    /// Generates a key pair from a 32-byte seed (unauthenticated).
+    fn generate_key_pair_internal(seed: [u8; 32]) -> Self::Keypair;
+    /// Signs `msg` under context `ctx` (unauthenticated).
+    fn sign_internal(
+        sk: &Self::SigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<Self::Signature>;
+    /// Verifies `sig` over `msg` under context `ctx` (unauthenticated).
+    fn verify_internal(
+        pk: &Self::VerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+        sig: &Self::Signature,
+    ) -> Result<()>;
+    /// Borrows the signing key of a key pair.
+    fn signing_key(kp: &Self::Keypair) -> &Self::SigningKey;
+    /// Borrows the verification key of a key pair.
+    fn verifying_key(kp: &Self::Keypair) -> &Self::VerifyingKey;
+}
+
+impl FipsModule {
+    /// Generates a KEM key pair and runs its PCT (Authenticated).
+    ///
+    /// A PCT failure moves the module to the Error state.
+    pub fn kem_generate_key_pair<K: Kem>(&self, seed: [u8; 64]) -> Result<K::Keypair> {
+        self.begin_service(true)?;
+        let kp = K::generate_key_pair_internal(seed);
+        if let Err(e) = kem_pct::<K>(K::public_key(&kp), K::private_key(&kp)) {
+            self.enter_error_state();
+            return Err(e);
+        }
+        Ok(kp)
+    }
+
+    /// Encapsulates a shared secret (Authenticated).
+    pub fn kem_encapsulate<K: Kem>(
+        &self,
+        pk: &K::PublicKey,
+        randomness: [u8; 32],
+    ) -> Result<(K::Ciphertext, K::SharedSecret)> {
+        self.begin_service(true)?;
+        Ok(K::encapsulate_internal(pk, randomness))
+    }
+
+    /// Decapsulates a shared secret (Authenticated).
+    pub fn kem_decapsulate<K: Kem>(
+        &self,
+        sk: &K::PrivateKey,
+        ct: &K::Ciphertext,
+    ) -> Result<K::SharedSecret> {
+        self.begin_service(true)?;
+        Ok(K::decapsulate_internal(sk, ct))
+    }
+
+    /// Generates a signature key pair and runs its PCT (Authenticated).
+    ///
+    /// A PCT failure moves the module to the Error state.
+    pub fn signature_generate_key_pair<S: SignatureScheme>(
+        &self,
+        seed: [u8; 32],
+    ) -> Result<S::Keypair> {
+        self.begin_service(true)?;
+        let kp = S::generate_key_pair_internal(seed);
+        if let Err(e) = signature_pct::<S>(S::verifying_key(&kp), S::signing_key(&kp)) {
+            self.enter_error_state();
+            return Err(e);
+        }
+        Ok(kp)
+    }
+
+    /// Signs a message (Authenticated).
+    pub fn signature_sign<S: SignatureScheme>(
+        &self,
+        sk: &S::SigningKey,
+        msg: &[u8],
+        ctx: &[u8],
+        randomness: [u8; 32],
+    ) -> Result<S::Signature> {
+        self.begin_service(ctx.is_empty())?;
+        S::sign_internal(sk, msg, ctx, randomness)
+    }
+
+    /// Verifies a signature (Authenticated).
+    pub fn signature_verify<S: SignatureScheme>(
+        &self,
+        pk: &S::VerifyingKey,
+        msg: &[u8],
+        ctx: &[u8],
+        sig: &S::Signature,
+    ) -> Result<()> {
+        self.begin_service(ctx.is_empty())?;
+        S::verify_internal(pk, msg, ctx, sig)
+    }
+}
+
+/// Generates a KEM key pair on the global module (Authenticated).
+pub fn kem_generate_key_pair<K: Kem>(seed: [u8; 64]) -> Result<K::Keypair> {
+    FipsModule::global().kem_generate_key_pair::<K>(seed)
+}
+
+/// Encapsulates a shared secret on the global module (Authenticated).
+pub fn kem_encapsulate<K: Kem>(
+    pk: &K::PublicKey,
+    randomness: [u8; 32],
+) -> Result<(K::Ciphertext, K::SharedSecret)> {
+    FipsModule::global().kem_encapsulate::<K>(pk, randomness)
+}
+
+/// Decapsulates a shared secret on the global module (Authenticated).
+pub fn kem_decapsulate<K: Kem>(sk: &K::PrivateKey, ct: &K::Ciphertext) -> Result<K::SharedSecret> {
+    FipsModule::global().kem_decapsulate::<K>(sk, ct)
+}
+
+/// Generates a signature key pair on the global module (Authenticated).
+pub fn signature_generate_key_pair<S: SignatureScheme>(seed: [u8; 32]) -> Result<S::Keypair> {
+    FipsModule::global().signature_generate_key_pair::<S>(seed)
+}
+
+/// Signs a message on the global module (Authenticated).
+pub fn signature_sign<S: SignatureScheme>(
+    sk: &S::SigningKey,
+    msg: &[u8],
+    ctx: &[u8],
+    randomness: [u8; 32],
+) -> Result<S::Signature> {
+    FipsModule::global().signature_sign::<S>(sk, msg, ctx, randomness)
+}
+
+/// Verifies a signature on the global module (Authenticated).
+pub fn signature_verify<S: SignatureScheme>(
+    pk: &S::VerifyingKey,
+    msg: &[u8],
+    ctx: &[u8],
+    sig: &S::Signature,
+) -> Result<()> {
+    FipsModule::global().signature_verify::<S>(pk, msg, ctx, sig)
+}
@@ -0,0 +1,356 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/xmss.rs
+//! XMSS and XMSS^MT stateful hash-based signature verification (RFC 8391,
+//! NIST SP 800-208).
+//!
+//! Covers the SP 800-208 parameter sets: SHA-256, SHA-256/192, SHAKE256/256
+//! and SHAKE256/192 with w = 16, XMSS tree heights 10, 16 and 20, and
+//! XMSS^MT total heights 20, 40 and 60. Signatures are detached (the message
+//! is passed separately) and parsed in place without allocating.
+//!
+//! As with LMS, signing is left to hardware modules (SP 800-208, Section 8.1).
+
+use crate::error::{PqcError, Result};
+use crate::hbs::{HashFamily, Hasher};
+
+/// Largest hash output n in bytes.
+const MAX_N: usize = 32;
+/// Largest WOTS+ chain count len (n = 32, w = 16).
+const MAX_LEN: usize = 67;
+/// Winternitz parameter w for all SP 800-208 parameter sets.
+const W: u8 = 16;
+
+// Hash function domain separators (RFC 8391, Section 5.1)
+const PAD_F: u8 = 0;
+const PAD_H: u8 = 1;
+const PAD_HASH: u8 = 2;
+const PAD_PRF: u8 = 3;
+
+// ADRS types (RFC 8391, Section 2.5)
+const OTS: u32 = 0;
+const LTREE: u32 = 1;
+const HASH_TREE: u32 = 2;
+
+/// XMSS or XMSS^MT parameter set (SP 800-208, Section 5).
+struct Params {
+    family: HashFamily,
+    n: usize,
+    h: usize,
+    d: usize,
+}
+
+impl Params {
+    fn xmss(oid: u32) -> Option<Self> {
+        let (family, n) = match oid {
+            0x01..=0x03 => (HashFamily::Sha256, 32),
+            0x0d..=0x0f => (HashFamily::Sha256, 24),
+            0x10..=0x12 => (HashFamily::Shake256, 32),
+            0x13..=0x15 => (HashFamily::Shake256, 24),
+            _ => return None,
+        };
+        let h = [10, 16, 20][(oid as usize - 1) % 3];
+        Some(Self { family, n, h, d: 1 })
+    }
+
+    fn xmssmt(oid: u32) -> Option<Self> {
+        let (family, n) = match oid {
+            0x01..=0x08 => (HashFamily::Sha256, 32),
+            0x21..=0x28 => (HashFamily::Sha256, 24),
+            0x29..=0x30 => (HashFamily::Shake256, 32),
+            0x31..=0x38 => (HashFamily::Shake256, 24),
+            _ => return None,
+        };
+        let (h, d) = [
+            (20, 2),
+            (20, 4),
+            (40, 2),
+            (40, 4),
+            (40, 8),
+            (60, 3),
+            (60, 6),
+            (60, 12),
+        ][(oid as usize - 1) % 8];
+        Some(Self { family, n, h, d })
+    }
+
+    /// Number of base-w digits of the message part, len_1.
+    fn len1(&self) -> usize {
+        2 * self.n
+    }
+
+    /// Number of WOTS+ chains, len = len_1 + len_2 with len_2 = 3.
+    fn len(&self) -> usize {
+        self.len1() + 3
+    }
+
+    /// Height of each tree layer.
+    fn tree_height(&self) -> usize {
+        self.h / self.d
+    }
+
+    /// Length of the leaf index at the start of a signature.
+    fn index_bytes(&self) -> usize {
+        if self.d == 1 {
+            4
+        } else {
+            self.h.div_ceil(8)
+        }
+    }
+
+    /// Length of a detached signature: idx || r || d * (WOTS+ sig || auth path).
+    fn sig_bytes(&self) -> usize {
+        self.index_bytes() + self.n * (1 + self.d * self.len() + self.h)
+    }
+
+    /// Length of the toByte padding in front of each hash input.
+    fn pad_len(&self) -> usize {
+        if self.n == 32 {
+            32
+        } else {
+            4
+        }
+    }
+}
+
+/// Hash address (RFC 8391, Section 2.5).
+#[derive(Clone, Copy)]
+struct Adrs([u32; 8]);
+
+impl Adrs {
+    fn new(layer: u32, tree: u64, kind: u32, word4: u32) -> Self {
+        Self([
+            layer,
+            (tree >> 32) as u32,
+            tree as u32,
+            kind,
+            word4,
+            0,
+            0,
+            0,
+        ])
+    }
+
+    fn set_word(&mut self, i: usize, value: u32) {
+        self.0[i] = value;
+    }
+
+    fn to_bytes(self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (chunk, word) in out.chunks_exact_mut(4).zip(self.0) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        out
+    }
+}
+
+// Word positions within an ADRS
+const CHAIN: usize = 5;
+const HASH: usize = 6;
+const TREE_HEIGHT: usize = 5;
+const TREE_INDEX: usize = 6;
+const KEY_AND_MASK: usize = 7;
+
+/// Keyed hash functions of one public key.
+struct Hash<'a> {
+    p: &'a Params,
+    pub_seed: &'a [u8],
+}
+
+impl Hash<'_> {
+    /// Starts a hash over toByte(pad, pad_len).
+    fn start(&self, pad: u8) -> Hasher {
+        let mut prefix = [0u8; 32];
+        let pad_len = self.p.pad_len();
+        prefix[pad_len - 1] = pad;
+        Hasher::new(self.p.family).chain(&prefix[..pad_len])
+    }
+
+    fn prf(&self, mut adrs: Adrs, key_and_mask: u32, out: &mut [u8]) {
+        adrs.set_word(KEY_AND_MASK, key_and_mask);
+        self.start(PAD_PRF)
+            .chain(self.pub_seed)
+            .chain(&adrs.to_bytes())
+            .finalize(out);
+    }
+
+    /// Chaining function F, applied in place.
+    fn f(&self, adrs: Adrs, node: &mut [u8]) {
+        let n = self.p.n;
+        let mut key = [0u8; MAX_N];
+        let mut mask = [0u8; MAX_N];
+        self.prf(adrs, 0, &mut key[..n]);
+        self.prf(adrs, 1, &mut mask[..n]);
+        for (m, x) in mask.iter_mut().zip(node.iter()) {
+            *m ^= x;
+        }
+        self.start(PAD_F)
+            .chain(&key[..n])
+            .chain(&mask[..n])
+            .finalize(node);
+    }
+
+    /// Tree hash function H.
+    fn h(&self, adrs: Adrs, left: &[u8], right: &[u8], out: &mut [u8]) {
+        let n = self.p.n;
+        let mut key = [0u8; MAX_N];
+        let mut mask = [0u8; 2 * MAX_N];
+        self.prf(adrs, 0, &mut key[..n]);
+        self.prf(adrs, 1, &mut mask[..n]);
+        self.prf(adrs, 2, &mut mask[n..2 * n]);
+        for (m, x) in mask.iter_mut().zip(left.iter().chain(right.iter())) {
+            *m ^= x;
+        }
+        self.start(PAD_H)
+            .chain(&key[..n])
+            .chain(&mask[..2 * n])
+            .finalize(out);
+    }
+
+    /// Computes a WOTS+ public key from a signature on `msg` (RFC 8391, Algorithm 6).
+    fn wots_pk_from_sig(&self, sig: &[u8], msg: &[u8], mut adrs: Adrs, pk: &mut [u8]) {
+        let n = self.p.n;
+        let len1 = self.p.len1();
+        let mut digits = [0u8; MAX_LEN];
+        for (i, digit) in digits[..len1].iter_mut().enumerate() {
+            *digit = (msg[i / 2] >> (4 * (1 - i % 2))) & 0x0f;
+        }
+        let csum: u32 = digits[..len1].iter().map(|&d| u32::from(W - 1 - d)).sum();
+        let csum = ((csum << 4) as u16).to_be_bytes();
+        digits[len1] = csum[0] >> 4;
+        digits[len1 + 1] = csum[0] & 0x0f;
+        digits[len1 + 2] = csum[1] >> 4;
+
+        for (i, (node, sig_i)) in pk.chunks_exact_mut(n).zip(sig.chunks_exact(n)).enumerate() {
+            adrs.set_word(CHAIN, i as u32);
+            node.copy_from_slice(sig_i);
+            for j in digits[i]..W - 1 {
+                adrs.set_word(HASH, u32::from(j));
+                self.f(adrs, node);
+            }
+        }
+    }
+
+    /// Compresses a WOTS+ public key into its leaf (RFC 8391, Algorithm 8).
+    fn l_tree(&self, pk: &mut [u8], mut adrs: Adrs) {
+        let n = self.p.n;
+        let mut len = self.p.len();
+        let mut height = 0;
+        let mut node = [0u8; MAX_N];
+        while len > 1 {
+            adrs.set_word(TREE_HEIGHT, height);
+            for i in 0..len / 2 {
+                adrs.set_word(TREE_INDEX, i as u32);
+                let (left, right) = pk[2 * i * n..(2 * i + 2) * n].split_at(n);
+                self.h(adrs, left, right, &mut node[..n]);
+                pk[i * n..(i + 1) * n].copy_from_slice(&node[..n]);
+            }
+            if len % 2 == 1 {
+                pk.copy_within((len - 1) * n..len * n, (len / 2) * n);
+            }
+            len = len.div_ceil(2);
+            height += 1;
+        }
+    }
+
+    /// Climbs from a leaf to its tree root along the authentication path.
+    fn compute_root(&self, node: &mut [u8], mut idx: u32, auth: &[u8], mut adrs: Adrs) {
+        let n = self.p.n;
+        let mut parent = [0u8; MAX_N];
+        for (k, sibling) in auth.chunks_exact(n).enumerate() {
+            adrs.set_word(TREE_HEIGHT, k as u32);
+            adrs.set_word(TREE_INDEX, idx >> 1);
+            if idx % 2 == 1 {
+                self.h(adrs, sibling, node, &mut parent[..n]);
+            } else {
+                self.h(adrs, node, sibling, &mut parent[..n]);
+            }
+            node.copy_from_slice(&parent[..n]);
+            idx >>= 1;
+        }
+    }
+}
+
+fn read_oid(pk: &[u8]) -> Result<u32> {
+    pk.get(..4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(PqcError::InvalidParameter)
+}
+
+/// Verifies a detached signature over the layered trees of `p`
+/// (RFC 8391, Algorithms 14 and 17).
+fn verify_with(p: &Params, pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    let n = p.n;
+    if pk.len() != 4 + 2 * n || sig.len() != p.sig_bytes() {
+        return Err(PqcError::InvalidParameter);
+    }
+    let (root, pub_seed) = pk[4..].split_at(n);
+    let hash = Hash { p, pub_seed };
+
+    let ib = p.index_bytes();
+    let mut idx = sig[..ib]
+        .iter()
+        .fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+    if idx >> p.h != 0 {
+        return Err(PqcError::InvalidParameter);
+    }
+
+    // M' = H_msg(r || root || toByte(idx, n), M)
+    let (r, mut rest) = sig[ib..].split_at(n);
+    let mut idx_bytes = [0u8; MAX_N];
+    idx_bytes[n - 8..n].copy_from_slice(&idx.to_be_bytes());
+    let mut node = [0u8; MAX_N];
+    hash.start(PAD_HASH)
+        .chain(r)
+        .chain(root)
+        .chain(&idx_bytes[..n])
+        .chain(msg)
+        .finalize(&mut node[..n]);
+
+    // Each layer signs the root of the layer below it
+    let th = p.tree_height();
+    let mut wots_pk = [0u8; MAX_LEN * MAX_N];
+    for layer in 0..p.d as u32 {
+        let idx_leaf = (idx & ((1 << th) - 1)) as u32;
+        idx >>= th;
+        let (wots_sig, tail) = rest.split_at(p.len() * n);
+        let (auth, tail) = tail.split_at(th * n);
+        rest = tail;
+
+        let wots_pk = &mut wots_pk[..p.len() * n];
+        let ots = Adrs::new(layer, idx, OTS, idx_leaf);
+        hash.wots_pk_from_sig(wots_sig, &node[..n], ots, wots_pk);
+        hash.l_tree(wots_pk, Adrs::new(layer, idx, LTREE, idx_leaf));
+        node[..n].copy_from_slice(&wots_pk[..n]);
+        let tree = Adrs::new(layer, idx, HASH_TREE, 0);
+        hash.compute_root(&mut node[..n], idx_leaf, auth, tree);
+    }
+
+    if node[..n] == *root {
+        Ok(())
+    } else {
+        Err(PqcError::FipsErrorState)
+    }
+}
+
+/// Verifies a detached XMSS signature (Unauthenticated).
+///
+/// `pk` is OID || root || SEED. Unknown OIDs and malformed encodings give
+/// [`PqcError::InvalidParameter`].
+pub fn verify(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    let p = Params::xmss(read_oid(pk)?).ok_or(PqcError::InvalidParameter)?;
+    verify_with(&p, pk, msg, sig)
+}
+
+/// Verifies a detached XMSS^MT signature (Unauthenticated).
+///
+/// `pk` is OID || root || SEED. Unknown OIDs and malformed encodings give
+/// [`PqcError::InvalidParameter`].
+pub fn verify_mt(pk: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    let p = Params::xmssmt(read_oid(pk)?).ok_or(PqcError::InvalidParameter)?;
+    verify_with(&p, pk, msg, sig)
+}
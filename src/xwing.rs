@@ -0,0 +1,195 @@
+// ------------------------------------------------------------------------
+// PQC-COMBO v0.2.0
+// ------------------------------------------------------------------------
+// Copyright © 2025 Aaron Schnacky. All rights reserved.
+// License: MIT (publicly auditable for FIPS/CMVP verification)
+// Contact: aaronschnacky@gmail.com
+// src/xwing.rs
+//! X-Wing hybrid KEM (draft-connolly-cfrg-xwing-kem): ML-KEM-768 combined
+//! with X25519 through a SHA3-256 combiner.
+//!
+//! The shared secret stays secure as long as either component does. X-Wing
+//! fixes ML-KEM-768 as its lattice component, so there is no ML-KEM-1024
+//! variant; the decapsulation key is the 32-byte seed and both component
+//! key pairs are re-expanded from it on every decapsulation.
+
+use crate::error::{PqcError, Result};
+use crate::ml_kem::{decapsulate_768, encapsulate_768, generate_key_pair_768, Kyber768Ciphertext};
+use crate::{
+    ML_KEM_768_CT_BYTES, ML_KEM_768_PK_BYTES, XWING_CT_BYTES, XWING_PK_BYTES, XWING_SK_BYTES,
+    XWING_SS_BYTES,
+};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Digest, Sha3_256, Shake256};
+use x25519_dalek::{x25519, X25519_BASEPOINT_BYTES};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Domain separator appended to the combiner input (`\.//^\`).
+const XWING_LABEL: &[u8; 6] = b"\\.//^\\";
+
+/// X-Wing shared secret type.
+pub type XWingSharedSecret = [u8; XWING_SS_BYTES];
+
+/// X-Wing private key (the 32-byte decapsulation seed).
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct XWingPrivateKey([u8; XWING_SK_BYTES]);
+
+/// X-Wing public key (ML-KEM-768 encapsulation key || X25519 public key).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XWingPublicKey([u8; XWING_PK_BYTES]);
+
+/// X-Wing ciphertext (ML-KEM-768 ciphertext || X25519 ephemeral key).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XWingCiphertext([u8; XWING_CT_BYTES]);
+
+/// X-Wing key pair.
+#[derive(Clone)]
+pub struct XWingKeypair {
+    /// Private key.
+    pub private_key: XWingPrivateKey,
+    /// Public key.
+    pub public_key: XWingPublicKey,
+}
+
+/// Implements the shared byte-encoding API for an X-Wing type.
+macro_rules! impl_encoding {
+    ($ty:ident, $bytes:ident, $what:literal) => {
+        impl $ty {
+            #[doc = concat!("Size of the encoded ", $what, " in bytes.")]
+            pub const SIZE: usize = $bytes;
+
+            #[doc = concat!("Wraps an encoded ", $what, ".")]
+            pub fn new(bytes: [u8; $bytes]) -> Self {
+                Self(bytes)
+            }
+
+            #[doc = concat!("Returns the encoded ", $what, ".")]
+            pub fn as_slice(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $ty {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $ty {
+            type Error = PqcError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                bytes
+                    .try_into()
+                    .map(Self)
+                    .map_err(|_| PqcError::InvalidParameter)
+            }
+        }
+    };
+}
+
+impl_encoding!(XWingPrivateKey, XWING_SK_BYTES, "private key");
+impl_encoding!(XWingPublicKey, XWING_PK_BYTES, "public key");
+impl_encoding!(XWingCiphertext, XWING_CT_BYTES, "ciphertext");
+
+/// Expanded component keys derived from an X-Wing seed.
+#[derive(Zeroize, ZeroizeOnDrop)]
+struct Expanded {
+    /// ML-KEM-768 key generation seed (d || z).
+    mlkem_seed: [u8; 64],
+    /// X25519 private key.
+    x25519_sk: [u8; 32],
+}
+
+/// Expands the seed into the component private keys: SHAKE256(sk, 96).
+fn expand(seed: &[u8; XWING_SK_BYTES]) -> Expanded {
+    let mut out = Expanded {
+        mlkem_seed: [0u8; 64],
+        x25519_sk: [0u8; 32],
+    };
+    let mut xof = Shake256::default();
+    xof.update(seed);
+    let mut reader = xof.finalize_xof();
+    reader.read(&mut out.mlkem_seed);
+    reader.read(&mut out.x25519_sk);
+    out
+}
+
+/// The X-Wing combiner: SHA3-256(ss_M || ss_X || ct_X || pk_X || label).
+fn combiner(ss_m: &[u8; 32], ss_x: &[u8; 32], ct_x: &[u8], pk_x: &[u8]) -> XWingSharedSecret {
+    let mut h = Sha3_256::new();
+    Digest::update(&mut h, ss_m);
+    Digest::update(&mut h, ss_x);
+    Digest::update(&mut h, ct_x);
+    Digest::update(&mut h, pk_x);
+    Digest::update(&mut h, XWING_LABEL);
+    h.finalize().into()
+}
+
+/// Generates an X-Wing key pair from a 32-byte seed (Unauthenticated).
+pub fn generate_key_pair(mut seed: [u8; XWING_SK_BYTES]) -> XWingKeypair {
+    let expanded = expand(&seed);
+    let kp_m = generate_key_pair_768(expanded.mlkem_seed);
+    let pk_x = x25519(expanded.x25519_sk, X25519_BASEPOINT_BYTES);
+
+    let mut pk = [0u8; XWING_PK_BYTES];
+    pk[..ML_KEM_768_PK_BYTES].copy_from_slice(kp_m.pk());
+    pk[ML_KEM_768_PK_BYTES..].copy_from_slice(&pk_x);
+    let kp = XWingKeypair {
+        private_key: XWingPrivateKey(seed),
+        public_key: XWingPublicKey(pk),
+    };
+    seed.zeroize();
+    kp
+}
+
+/// Encapsulates a shared secret to an X-Wing public key (Unauthenticated).
+///
+/// `eseed` is the ML-KEM-768 encapsulation randomness followed by the
+/// ephemeral X25519 private key.
+pub fn encapsulate(
+    pk: &XWingPublicKey,
+    mut eseed: [u8; 64],
+) -> (XWingCiphertext, XWingSharedSecret) {
+    let (pk_m, pk_x) = pk.0.split_at(ML_KEM_768_PK_BYTES);
+    let pk_x: [u8; 32] = pk_x.try_into().expect("X25519 key is 32 bytes");
+    let pk_m: [u8; ML_KEM_768_PK_BYTES] = pk_m.try_into().expect("ML-KEM-768 key size");
+
+    let mut m_randomness = [0u8; 32];
+    m_randomness.copy_from_slice(&eseed[..32]);
+    let mut ek_x = [0u8; 32];
+    ek_x.copy_from_slice(&eseed[32..]);
+    eseed.zeroize();
+
+    let (ct_m, mut ss_m) = encapsulate_768(&pk_m.into(), m_randomness);
+    let ct_x = x25519(ek_x, X25519_BASEPOINT_BYTES);
+    let mut ss_x = x25519(ek_x, pk_x);
+    ek_x.zeroize();
+
+    let ss = combiner(&ss_m, &ss_x, &ct_x, &pk_x);
+    ss_m.zeroize();
+    ss_x.zeroize();
+
+    let mut ct = [0u8; XWING_CT_BYTES];
+    ct[..ML_KEM_768_CT_BYTES].copy_from_slice(ct_m.as_slice());
+    ct[ML_KEM_768_CT_BYTES..].copy_from_slice(&ct_x);
+    (XWingCiphertext(ct), ss)
+}
+
+/// Decapsulates an X-Wing shared secret (Unauthenticated).
+pub fn decapsulate(sk: &XWingPrivateKey, ct: &XWingCiphertext) -> XWingSharedSecret {
+    let expanded = expand(&sk.0);
+    let kp_m = generate_key_pair_768(expanded.mlkem_seed);
+    let pk_x = x25519(expanded.x25519_sk, X25519_BASEPOINT_BYTES);
+
+    let (ct_m, ct_x) = ct.0.split_at(ML_KEM_768_CT_BYTES);
+    let ct_m: [u8; ML_KEM_768_CT_BYTES] = ct_m.try_into().expect("ML-KEM-768 ciphertext size");
+    let ct_x: [u8; 32] = ct_x.try_into().expect("X25519 share is 32 bytes");
+
+    let mut ss_m = decapsulate_768(kp_m.private_key(), &Kyber768Ciphertext::from(ct_m));
+    let mut ss_x = x25519(expanded.x25519_sk, ct_x);
+    let ss = combiner(&ss_m, &ss_x, &ct_x, &pk_x);
+    ss_m.zeroize();
+    ss_x.zeroize();
+    ss
+}
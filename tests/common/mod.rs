@@ -0,0 +1,39 @@
+// tests/common/mod.rs
+// Provisioned credentials shared by the integration tests
+
+#![allow(dead_code)]
+
+use pqc_nostd::auth::{self, Role, StoredCredentials};
+use pqc_nostd::{FipsModule, Result};
+use std::sync::Once;
+
+/// User password of the test credentials.
+pub const USER_PASSWORD: &[u8] = b"user password";
+/// Crypto Officer password of the test credentials.
+pub const CO_PASSWORD: &[u8] = b"officer password";
+
+/// The test credentials, as they would be read back from storage.
+pub fn credentials() -> StoredCredentials {
+    StoredCredentials::from_passwords(USER_PASSWORD, [0x11; 16], CO_PASSWORD, [0x22; 16]).unwrap()
+}
+
+/// A new module holding the test credentials.
+pub fn module() -> FipsModule {
+    FipsModule::with_credentials(&credentials())
+}
+
+/// Returns the test password of `role`.
+pub fn password(role: Role) -> &'static [u8] {
+    match role {
+        Role::User => USER_PASSWORD,
+        Role::CryptoOfficer => CO_PASSWORD,
+    }
+}
+
+/// Logs in to the global module as `role`, restoring the test credentials
+/// the first time.
+pub fn login(role: Role) -> Result<()> {
+    static PROVISION: Once = Once::new();
+    PROVISION.call_once(|| auth::load_credentials(&credentials()).unwrap());
+    auth::login(role, password(role))
+}
@@ -0,0 +1,232 @@
+// tests/composite.rs
+// Composite ML-DSA-65 + ECDSA-P256 / Ed25519 signatures
+
+#![cfg(all(feature = "composite", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::state::reset_fips_state;
+use pqc_nostd::{
+    dilithium_verify, mldsa65_ecdsa_p256_generate_key_pair, mldsa65_ecdsa_p256_sign,
+    mldsa65_ecdsa_p256_verify, mldsa65_ed25519_generate_key_pair, mldsa65_ed25519_sign,
+    mldsa65_ed25519_verify, run_post_or_panic, DilithiumSignature, DilithiumVerifyingKey,
+    MlDsa65EcdsaP256Signature, MlDsa65EcdsaP256SigningKey, MlDsa65Ed25519Signature,
+    MlDsa65Ed25519VerifyingKey, PqcError, FIPS_CONTEXT, MLDSA65_ED25519_SIG_BYTES,
+    ML_DSA_65_PK_BYTES, ML_DSA_65_SIG_BYTES,
+};
+
+// MLDSA65-Ed25519-SHA512 key pair from seed [0x41; 64] and its signature over
+// "composite test" with context "ctx" and randomness [0x42; 32]. Both
+// components verify with OpenSSL 3.5 against an independently built M'.
+const ED25519_VK_HEX: &str = "\
+    e13cb3a21650260347f87881298bdd58dd42a7650e8e33c037f1af005dd96529f6cd0aa0c70f1363c0f785358952e302\
+    58d80216f2fbf6ff8bee2f7a1cd438e803708092fe1cfa02e27d221b974a395458c820c75e6ee152a963c5def61a7871\
+    6dce204a5c39b5fbc5068a8e0c811c9251fb9ad782a03fdb06cdcbfb7b2249e384f804320bf97621367dd50a2a411ed3\
+    14990e87f7db6d6d0601425747c9551825f3c05692953d5bf3e1d1fc728ffdd5d2dbb255eb1e07cc83941f0b107ac68c\
+    e5105cd6a7d0fbcab61c4c8ad05c44171cb640168d01f5e788a17fd32bd7acb5b220ff3a0c2f413beabe4f7cb1f5629d\
+    b8df16b3cab4eddb176c929ae142a66b270931b26942a617d64ae466d6cb491f3eb4512849643eca24e9154f70722590\
+    640d3fb398bc5e9ded132a125eaf360cf25f5add6138460de1818ae179bcdde20beccbac84cefa73461c3e019f6be89f\
+    24532a992d1249493c3fca1104307d8a0fe92262cc82b8e9aec523212c16df979aa2343f6ab9fe3a9900f8e54127c8f3\
+    1ff623bcd3e47d7e99c6625379e3fc8d01714e9dd4e5cd500a1628cd228477e8d7d45fbc734f6d0db509f16f04c0e519\
+    39714833efb529644dde45852996cd4f2918af772b0dbf6b3296753da23c5ffdcc13115afc4511d045332ac9fe499563\
+    238d501672d15413091e7ac8aeca55af4c46c6706dd57f8d2633945cdda0e6ccf060ed15a196e4394ca31832e486c536\
+    f1bcbbdf84870e361299ad374919f9f7f80ef6317b47e9e0a5b0e81cfd7489f862e052a6297387e8d72388a13da199d8\
+    b7ecf241aa39a5f349a21cfd38a717294a73bc61e8cd043912720492c10e347a65cd6e7ac2834c5e62fd95007a7e7f74\
+    8cedf295b2f143d29b8aacf8cc5b09841390aaf2de0892f88a9b7e1dd1ea9f49e040846bbc1af234c57f44c25211c71e\
+    e09eb01320b90bbf60f92916c7b2e88291d8c2bd3bfb29073b8a2e31d202003df722e442973e4f44371bb93cd8874160\
+    6de48b214adcadf94576a05a20b7d71d48b52c73f67b6f8f80a81765f1befbb56432c86f0c815967421e22d051a63e28\
+    c7eb69cf1435e0ae57fde083d6a8a2e84cad537e3fb5b3de5435aa3139f56c018f210928bd97b4d998d3dbac6728648f\
+    94f053c9d13fa132e522b3b82914be05892ccce18e8d34a8f4e67f44fc75db8e8c6d83d62b1680978c6887d91c056223\
+    028eb3f8ed75d38892b214541932325861defd6ef2afd94fda41608bd380f95d165012f9f2617edc814cb6eadec7ef9c\
+    3bd4f708b25d91c6f03ab805a7cd88a49343cb79557468c251125607e999ce2b5e28e590aba7fb7e5bd5ca41083085e1\
+    aadaa054d17cfbbbf7aa4e8d1b699d46ef149cbdc3cfa929e25d86a9ab0a135f69f47f884c461a9db36ce09cfaff8bca\
+    f19b779613031b12644fec016b1a57fb065df16e21b0dd0afb458742c285840cf18fd84b41f75f33a766cf61b617936d\
+    23e97681d27d53face89409c456e83642d79017be12b2c555744cc3f2243139392b90896092a554fdf64bf953c5cc31e\
+    2f447bf35487a1f9921e129d6b34e12043b7b670afd66b4d51fd235a7d6f7c97272154975fc229390e984169e3684077\
+    e68f45dd1a0a247dee386561f6454ba253b924b8bf32ad4cdb04a2b7caa9ce52e61909f814ea24fd11e1068d0bfa815d\
+    7a17b78c064d995016ca723f16cdb5426b86b9a5e26bce94082fbf876780f357133a64be16050f00e379c8c9fa1ecaee\
+    3477a55596db59508e978592824af2ca3d385b1a2c23d81ab0c5e6aa7c14efbe02481e9d6b286f1e438637bc52098d35\
+    5b64a80ad59ee28de8a1cbfca623134fc686d5a91ad7250d0823479fc85b93c4d631794a8ca8e3fefc39db9ff5237c0d\
+    95fdeee0a16f3b38e40d152e1f9806a569cdb0ccd28458a9ef01240f2b3a298d9c4f94d34ab1eb5f4e0444172a0a6bac\
+    5912a2089f06f390c27a95d167b4fb935c12768eac423a8cb010cb1e2653fa58f46605d2b0720cbd216c14590c319e2f\
+    f508c6f1847d292dab28c7cb5c3300c4f204ba8efad5dd7ff2f0e022cf8e298febbe32c996c0fbf7a75bb327c9d30758\
+    c494865904e5ae30b26cd0bb0f1b084ae657b791fc122250d31a9600bad33c9206520bfc4686df01d4b64670e19ee878\
+    c47adf59f3cd22f1a31bd37dda82a221a55199bf41f54c6e7a0f5acf1c753e3325e4d8d053060d6bcdc18bf27bb15e42\
+    70e74d805630f6f814ea0c9082d657a459daca46ae7f4692bfab3cfa33ac5afe5f05c451f1eb837875baff8374be65a5\
+    72deee4abe1a212c3cb9bafd8e3f7f176e35e904876fa4b73ab99d4797ec6994cfd87166e8f4e1f8c717e782c6ad7f26\
+    ab89e5efc266e746761bab41f57c697379797e1d85765b64ed89479c21e5bdb9d0b92ef3c07631296bc76780c2b0b640\
+    06759449fac65783245852818c2c703f4e1385baceb495b81de4e32f5530b6a02078ffca66ad5fa739b23982c6b615ef\
+    5f450d4841787804c0f62e4b5afd53b6c696737b9b4365288a76d562fc7c95fb371791199c4a2115fa56f75ba0d3af42\
+    89f73a32dcc020c286b8eddf87bcb06fb4dd971bca1a544d32f0e9198397e2bf80ba131fd3f6f380b788572beffc132d\
+    b915b5a91de289935a2c43782079eb71284c4032a2922735a7b604ac670594cb36612bd2e4b88af401934813460f3ff2\
+    5c6d7e069ffe26745c531bd143b81b90855a20fc621c92c307a071aed41331e4db995fe25169d141cab9bbba92baa01f\
+    9f2e1ece7df4cb2ac05190f37fcc1f9d";
+const ED25519_SIG_HEX: &str = "\
+    a627d9499db4447901d6d641bbb5c70d58423c11f6778e07496c9e400372ab68df6a756c4692f3762342b2d6e0a23eda\
+    9a27ca7611051f6d31b463fe0498fd67dd80fa6408a7b6b8f070ce5b7685e5e21c77708d9e80bbc3d24d64330635255e\
+    91f283fa0365a100fcf62ed95504263f8a01e33cd86aa7eac29bcc5b5d4662b14d86a2917a6d6ccbbe64e6941776c059\
+    95e2182cd32d955c3459583de4e9165d5f3422126bb77bf66bf78760115f69924d67131ab7fc881ee2b515419f800305\
+    ac48f49f62ade56265f2a371b599575b07afc9f4cfaa0cf5c8f4b5e454a0c681faeffa4345642a8ee474a868329e1533\
+    580f98229a0021d46fbd7a5c93343239ba331992ea567b51d3c3f899d91be30c3f656d4ccad84c4f2c0ae1222a65e81a\
+    95c04d2c3e315528c150169efbeb3ae08f5bbeb2583b929385b708c614c5290d843c80bbff1d854372d9e0d82066187f\
+    4fb7962e9b469611131ffe8205d11cf4117366796380d27f92d86ac5406bd7a59ffa7bb6a62143038a356d4eb339e1ad\
+    b6f3aea9fe1742ee1f9ae8ffa9d3ed22b21173d909d2ecec572e3255c737c4421b10c02069a6ba689cacc2a201762f72\
+    46f7077d2bcbf952a3f9a67b45604b0d388711b05ed35bd8c49c715af65d4c41ab7258c8ca5ca026e60ea51cf69b6a8b\
+    db96e4f2fc5606e6b66978574593d4da426909a1e1fac0ecbde8ca5ef4a22e7c917349839ce66fcd1268811ae335438b\
+    91e39350cbd762cb42ec8b120c290c37b008ec6a1d2d9dae023acb895b2fee0b88eec7bb04e787d95ccf6d834decaf64\
+    8f269799051ab7ef6bf1a29a8ed4dd929793bf2ea93909e6ec147a150f5be17b731e1a2fa26770b3d492cd0c60671f9d\
+    9859f8084a8f38a3de4482ed72ac4fc6df190151c8894d228b1b9f8ff32bfacadd9044097a4c45ed99239eff48b674ef\
+    5ccfdb8f6b1f4de70a14bc33a797f2f26a8b73405cccb1d22dd2f769d05a0f41b19986f8c637a77fa62059fb8958f8db\
+    bbe5def490bffee6e33068ae639cec77eba1953bb3277c18d7ff5b3e9eab5ab550727ea75d615ecee8cea271c562904a\
+    e50daf9d5900bea39bc0629b3a6670b4c96d126071d4c3e8783a42fedd7e800760fa4bfe66c00a3fd52ec48faa775895\
+    edde0df1d7385703dd8dc139801463119875e2af6948b40cf2d78d77076f45f5cb7e7978df64d7f7a856cb45efc157a1\
+    e303ac93c0580a838154eb1c0b194039d85eb30d53a9fd36dd24042f9b90233a05488bed14270c6fd7f30e61ae2aae38\
+    8f7c68bdf69dbd5ea4f380967985754b35c712abfe0fa81c956cfa94a5d22305357bc725aa3a55460098d166c0143362\
+    6bd980cb77c8c017f730ee76325b0def3384a5465a55ef65f9a5008ef4a04071d358cd39e673fcebeddda5e6a826d64e\
+    5c88ba15c9b7ad4c6633308939b0be78c40b36958196d01206d9938cde920dfbefb261bbec0c037a202f9e54b591c29b\
+    a293a87fd6bb615ad4e61c5f66547aa1acf25b25ab42de0351739629c6959522f9973f3d035d6eb39665add944341d92\
+    f4170e3f8b911511701e9dda05f726b3f930f2eafec1312d9b3cc92e90817504115af2968fb43bb992affaf87685e75a\
+    70ff482b266b59747345d4e39ee8033d6f15cd9487327957f427128ee0968c776a14c97a6c2e4efbed7b0079b70f74d9\
+    f5d878d44c217c3487e88e9a73db98dcaef95e379c7e3528609ff3d55d177abd5b6a694d5c0c65ed367a5357086720a8\
+    925db8146a54305d4ef8067c6bd4eb0fd482b1f5579173bd75559adf2c070f0b643d242f83fe9aa5466a2a4bc5a6aa11\
+    0ef2353e66e0d340f150240eb05463e237c93b5811499cda863677926a2fe7b59beffc1c1be6cd926df6433554f548c6\
+    4693c36ea5377836c327e67ddee10c117f5213bfd620a7aed2bdfc91952966a6db6933df954ab7523b2435fb07d6f52e\
+    1b14e8a59c17268c2f9de80f4e1b8da8a8c0d577743b0e3bea22b352d54994409290978d0a8053e742db8cfe4bbfdd35\
+    1ab6e5f3297dcea6aadd4d237308abed7d29cbe63268f803ee82bcbe94addda2f60c78dd08ed14540cb5aa97638418f0\
+    a0ee341a44eb5bfc40bb83c6d4593d30d0f7a9fb3e74c73b3b2f1cb9d6aaf61dc3d7b4be970ffdfef6acb4865a37d7ef\
+    68bbfa22677b6af7f19141e72a274d485faeb3c68074ce5b2b2b8897ce18229fb78b5971409f42416eaf009d7adb47e7\
+    06ba3c66133a3b097d2aa09edd55078fed547388d49b452e4df2a0b61419b6ef410d6bb08c21c78741403b8972c02e4d\
+    1a7bae0af2a5ec63ad4ade96efe4984966dec236fb429fdc1bec37d1080d3d0b063f95576a2437ea8c8bb0fdcd0c721f\
+    830ad341c92af126168ec919068d99ab3c3fb7a0e3cb3e3499a049667ba46c480ba46a9288931f22d42080bd265ec4d8\
+    4223390a13bb22aaf2f725e36cce79f1dac954ba3be10df2a8dd0aa7d07aa09cecb22a09209a6541ec832db8c9ee50f7\
+    d069e0d06c2d7c39ae0d6d4d1e58b0c64a1b5d6c294536268ad3488e5ac6ace9dad5d596f114a380c201a328a424fb39\
+    430f971d5571dac350664738bf84059f79ea9c48143e4c9441328725c2fca2256d7649532ca277a0a134522a0dbfb5ea\
+    6dc2f37b043a5af81d4c50cdeb26857943702714fafab3c03a877ddf31beeba5d808578d76e7b0e455cf675e10cf94f0\
+    d4b80fd5d22e5a36a7f75bd7538d860a743ce54325a06dc377bce8038c2c63174d5cdcd07feb357d38bf8d6b39b54c62\
+    c5aed2e790ed881bb8f274890cbda240af8068f7852a33bd37338df1f439bb4d120d65942842df488b2534187a8ff8d8\
+    951de20f611c5d4579d8d93dcedb331f542c48f6a2b35b3d6efdec9dd548199d366ba07729dd8d81c585c5d251a23c1d\
+    34dc2f437e7eb1259a0c6340298b2b449d138fb44b192c4032b57b4751ef42a5093aa1961bd8f6e6ebdead18fa9342ef\
+    6220c3524361447e4981e83f8ca29fc85742af1621288d8280c695430a801dd0215d10e6ae38685743cb8b1e8103bada\
+    d83493947a1014bc8ee2d9630fa6617008cf8e60b67a5167ce604138ad02991a30176fa153383d11d3c74bdd3d727c2f\
+    d6ca99b3e9e468f1fac30b4bdc829462cb73476b40255f4aa4224661495041c847bd438636ad45a76ca5ab8840e83812\
+    cb9f77d6afeba0172544d721d68f9b65452b1393624b5310d74d6f109341f2f0d46b207e38b8de75cc348bddf6f0d959\
+    d61f193928c9ec877dbc23cd5ad00a3460ca6e7410a2eb30d91723621e2feb1758ecce6b100cdbdcf0e8919de4e1aad0\
+    53db09d995b380c18310801fcb7015e5fc56e84292372c4f3ca7fbe96a11d8fcaaab00319d191b24c6a43db08d96e742\
+    4433d6375287c759569afba4e7b5910228ded3a81491617ec6206e95473a2d1d506e63952cbb7fba76bd7f63e80caabf\
+    ba9a6ab89674a406cd6941b3887bb810e4d4d70d25846951b226a18998623bd5940e1d405c641e7ca2ac550f8d256854\
+    cfb334eca6e89283e6dd2083191d4bd491c79b8df717d6f0a3018f94532efbee4905a9c6c9baef4c1f1d5c060874b995\
+    7b55be0d51bf1fc434ad79b871ed83d7b32a95b5ad4e032dafe320c553b6a314d44541ef5d138d9aed8a9225c3ede218\
+    2c7b3c74f2435c27c9e82f1890c89628d250bb26e7b80dfa9911900cfa7f1e471b7cd0a246193ee8c590d5e8ae3b6973\
+    0d9049551fa5d7af8c90ae1757a4bbb31e10d5208d05c450c1f7d6fc8a8033e5b2574700d540e8c4a4ec0408a58a197d\
+    48fb4b0132551169200aa65b25ef1c3f29d48c0a07728c11beedfc68e96031129555791840ee22b69972dd36e132b9b4\
+    17487c451019ad48bb450f22519fedfe02def72b3067391631463d827885d4b73c6d6ec881b832e36440894db2ea5768\
+    d671e34c1215fcf0dc3e0184acdce38642cddba3ae8c26dd8f670d21d2426dd0c33df552079441dfc441c11753895305\
+    6a84ec3594e2090a07fe785bfb7c1eeba643969e75ce6a76170cd094a4fa054aa1684ed8675b0cbf4574b5b978ec43eb\
+    59c330899f0a8790675d2820e23b8d66c96891d534f950b3c7ec108db89e651dc4bf235bc98e19fae0d6e2f5a7494694\
+    6fb1ff24254f76c3f2a75062cf3322bf9fc34e375549b61aad1f6fc85e739aea98803a2511629e6906bc9ddedd66bb31\
+    27b859d154df1705e61cf9822926095c7751b132137d05bdbdff74383e1fd1f0d54667b0e74944af24b82b0355fc2e47\
+    053b619eedf736dda5478e408df944c7932a76b1582aa1b9be405652e028c931eff411eac3a1cbbb8c08a5cd3313e6eb\
+    f966af501516233c6e61c275976dde265af99368b4ca6126a570c9b355c71964260159d8df05b767707c4b535e1f9af9\
+    ecfa5493164a5ea3ba80174442acf10311a3833111887d97e791d125f3ae5a4b4cca3ff19eb022547ba1a65dbce329b1\
+    0c1a58d7e7ade2b27b0bd591032853880ea2872da9003745613effd29b09354b943a94089a646d3f81653c49dc20f3f9\
+    7a9353ff889cb20d4048a81b0599e98b6c29d77a5c38f5e78f0096206de704e710213c45506598b7cfeaef1120485570\
+    95b37e909ba8b5f4f8061851576686879bd2f12e3b50c7c2c400000000000000000000000000000b1219232729e047bc\
+    f43150d37a29a522f14e557fd362517938fb553e0dda5a9916c32b415cfa456fdca548cf086094395ac8142ec8cee1a1\
+    5e4a11a11fcb8140c8ce116106";
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn composite_signatures() {
+    run_post_or_panic(); // Includes the composite KATs
+    common::login(Role::User).expect("Login failed");
+    let msg = b"composite test";
+
+    // Fixed MLDSA65-Ed25519-SHA512 vector
+    let kp = mldsa65_ed25519_generate_key_pair([0x41; 64]).unwrap();
+    assert_eq!(
+        kp.verification_key.as_slice(),
+        &from_hex(ED25519_VK_HEX)[..]
+    );
+    let sig = mldsa65_ed25519_sign(&kp.signing_key, msg, b"ctx", [0x42; 32]).unwrap();
+    assert_eq!(sig.as_slice(), &from_hex(ED25519_SIG_HEX)[..]);
+    assert_eq!(sig.as_slice().len(), MLDSA65_ED25519_SIG_BYTES);
+    let vk = MlDsa65Ed25519VerifyingKey::try_from(&from_hex(ED25519_VK_HEX)[..]).unwrap();
+    let ed_sig = MlDsa65Ed25519Signature::try_from(&from_hex(ED25519_SIG_HEX)[..]).unwrap();
+    assert!(mldsa65_ed25519_verify(&vk, msg, b"ctx", &ed_sig).is_ok());
+    assert_eq!(
+        mldsa65_ed25519_verify(&vk, msg, FIPS_CONTEXT, &ed_sig).err(),
+        Some(PqcError::FipsErrorState)
+    );
+
+    // Both components must verify
+    let mut bytes = sig.as_slice().to_vec();
+    bytes[10] ^= 0x01; // ML-DSA-65 component
+    let bad = MlDsa65Ed25519Signature::try_from(&bytes[..]).unwrap();
+    assert!(mldsa65_ed25519_verify(&vk, msg, b"ctx", &bad).is_err());
+    let mut bytes = sig.as_slice().to_vec();
+    bytes[ML_DSA_65_SIG_BYTES + 10] ^= 0x01; // Ed25519 component
+    let bad = MlDsa65Ed25519Signature::try_from(&bytes[..]).unwrap();
+    assert!(mldsa65_ed25519_verify(&vk, msg, b"ctx", &bad).is_err());
+
+    // The ML-DSA-65 component is bound to the composite label and M', so it
+    // cannot be stripped out and verified as a plain ML-DSA-65 signature
+    let mldsa_vk: [u8; ML_DSA_65_PK_BYTES] =
+        vk.as_slice()[..ML_DSA_65_PK_BYTES].try_into().unwrap();
+    let mldsa_sig: [u8; ML_DSA_65_SIG_BYTES] =
+        sig.as_slice()[..ML_DSA_65_SIG_BYTES].try_into().unwrap();
+    assert!(dilithium_verify(
+        &DilithiumVerifyingKey::new(mldsa_vk),
+        msg,
+        FIPS_CONTEXT,
+        &DilithiumSignature::new(mldsa_sig)
+    )
+    .is_err());
+
+    // MLDSA65-ECDSA-P256-SHA512 with its variable-length DER component
+    let kp = mldsa65_ecdsa_p256_generate_key_pair([0x31; 64]).unwrap();
+    let sig = mldsa65_ecdsa_p256_sign(&kp.signing_key, msg, b"ctx", [0x32; 32]).unwrap();
+    assert!(mldsa65_ecdsa_p256_verify(&kp.verification_key, msg, b"ctx", &sig).is_ok());
+    assert!(mldsa65_ecdsa_p256_verify(&kp.verification_key, b"other", b"ctx", &sig).is_err());
+    let parsed = MlDsa65EcdsaP256Signature::try_from(sig.as_slice()).unwrap();
+    assert!(mldsa65_ecdsa_p256_verify(&kp.verification_key, msg, b"ctx", &parsed).is_ok());
+    let mut bytes = sig.as_slice().to_vec();
+    bytes[ML_DSA_65_SIG_BYTES] = 0x31; // Not a DER SEQUENCE
+    let bad = MlDsa65EcdsaP256Signature::try_from(&bytes[..]).unwrap();
+    assert_eq!(
+        mldsa65_ecdsa_p256_verify(&kp.verification_key, msg, b"ctx", &bad).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    assert!(MlDsa65EcdsaP256Signature::try_from(&sig.as_slice()[..ML_DSA_65_SIG_BYTES]).is_err());
+
+    // Signing keys round trip through their encoding
+    let sk = MlDsa65EcdsaP256SigningKey::try_from(kp.signing_key.as_slice()).unwrap();
+    let again = mldsa65_ecdsa_p256_sign(&sk, msg, b"ctx", [0x32; 32]).unwrap();
+    assert_eq!(again, sig);
+
+    // Invalid inputs
+    assert_eq!(
+        mldsa65_ecdsa_p256_sign(&kp.signing_key, msg, &[0u8; 256], [0x32; 32]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut seed = [0x31; 64];
+    seed[32..].fill(0xff); // ECDSA scalar above the group order
+    assert_eq!(
+        mldsa65_ecdsa_p256_generate_key_pair(seed).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    // Approved-mode gating applies to the composite services
+    reset_fips_state();
+    assert_eq!(
+        mldsa65_ed25519_verify(&vk, msg, b"ctx", &ed_sig).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+    run_post_or_panic();
+}
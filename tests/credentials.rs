@@ -0,0 +1,242 @@
+// tests/credentials.rs
+// PBKDF2 password verifiers and Crypto Officer password provisioning
+
+#![cfg(feature = "fips_140_3")]
+
+mod common;
+
+use pqc_nostd::auth::{Role, StoredCredentials, MIN_PASSWORD_BYTES};
+use pqc_nostd::{run_post_or_panic, FipsModule, PqcError};
+
+#[test]
+fn crypto_officer_rotates_passwords() {
+    let module = FipsModule::new();
+    assert!(module.has_default_credentials());
+
+    // Default credentials, checked before POST
+    module.login(Role::User, b"user123").expect("Login failed");
+    module
+        .login(Role::CryptoOfficer, b"admin456")
+        .expect("Login failed");
+    for (time, (role, password)) in [
+        (Role::User, &b"admin456"[..]),
+        (Role::User, b"user12"),
+        (Role::User, b"user1234"),
+        (Role::User, b""),
+        (Role::CryptoOfficer, b"user123"),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        // Far enough apart to clear the login back-off
+        module.update_clock(time as u64 * 100_000);
+        assert_eq!(
+            module.login(role, password),
+            Err(PqcError::AuthenticationFailure)
+        );
+    }
+    // A failed login keeps the Crypto Officer logged in
+    module.check_authority(Role::CryptoOfficer).unwrap();
+
+    // Provisioning is a Crypto Officer service on an Operational module
+    assert_eq!(
+        module.set_password(Role::User, b"new user password", [0x01; 16]),
+        Err(PqcError::FipsNotInitialized)
+    );
+    module.run_post().expect("POST failed");
+    module.login(Role::User, b"user123").expect("Login failed");
+    assert_eq!(
+        module.set_password(Role::User, b"new user password", [0x01; 16]),
+        Err(PqcError::AuthenticationFailure)
+    );
+    // Nothing else runs while a default password is in place
+    assert_eq!(
+        module.kyber_generate_key_pair([0x01; 64]).err(),
+        Some(PqcError::DefaultCredentials)
+    );
+    module
+        .login(Role::CryptoOfficer, b"admin456")
+        .expect("Login failed");
+    assert_eq!(
+        module.set_password(Role::User, &[0x61; MIN_PASSWORD_BYTES - 1], [0x01; 16]),
+        Err(PqcError::InvalidParameter)
+    );
+
+    // Rotating the User password
+    module
+        .set_password(Role::User, b"new user password", [0x01; 16])
+        .unwrap();
+    assert_eq!(
+        module.login(Role::User, b"user123"),
+        Err(PqcError::AuthenticationFailure)
+    );
+    module
+        .login(Role::User, b"new user password")
+        .expect("Login failed");
+    assert!(module.has_default_credentials());
+    assert_eq!(
+        module.kyber_generate_key_pair([0x01; 64]).err(),
+        Some(PqcError::DefaultCredentials)
+    );
+
+    // Rotating the Crypto Officer password, twice, with fresh salts
+    module
+        .login(Role::CryptoOfficer, b"admin456")
+        .expect("Login failed");
+    assert_eq!(module.unlock(Role::User), Err(PqcError::DefaultCredentials));
+    module
+        .set_password(Role::CryptoOfficer, b"officer password 1", [0x02; 16])
+        .unwrap();
+    assert!(!module.has_default_credentials());
+    module
+        .set_password(Role::CryptoOfficer, b"officer password 2", [0x03; 16])
+        .unwrap();
+    for password in [&b"admin456"[..], b"officer password 1"] {
+        assert_eq!(
+            module.login(Role::CryptoOfficer, password),
+            Err(PqcError::AuthenticationFailure)
+        );
+    }
+    module
+        .login(Role::CryptoOfficer, b"officer password 2")
+        .expect("Login failed");
+
+    // The same password under the same salt gives the same verifier
+    module
+        .set_password(Role::User, b"officer password 2", [0x03; 16])
+        .unwrap();
+    module
+        .login(Role::User, b"officer password 2")
+        .expect("Login failed");
+    module.kyber_generate_key_pair([0x01; 64]).unwrap();
+
+    // Each module has its own credentials
+    run_post_or_panic();
+    common::login(Role::User).expect("Login failed");
+    assert_eq!(
+        FipsModule::new().login(Role::User, b"new user password"),
+        Err(PqcError::AuthenticationFailure)
+    );
+}
+
+#[test]
+fn stored_credentials_survive_a_power_cycle() {
+    // Provision a module and export its credentials
+    let module = FipsModule::new();
+    module.run_post().expect("POST failed");
+    module
+        .login(Role::CryptoOfficer, b"admin456")
+        .expect("Login failed");
+    assert_eq!(
+        module.export_credentials().err(),
+        Some(PqcError::DefaultCredentials)
+    );
+    module
+        .set_password(Role::User, b"new user password", [0x01; 16])
+        .unwrap();
+    module
+        .set_password(Role::CryptoOfficer, b"new officer password", [0x02; 16])
+        .unwrap();
+    module
+        .login(Role::User, b"new user password")
+        .expect("Login failed");
+    assert_eq!(
+        module.export_credentials().err(),
+        Some(PqcError::AuthenticationFailure)
+    );
+    module
+        .login(Role::CryptoOfficer, b"new officer password")
+        .expect("Login failed");
+    let bytes = module.export_credentials().unwrap().to_bytes();
+
+    // The export holds salts and verifiers, never the passwords
+    let stored = StoredCredentials::from_bytes(&bytes);
+    assert_eq!(stored.to_bytes(), bytes);
+    assert_eq!(&bytes[..16], &[0x01; 16]);
+    assert_eq!(&bytes[48..64], &[0x02; 16]);
+    assert!(!bytes.windows(17).any(|w| w == b"new user password"));
+    let derived = StoredCredentials::from_passwords(
+        b"new user password",
+        [0x01; 16],
+        b"new officer password",
+        [0x02; 16],
+    )
+    .unwrap();
+    assert!(stored == derived);
+
+    // A new module built from the stored credentials is ready to use
+    let restored = FipsModule::with_credentials(&stored);
+    assert!(!restored.has_default_credentials());
+    restored.run_post().expect("POST failed");
+    assert_eq!(
+        restored.login(Role::User, b"user123"),
+        Err(PqcError::AuthenticationFailure)
+    );
+    restored
+        .login(Role::User, b"new user password")
+        .expect("Login failed");
+    restored.kyber_generate_key_pair([0x01; 64]).unwrap();
+
+    // Anyone can restore credentials over the defaults, in any state
+    let module = FipsModule::new();
+    module.load_credentials(&stored).unwrap();
+    assert!(!module.has_default_credentials());
+    module.run_post().expect("POST failed");
+    module
+        .login(Role::User, b"new user password")
+        .expect("Login failed");
+    module.kyber_generate_key_pair([0x01; 64]).unwrap();
+
+    // Replacing provisioned credentials takes the Crypto Officer
+    let other = common::credentials();
+    assert_eq!(
+        module.load_credentials(&other),
+        Err(PqcError::AuthenticationFailure)
+    );
+    module
+        .login(Role::CryptoOfficer, b"new officer password")
+        .expect("Login failed");
+    module.load_credentials(&other).unwrap();
+    module
+        .login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+
+    // Once the CO password is set, the remaining default User password does
+    // not reopen unauthenticated loading
+    let module = FipsModule::new();
+    module.run_post().expect("POST failed");
+    module
+        .login(Role::CryptoOfficer, b"admin456")
+        .expect("Login failed");
+    module
+        .set_password(Role::CryptoOfficer, b"new officer password", [0x02; 16])
+        .unwrap();
+    module.logout();
+    assert!(module.has_default_credentials());
+    assert_eq!(
+        module.load_credentials(&other),
+        Err(PqcError::AuthenticationFailure)
+    );
+    assert_eq!(
+        module.login(Role::CryptoOfficer, common::CO_PASSWORD),
+        Err(PqcError::AuthenticationFailure)
+    );
+    module
+        .login(Role::CryptoOfficer, b"new officer password")
+        .expect("Login failed");
+    module.load_credentials(&other).unwrap();
+    module
+        .login(Role::CryptoOfficer, common::CO_PASSWORD)
+        .expect("Login failed");
+
+    // Factory provisioning enforces the minimum password length
+    assert!(matches!(
+        StoredCredentials::from_passwords(
+            &[0x61; MIN_PASSWORD_BYTES - 1],
+            [0x01; 16],
+            b"new officer password",
+            [0x02; 16]
+        ),
+        Err(PqcError::InvalidParameter)
+    ));
+}
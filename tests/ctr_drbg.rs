@@ -0,0 +1,90 @@
+// tests/ctr_drbg.rs
+// SP 800-90A CTR_DRBG (AES-256, with derivation function)
+
+#![cfg(all(feature = "ctr-drbg", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::drbg::RESEED_INTERVAL;
+use pqc_nostd::{
+    decapsulate, encapsulate_with_drbg, kyber_generate_key_pair_with_drbg, run_post_or_panic,
+    CtrDrbg, Drbg, PqcError,
+};
+
+// Outputs of the second generate call, computed with an independent
+// CTR_DRBG implementation (AES-256-CTR, use df, no prediction resistance).
+const ENTROPY_HEX: &str = "00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff";
+const NONCE_HEX: &str = "0f0e0d0c0b0a09080706050403020100";
+const RESEED_ENTROPY_HEX: &str = "ffeeddccbbaa99887766554433221100ffeeddccbbaa99887766554433221100";
+const EXPECTED_HEX: &str = "\
+    bb896306b8f8a87f2a0c581487bc76211e21afd023cc9d35c143c9a5527a6d930cfb57f7270b79928faf1e256375f880\
+    b72f15affa2f365d183d0d10e618b1e4fbb88a9bdac41627a0a8ae25871db07c5829b91c74c1065ad70438db15db91b4\
+    2d6b9610";
+const EXPECTED_RESEEDED_HEX: &str = "\
+    d49143f20eb516363598ac026f4bdef796d14fbe7fd2fab6ec0282de11c9f5348234cf5662044ae1bc0a75f5e8288cc5\
+    ec021dcb1f9c8a1548c434787be37b1d88ec524e51764e912c61526e1063656ec703305f92cae3c723eb36f7179dfbd2\
+    1502b2e1";
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn ctr_drbg_vectors_and_limits() {
+    run_post_or_panic(); // Includes the CTR_DRBG CAST
+    common::login(Role::User).expect("Login failed");
+    let entropy = from_hex(ENTROPY_HEX);
+    let nonce = from_hex(NONCE_HEX);
+
+    // Personalization string and additional input on both generate calls
+    let mut out = [0u8; 100];
+    let mut drbg = CtrDrbg::instantiate(&entropy, &nonce, b"device-42").unwrap();
+    drbg.generate(&mut out, b"first request").unwrap();
+    drbg.generate(&mut out, b"second request").unwrap();
+    assert_eq!(out.to_vec(), from_hex(EXPECTED_HEX));
+
+    // Same, with a reseed (with additional input) before generating
+    let mut drbg = CtrDrbg::instantiate(&entropy, &nonce, b"device-42").unwrap();
+    drbg.reseed(&from_hex(RESEED_ENTROPY_HEX), b"reseed")
+        .unwrap();
+    drbg.generate(&mut out, b"first request").unwrap();
+    drbg.generate(&mut out, b"second request").unwrap();
+    assert_eq!(out.to_vec(), from_hex(EXPECTED_RESEEDED_HEX));
+
+    // Short entropy input or nonce, and oversized requests, are rejected
+    assert_eq!(
+        CtrDrbg::instantiate(&entropy[..31], &nonce, &[]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    assert_eq!(
+        CtrDrbg::instantiate(&entropy, &nonce[..15], &[]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut big = vec![0u8; (1 << 16) + 1];
+    assert_eq!(
+        drbg.generate(&mut big, &[]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    // The reseed interval is enforced and a reseed clears it (two of the
+    // requests were served above)
+    for _ in 2..RESEED_INTERVAL {
+        drbg.generate(&mut [], &[]).unwrap();
+    }
+    assert_eq!(
+        drbg.generate(&mut out, &[]).err(),
+        Some(PqcError::DrbgReseedRequired)
+    );
+    drbg.reseed(&entropy, &[]).unwrap();
+    assert!(drbg.generate(&mut out, &[]).is_ok());
+
+    // CTR_DRBG drives the same DRBG-backed services as HMAC_DRBG
+    let mut drbg = CtrDrbg::instantiate(&entropy, &nonce, b"ml-kem").unwrap();
+    let kp = kyber_generate_key_pair_with_drbg(&mut drbg).unwrap();
+    let (ct, ss_alice) = encapsulate_with_drbg(kp.public_key(), &mut drbg).unwrap();
+    assert_eq!(ss_alice, decapsulate(kp.private_key(), &ct).unwrap());
+}
@@ -0,0 +1,52 @@
+// tests/deterministic_ml_dsa.rs
+// Deterministic ML-DSA-65 signing and its Crypto Officer policy switch
+
+#![cfg(all(feature = "ml-dsa", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::policy::{deterministic_signing_allowed, set_deterministic_signing};
+use pqc_nostd::{
+    dilithium_generate_key_pair, dilithium_sign, dilithium_sign_deterministic, dilithium_verify,
+    run_post_or_panic, PqcError, FIPS_CONTEXT,
+};
+
+#[test]
+fn deterministic_signing_policy() {
+    run_post_or_panic(); // Includes the deterministic signing KAT
+    common::login(Role::User).expect("Login failed");
+    let kp = dilithium_generate_key_pair([0x71u8; 32]).unwrap();
+    let msg = b"release-artifact.tar";
+
+    // Disabled by default, and only the CO may enable it
+    assert!(!deterministic_signing_allowed());
+    assert_eq!(
+        dilithium_sign_deterministic(&kp.signing_key, msg, FIPS_CONTEXT).err(),
+        Some(PqcError::ServiceDisabled)
+    );
+    assert_eq!(
+        set_deterministic_signing(true),
+        Err(PqcError::AuthenticationFailure)
+    );
+
+    common::login(Role::CryptoOfficer).expect("CO login failed");
+    set_deterministic_signing(true).unwrap();
+    common::login(Role::User).expect("Login failed");
+
+    // Stable output, identical to hedged signing with rnd = 0^32
+    let a = dilithium_sign_deterministic(&kp.signing_key, msg, FIPS_CONTEXT).unwrap();
+    let b = dilithium_sign_deterministic(&kp.signing_key, msg, FIPS_CONTEXT).unwrap();
+    assert_eq!(a.as_ref(), b.as_ref());
+    let hedged_zero = dilithium_sign(&kp.signing_key, msg, FIPS_CONTEXT, [0u8; 32]).unwrap();
+    assert_eq!(a.as_ref(), hedged_zero.as_ref());
+    assert!(dilithium_verify(&kp.verification_key, msg, FIPS_CONTEXT, &a).is_ok());
+
+    common::login(Role::CryptoOfficer).expect("CO login failed");
+    set_deterministic_signing(false).unwrap();
+    common::login(Role::User).expect("Login failed");
+    assert_eq!(
+        dilithium_sign_deterministic(&kp.signing_key, msg, FIPS_CONTEXT).err(),
+        Some(PqcError::ServiceDisabled)
+    );
+}
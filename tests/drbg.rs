@@ -0,0 +1,93 @@
+// tests/drbg.rs
+// SP 800-90A HMAC_DRBG and the DRBG-backed key generation and signing services
+
+#![cfg(all(feature = "ml-kem", feature = "ml-dsa", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::drbg::RESEED_INTERVAL;
+use pqc_nostd::{
+    decapsulate, dilithium_generate_key_pair, dilithium_sign_with_drbg, dilithium_verify,
+    encapsulate_with_drbg, kyber_generate_key_pair_with_drbg, run_post_or_panic, Drbg, HmacDrbg,
+    PqcError, FIPS_CONTEXT,
+};
+
+// CAVP HMAC_DRBG SHA-256 (no prediction resistance, no reseed), with
+// additional input and no personalization string.
+const ENTROPY_HEX: &str = "13c752b9e745ce77bbc7c0dbda982313d3fe66f903e83ebd8dbe4ff0c11380e9";
+const NONCE_HEX: &str = "f1a533095d6174164bd7c82532464ae7";
+const ADD1_HEX: &str = "4f53db89b9ba7fc00767bc751fb8f3c103fe0f76acd6d5c7891ab15b2b7cf67c";
+const ADD2_HEX: &str = "582c2a7d34679088cca6bd28723c99aac07db46c332dc0153d1673256903b446";
+const EXPECTED_HEX: &str = "\
+    6311f4c0c4cd1f86bd48349abb9eb930d4f63df5e5f7217d1d1b91a71d8a6938b0ad2b3e897bd7e3d8703db125fab30e\
+    03464fad41e5ddf5bf9aeeb5161b244468cfb26a9d956931a5412c97d64188b0da1bd907819c686f39af82e91cfeef0c\
+    bffb5d1e229e383bed26d06412988640706815a6e820796876f416653e464961";
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn hmac_drbg_and_drbg_backed_services() {
+    run_post_or_panic(); // Includes the HMAC_DRBG CAST
+    common::login(Role::User).expect("Login failed");
+
+    // CAVP vector with additional input on both generate calls
+    let mut drbg = HmacDrbg::instantiate(&from_hex(ENTROPY_HEX), &from_hex(NONCE_HEX), &[])
+        .expect("instantiate");
+    let mut out = [0u8; 128];
+    drbg.generate(&mut out, &from_hex(ADD1_HEX)).unwrap();
+    drbg.generate(&mut out, &from_hex(ADD2_HEX)).unwrap();
+    assert_eq!(out.to_vec(), from_hex(EXPECTED_HEX));
+
+    // Short entropy input or nonce, and oversized requests, are rejected
+    assert_eq!(
+        HmacDrbg::instantiate(&[0u8; 31], &[0u8; 16], &[]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    assert_eq!(
+        HmacDrbg::instantiate(&[0u8; 32], &[0u8; 15], &[]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    assert_eq!(
+        drbg.reseed(&[0u8; 16], &[]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut big = vec![0u8; (1 << 16) + 1];
+    assert_eq!(
+        drbg.generate(&mut big, &[]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    // The reseed interval is enforced and a reseed clears it
+    let mut drbg = HmacDrbg::instantiate(&[0x11; 32], &[0x22; 16], b"reseed test").unwrap();
+    for _ in 0..RESEED_INTERVAL {
+        drbg.generate(&mut [], &[]).unwrap();
+    }
+    assert_eq!(
+        drbg.generate(&mut out, &[]).err(),
+        Some(PqcError::DrbgReseedRequired)
+    );
+    drbg.reseed(&[0x33; 32], &[]).unwrap();
+    assert!(drbg.generate(&mut out, &[]).is_ok());
+
+    // DRBG-backed ML-KEM-1024: equal DRBG states give the same key pair
+    let mut a = HmacDrbg::instantiate(&[0x44; 32], &[0x55; 16], b"ml-kem").unwrap();
+    let mut b = HmacDrbg::instantiate(&[0x44; 32], &[0x55; 16], b"ml-kem").unwrap();
+    let kp = kyber_generate_key_pair_with_drbg(&mut a).unwrap();
+    let kp_again = kyber_generate_key_pair_with_drbg(&mut b).unwrap();
+    assert_eq!(kp.public_key().as_ref(), kp_again.public_key().as_ref());
+    let (ct, ss_alice) = encapsulate_with_drbg(kp.public_key(), &mut a).unwrap();
+    let ss_bob = decapsulate(kp.private_key(), &ct).unwrap();
+    assert_eq!(ss_alice, ss_bob);
+
+    // DRBG-backed ML-DSA-65 hedged signing
+    let dil_kp = dilithium_generate_key_pair([0x66; 32]).unwrap();
+    let msg = b"drbg-backed signature";
+    let sig = dilithium_sign_with_drbg(&dil_kp.signing_key, msg, FIPS_CONTEXT, &mut a).unwrap();
+    assert!(dilithium_verify(&dil_kp.verification_key, msg, FIPS_CONTEXT, &sig).is_ok());
+}
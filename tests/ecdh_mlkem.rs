@@ -0,0 +1,266 @@
+// tests/ecdh_mlkem.rs
+// SecP256r1MLKEM768 and SecP384r1MLKEM1024 against OpenSSL 3.5 key shares
+
+#![cfg(all(feature = "ecdh-mlkem", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::state::reset_fips_state;
+use pqc_nostd::{
+    run_post_or_panic, secp256r1_mlkem768_decapsulate, secp256r1_mlkem768_encapsulate,
+    secp256r1_mlkem768_generate_key_pair, secp384r1_mlkem1024_decapsulate,
+    secp384r1_mlkem1024_encapsulate, secp384r1_mlkem1024_generate_key_pair, PqcError,
+    SecP256r1MlKem768ClientShare, SecP256r1MlKem768ServerShare, SecP384r1MlKem1024ClientShare,
+    SecP384r1MlKem1024ServerShare, SECP256R1_MLKEM768_CLIENT_SHARE_BYTES,
+    SECP256R1_MLKEM768_SERVER_SHARE_BYTES, SECP384R1_MLKEM1024_CLIENT_SHARE_BYTES,
+};
+
+// OpenSSL's server key_share answering the client key pair generated from
+// [0x11; 96], and the shared secret it derived
+const P256_SERVER_SHARE_HEX: &str = "\
+    04a642739a8f172315df22da97fc5ea2fe4736c5cd096ae9270a9bf8591ae4e37fe2474a3d6023cc0c391a77fd4e4b5a\
+    41efa2066dfec6e8b5a364ba80810da80a8b15cbe17263fccdf3e981d8cf40c54bab96d2818d980673c4fbc8bb6dd6b6\
+    b65518803bd23356ac08f00a044005034166148b611da1708d28a887466343eab5a1a4ff5b9bd93b5f2f31f3d153792f\
+    9a29bda3ea1bb42ad236dfc156ebc49eaf3fa6333d59f1cbe0aa026fa97a149bc4b0d0c41ab26ccbf7ed3b128f924654\
+    9eadead5182ecaa034606c7e30187c57e1bd4dc1a64fc6fa36f2d4aacb914e11160292caeabb02dfe96282e4b57c1b78\
+    43db45fd534aa896b027692b996773831dac7df7c6f8112d5e5a248caba357b870b743a223f1156ea6180573444e03e2\
+    7e00d7462282cebe757500212c1fba1cf0a515d670b1459f3c5937202efecec1b2cfcb3307d388fcc728a90c98a877ce\
+    1d2096fd2e23df89bd334eceb5f9e528fcbe562c24c4ccbb5ed4ea86b3a8440c2168a0a0cfddf535bc26bf55f062e493\
+    df4dab9a2ee496453b98be1d32276dbee029c8cb92419fd3b48966f8471cd33a40cda145b2d3999e75a75142698c6635\
+    db8bf377c0f84f5f0f4b5ef4cad8cd2f25de60fd08e901e221c62a8c1f7f8f224c69a60e939f260c279c21bdd5d5168f\
+    4e349d753dc63b250f790ab79f8a270ab935f2cb7eebb1ef2b9e5e9d46d542abc01e2ca4fa02c18836fbc12d293288c6\
+    2286f73e8a980c2b18684a4db47f840dbefaa6896ffe0b306aa4618da48c9b983e922b730a0e4e7a5e8216e7e61056cb\
+    6cc13802029c322d348395cbb7082b792d84c05325c694598867721efcb76e35ffa990dc9200b3dcf14b555fea24d871\
+    53a556a18ac748cd8bb4727eecbc92bc17261fca6f5429a2e3a3c3fd7f09eb3a867ffcd804362d1fbdf75908969d8253\
+    d6b483513e4930176aa650a13fecd08b80c94df0766943e0b66292118db435c737bbcd5f85f53fe69a0cf0f059b65185\
+    44954dd210c9085d5326ac0adf4745e348b8adabe761472ab85757884c773eec470592a521347bc42cd327188ed9d047\
+    e132c24fa462a2464dc192fd61f89716ac2dabf3d88c964f158ab3377dd8b57748f3683f28f11b21878d77e3d43709fa\
+    ce7bb9995824ad9601b7c79a5f7127139a4cfc4a53672651e8204f048d6d582f1de07cf57578c1e79bbeedef7e91676b\
+    ee79158b887e836d4c78715de11ff00aea631bac00cb0b1f9b30d4143576aa5b986506f5358435f22ab2e0f37585fb83\
+    545ad7529d17981edd05e1f38df941afaa1eb338dc1ca6cefc22e8673778287b3a39da955367ef732beebe4f2ee996fd\
+    0b714a26401bd17c6d6b0802ad0973c22e17355cfa242dd2c8b8d5ef5be14f3515087c66db6bee5c92a972e560c25ff8\
+    bcdd0408f2762092cbdc18e8684d2a1aae41dd98a8bb46b7a7b3f25421585fda94a8e2985c981463125ff40d94d4627d\
+    4b1f1c9e11ce0226154dfe59b30331116ee72c30365f90ca6099f750c17c5b878f5adf959c5820f9ffd8b30858b83064\
+    e5218868522cdad4b069ca6bbfbb8c8b6ed5162865dd4ce0f5436fde6547f64473be05c7536fe76ad4053a88e31d1e11\
+    3e";
+const P256_SERVER_SS_HEX: &str = "\
+    753f92d6d52d6bd33157b9c6c4f031b9df2b429819092e1c4db2bb82af0d5d2f029813034e466b1bd326382ecee63f98\
+    bfc156c3ec9c83ff6ed006d986325425";
+
+// An OpenSSL client key_share, and the shared secret OpenSSL derived from our
+// server key_share built with randomness [0x33; 64]
+const P256_CLIENT_SHARE_HEX: &str = "\
+    04fed0fb190c9be82adb4c2510ee012932bc6e50516ab99ab1efcc62de037e9c7c86fc819a01f63230a9060126619755\
+    1349d3dd8d787dc7609e28052b5a7934b4fc75ca3244081f19734793bd13e048b03791a5a02af5c8bd207b792d490ce8\
+    977c26473b4206a5ea96baedc7398a267d2d9bb1a901cfb3f120cd457d2d96106d2775e3a14bc2179bf887a352006f69\
+    cb95bef9cf7f6cbac5418469fb38d559837a7a3b5846c0f2a39e89cb34aef87a35a9c91f46147ad2916eb8cc0ba050d0\
+    ebc48fd5bdd276ace80bd08a83a37f2b72c700baf78b8c1427b7f0d9b38a123fad81b1f7b86fa15b414555adf22145b6\
+    b37bd32380f04a3443e33930a94318465e624231c3703005b254a46a0e6e34b71b0c64bbc27c44d29260113eb986b86e\
+    84b3e371be109410d39968dd0cb774f1cfdddb910aeac89e5c5343a89cf8705e10a36c975cbf98c6b84e58bb04d300c6\
+    77731d7284f1038f336bbbff909c52897ccdc9ac4dba905eac90963584c3431d94a4a084fcc6264108abb9cadc1c1ecd\
+    2b947bca235f82219845a6e1991135473f75e5ce818978d7675a42d18d53a96edae7b5c29127931ca54bc8925be356d8\
+    2093da464c29a48f183c0aa69a4001bc37e6d82d8b01cef5858a1208c9c3366e8e845ba30807f8d90e12a44be3f6ac7d\
+    9a28495c4d4fb5375e48aea18722bc330eebb34032e59d17757ae9c846b0ec3d75923b87b6804820c605994bba008bfb\
+    cc8d138a5c02a215f2635949f083beb57c30048d75b85d612cb53c6c45bfa817bcf2ac5f834a71f0872b78504f7423f9\
+    8c2a799abe5fec296bd52d7d0246125c66a0b48296058274509ff2b760402b83685164d3246d389043548102477b8ef5\
+    a78b24c49ae4487f20ca40ed718fb4363b743a4bedc6079d111a3c4668645a08bf7b1a322b0300749d0894a643136683\
+    169e7d6130b6232e8ec2a264bab15513316f56815f4c4ffc3c5fc6b44df2254f07213ce5f9250cd10d09740e1f21af30\
+    97a2efa5854ab2a068e3018a921c4a255ad1421057fc79c7a2448db6711fd8a072f6a6f8003dbee9660cd872c63a8257\
+    d8691e441df25903b9c7b170238f62871dc55a3db0fc998350a02ad2cd854122b5423d6fc96361563c3a186e09f6911e\
+    c25e649184b7618be8f24fb5573162ebb55a074b1a46abfd22a0c51352f3b65b06ea94500a2f12fa8fd494a7cd1b2fd2\
+    d0942bf3994dd11ab5f01a5484507608a4d602b1f5883003e38e45e4226eda9a38384ca002bf424755723bc8b1ab5ea3\
+    027733ca0a2dd68bb0ea96b5389fffb564a97c0047e66dcea06415627abcc42843c6ac2931618fb2ce60798e734a6cbd\
+    dc5d0520403c6939ca9a888173b8970ac1f7693f9c655242c3ab7c825683c82a5f457261d61cb13c45baba458a32a79a\
+    f41f789617b2da335a1c0d23880d8842b63e910a046876c9909132a8af4ceb1ef1500321157c1725962fd16ace160694\
+    c80cdafb8edb373e72557f8bb38b884a452779810b1047f0529ba83c3b1f17ca71833e9992ce84ab5528372382e2717f\
+    5b3cc29bc09c73ca457937e3ec467c30027af07b60320487f1465a550fa56278b4f0c96ba80b34f08de21a859623c1eb\
+    8b5609d8410f43a1cbeb2be9acb54f836ca5f44b86b6c3499269355b36987346ddf7b86271223160c0ebc6b2bc456e8f\
+    736593acb607d89b57acc081473a770018e12f6a65aa901d8d6de8c16b3550bb93e83355a1efbf8820b1656674714964\
+    69";
+const P256_CLIENT_SS_HEX: &str = "\
+    66b66ee53f9c9b996c7615bb948bcd1054b83f83c631d582142df6bd42f073f4ff6ae790543bd4328bf767d273995482\
+    ebeef650ace03db3279542bdbbeb42c6";
+
+// As above for SecP384r1MLKEM1024, with seed [0x22; 112] and randomness [0x44; 80]
+const P384_SERVER_SHARE_HEX: &str = "\
+    0440199778653ac2afa320dabbf37fe8837350f557ef6865ffe076dbfb5e913464c0c9731e9e504a16209877e46dce15\
+    993b10f0b1c76f538432638af74dca745d1c7c38758bafec62f41b2c95b02fc900d75355cfb8de965a2e59644ecfae29\
+    2734e7f760c9627c3c9c9e1b216171c69891f30de28fce75433132db93e3aba461c1defef97d0edc9e417c46f84c7613\
+    4e803077797699216b5d18ff11d7ba37c72823b62c5384b9e764a8c27db77491b16f2547a623d1fbdcc5efd0b311d32b\
+    65edebdea75a48fc6d574b5905e926b24738578f5d96485a80f7d9de0bd303b03f917134c5ff64c4f7b4c52b531aa3fd\
+    6e77754fe4a98c4b69a748589307f28bb4d6f1f6da8162f3ab980d186c212cb082d49463eaade3d4a803326b950b83ec\
+    11c18824c0a720841fe68fbc3d46366b2056bc438fd6f8294fa2a8e660ffa58ba97f72c93c1ef4991d6d282bf0f3a15c\
+    a24c8e319180e272deed9de2dfdeafdfc9b2d85cde202d593598de70a143f67e5f4e71e49e6788a53c4c10be54b515b5\
+    815e5c8649a64a29a3ad3f006041fd8efe841805b28b8baa472fa63a42a3e8ec2e5423da9c66e2b0f5b1246a3d4767bc\
+    dc6db1b1fce5f893951a014d40e55fe81771b3ffb466b2c797d27c41e6d21218e34f12930289d5e43017aaa2cc1fc4dc\
+    01a805ccd5141dca0a7588425e35741258cd01d0643deaeeaf9392eb4dd499280113ed0b6c8713f3998553c1d57c38f4\
+    c95c421f0d0e58f5df7753a3355a166f63d63112339c9ff29ddf97b5cd4679be7cf69ac3da79e5c87e715702a5c731e0\
+    79da5a73cbd726b284dcda1ded8c11a0b249644d76f2fe8d2b2a02d288e9d530261f1303e3f4e18eeb2e8a126e050458\
+    a7fa8cda0f6616dad28146b1635b63d9c87f89d49c824bacedc32d98acccfc09873c96f6fc128d4121903a3918d8957c\
+    bae1501ace758c9f56d0b0aed54aeafe785345c7a87f0dd48dcaa246e9195c89c7f0c48135e738f734c76e500f55a1b9\
+    8111c090ac118bfe5cbb24a3df350c9e5389d91991d20973024114844159326f2787216f86e8820cf93e81a9b11096ea\
+    64389c8a026ade09ec6df145ac4ab2bd28a853560fa4501b385f6d3f4ea00bb28256e6b535dd202f0110a18ed1cd2866\
+    a1dfa5b33074740bbfd6d0a8a5fef68e6b617690e22481e9ffb3540ed4584b00200cafabe0263ba072887486c94a0ee5\
+    d450726ca865ea1eabf4c66dc08f27c1ce50b161f50387542da03d8e6ae6b7006706339af6e4ecacfebfcc164d328a05\
+    fa0def4447f42128113e41975846535b287e073714ab35e8ec0ab329aa639130d1b4c5ca6338d0778f9747a716ec4029\
+    f07aea8c71c4f00944653861d86a5baf904b7cc4b31d835d87c2c522c7a7c9fa680065bb574af9c938f510039cae0b18\
+    7a4cc5e167f22f437f5d657a94502ecaac60569ab54687b5c2d483624900fd17305d8d22fbbf8201d40973e379461b55\
+    126ef2ee5284bd821213cd97146790f16952388fe094614f91f88a1e1e7a27adccd5764cde3af005e4e25128094625d0\
+    b5b60a57b2a9787ba58c2a062762efe8c6a60748b7eb69079ffe5606312daca5f89f5da86734f2d14400e4ab4917bc1d\
+    4f24b71e4aff9830a1bc627294a98b1bac04d9426a94e1ffb0a4c2c8b221f514e82678355ca0174f26111dc6fae5f6af\
+    541e79f5ca533e0201b4be040c40069595ca07b62737c465f63a742ba42cc79ef4fe2ea320c6ca63c99b3b6fdf112685\
+    3add1d7c46992028d1a38a22f31e9e1edbb4938e6f6841d6f0ae774de7d193d0b57b95123649b04c5c3785ce45913dc2\
+    be2a77b0afd5a4f84ea62be278cb8eb92462663eb08fc213508e0d7ab31d0e33e3e017e4578b984a99a59a4bc8dd408b\
+    28b45856a2dfa0d99e1ea1fec6b8b51a5c2fe1c7009da1b91f006c99337288a5bb9fbf5f366f683c0440f69e4d6b41a1\
+    f1615d9e01f0cfb9f28e8321699a70f6623c0bd71d62674c3df31c9f16771c9728fc0c2d2e3f58a48d7c2ce952109add\
+    4e5339727f281e4e64bc0cd106ce9af95181d4f6dd03bd12931f9e64d63126ab91e1d72dd4124c4d15c91de1acf32f30\
+    ca146bd8fa6999375a2caf53cd7cd26579f8f175bade9d8c88e5a4169574e2e7a1f2bfa60bd5394da679ea294cb9ae40\
+    c31e938ee111402793340305123c2f42870d1f7ae8a33dcea89c665659c6bfe289cf12a5d25edc8612f4a5cca23bf640\
+    0e9355e1d9673d8dcbdaeb7ef9fe363bbd545a1e67d7b5e95e8f3b4a73e9b66e73c24ebf253aec134a8b0df772e4d15d\
+    f5475be035bb44c38ddc7f92e3ca4968f796caf5a673a15b7977ed9744a51e7f3b";
+const P384_SERVER_SS_HEX: &str = "\
+    44660b22bd49bb2725ea63393f268a27b7c34bffa944d810c72f4d62d6559bcbdd2a6e39ed5582950c1ba18013c5a10d\
+    ead57185be03470b8ee717359b84a2be7a5292cb594578cbab71fd2e0eca3608";
+const P384_CLIENT_SHARE_HEX: &str = "\
+    0498568a232a15932289a9794c7d8bb577ec53428b58a63e56e55dc15fbc8df9d702babf7a38af6c8245666907753641\
+    1125ba6d77c4ba3e2fda3f09ed641d76480caf4e37912306b2e9a755ada3f6e00a6b5eb860a572b032b641bbdea95086\
+    8d64a145db14ca5a1773468995f74b79b002c72ea96a72800e0db04d9feb7ac3f67dc91395527c1158b08e79f70fac73\
+    3b76f30e57985e64634db7655add9085233c3b5d172612812154e8a758ab6c598525f71c3682d5396b7876b8c46cd085\
+    6dc2855dfe0741277b48f72150cbd46bcc37b08f1955bc75536ba4564ccb95da494720b07101a213979c7c67d3b6a566\
+    0efabca99385a9c584b5f1fc9a00cd5648454ad21670a8a0582af5a8830066dc6b439e49ba77927aa343020a97bda975\
+    8274330e18923f8dd42ee59641af7746e98c9c08932fa5bb255efc7dc1e9be00d2ab7bf8a34ca63cdfeacb3b9a8bf9d0\
+    0a9beb45a899060167528214add800515baa2de2a018ba529cebfc4ea93540839a1def92b13fa43f325aae5cd9967baa\
+    73e2d38c96f9b5a0d01f746b4966189814a0c716a96a40ea97492c1b102a2a4999b1afca0fbce052765047c69531be52\
+    420dc89e2cc071aeac81ecbabda5c86a07a23c2367558ec4677b076c3ccc2594d469718765e8f804e05a68c9dcca015c\
+    5fbac2561c88b8bd862b3ec01e15b27e5504a6466b6487d248b8c67310a25006727d9ce9b1b18cc0249bc3903486cd00\
+    b05567a7bcc84912bb7759599fe4fc366d689112473793f67083c4272a54376110b2c1f1bc03dc6b52cb90ccac564138\
+    9a3ba3681e86a2aa42a94cb91a20a70f8ab4a58a31bdd9e9cac06ba518705cdbf5b191b49019ec3c1c0a0d13fb1d48a9\
+    b99d87bdb21c0d9600611f05412de46ab15b6fff627f504b194857ca31d228c79b432eac0199a4363fca0657433cb520\
+    6432880a60f7571236b78ca34e2bc0c61ca33663f66e23f43c5685b5a1d81d35cbae5b13c6e6d3ba53782059a244bccb\
+    89392a6700199ad33a116f976b5a4754a39006cb7228ac2018d861759480b3a3ec63346a13355b1323fa9acf368e4617\
+    c72bf07775b959d839a9c8c9838e54c56664bf25666bf70cbb8a79c0fd673d9917454fba7e5a2c95a86b8c98c5078fa6\
+    a9db8aaad0b30614261fc070b767c056161cb0da31c05efa506b6689c02b193ec9764fe8b709736879b8bffb399a4d27\
+    8efac7564e701bcf334c54bb3e36a96facab9fc2eaa91113b8849685ccd21789ca9bce015818c146139a28c6d272c18b\
+    989ac454e22b5f785597b7693747652fb7105a627579b22317c432c81ef304c0931942f5a385c1575c50735e5c9c6dea\
+    acfeb51df59c001c28c947c75676dc946d260540663485b5b7bf2a6f3efa164af31f1b3b37596205bb9a9dd137aab47b\
+    395433c18ad71bb21a562c490b971973cb41cb1d0c742582b63a8c8f6e6c9bfa6c3ddd43a4b0f0712da78536fb316165\
+    8fc2e7949984594f0a50c1491b4201827d507f15faaa6589425b75aa44901561175d690250d8091ee5dc250a06008024\
+    7b6d93916b3156bb5466737c1eaf2595e69c6b98772f77e6052e822fb90c9436526ae6c415d9011d428437a3d77ffdd0\
+    a02db325e18c6d3052a5b7961ca8cc37cdf541cd235354db00340079bccb64963962eb84a2d7db3507a70679914e05ea\
+    12977920f5d19023559549c7c76724377fec442906c0232bc86ffa18ccab6c99206f5c900f62871f39c57aa89c4faa2c\
+    3e6049a4954586273500aac841c02993a5ca7c207475ced796a0524c42710fef23a85f4c7f59044e1e1cbc2423132138\
+    b1f9d6c848bb5c4a0a353fc65f0268698d9a2afc60b5229126ac09955c064e0366932ffb368a86ce92abcc34da6f84e2\
+    b9b0a9526bf4809c629eaaa36c4023579a07bbf2b0b2fc22b3217c1da9f506e2921e948649a6315ea317a6dd43cfd8eb\
+    8368245ff8b4750b935313787a9184a745e3bb86f59b72e618e306bdda363824a81b98fbc30c42afeb15c0eaa3c3f232\
+    32f827aa95926ea034240f6826af84b28806919bfc9eb2a797de615b2d593e30fa3815ac637c6b657e45c89ae871a378\
+    9f32c77c05a1815301a22c386570b28afe3016d6a808e09417bd7698cf6b7e6264a3efa9bdb57c642a94802365ca7901\
+    7cafaaac8069144ff789e30663134a2689db4edf13393138c8085386bc90b8c5380ad4c46d336c2708b89d0aa469b673\
+    8565db904907a9983b3f3d972301d6293b0bceba2b670ed52f9fc32bb92a8a3cf6ae2baccc092215a75b1b9e71026d20\
+    3ca0389c4fb2d7f4947f52394a24d36ba6c6808023bc6862af57f2ceeb0c117d1d";
+const P384_CLIENT_SS_HEX: &str = "\
+    b8c721fce50b2faf96c569598dff8b96d2024648d4d0f98f912a3c47df11b9cb7cdf208f27d640cc4216bc75974306fc\
+    3e444288a1fc324327b92c802682340a9c3da6c082c1d1a82720549330b22878";
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn ecdh_mlkem_interop_and_validation() {
+    run_post_or_panic(); // Includes the ECDHE-MLKEM KATs
+    common::login(Role::User).expect("Login failed");
+
+    // SecP256r1MLKEM768 as the TLS client
+    let kp = secp256r1_mlkem768_generate_key_pair([0x11; 96]).unwrap();
+    assert_eq!(kp.client_share.as_slice()[0], 0x04);
+    let server =
+        SecP256r1MlKem768ServerShare::try_from(&from_hex(P256_SERVER_SHARE_HEX)[..]).unwrap();
+    let ss = secp256r1_mlkem768_decapsulate(&kp.private_key, &server).unwrap();
+    assert_eq!(&ss[..], &from_hex(P256_SERVER_SS_HEX)[..]);
+
+    // SecP256r1MLKEM768 as the TLS server
+    let client =
+        SecP256r1MlKem768ClientShare::try_from(&from_hex(P256_CLIENT_SHARE_HEX)[..]).unwrap();
+    let (server, ss) = secp256r1_mlkem768_encapsulate(&client, [0x33; 64]).unwrap();
+    assert_eq!(
+        server.as_slice().len(),
+        SECP256R1_MLKEM768_SERVER_SHARE_BYTES
+    );
+    assert_eq!(&ss[..], &from_hex(P256_CLIENT_SS_HEX)[..]);
+
+    // SecP384r1MLKEM1024 in both roles
+    let kp384 = secp384r1_mlkem1024_generate_key_pair([0x22; 112]).unwrap();
+    let server =
+        SecP384r1MlKem1024ServerShare::try_from(&from_hex(P384_SERVER_SHARE_HEX)[..]).unwrap();
+    let ss = secp384r1_mlkem1024_decapsulate(&kp384.private_key, &server).unwrap();
+    assert_eq!(&ss[..], &from_hex(P384_SERVER_SS_HEX)[..]);
+    let client =
+        SecP384r1MlKem1024ClientShare::try_from(&from_hex(P384_CLIENT_SHARE_HEX)[..]).unwrap();
+    let (_, ss) = secp384r1_mlkem1024_encapsulate(&client, [0x44; 80]).unwrap();
+    assert_eq!(&ss[..], &from_hex(P384_CLIENT_SS_HEX)[..]);
+
+    // Malformed key shares are rejected
+    let mut share = kp.client_share.as_slice().to_vec();
+    share[0] = 0x02; // Compressed points are not allowed in TLS 1.3
+    let bad = SecP256r1MlKem768ClientShare::try_from(&share[..]).unwrap();
+    assert_eq!(
+        secp256r1_mlkem768_encapsulate(&bad, [0x33; 64]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut share = kp.client_share.as_slice().to_vec();
+    share[64] ^= 0x01; // Point no longer on the curve
+    let bad = SecP256r1MlKem768ClientShare::try_from(&share[..]).unwrap();
+    assert_eq!(
+        secp256r1_mlkem768_encapsulate(&bad, [0x33; 64]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut share = kp.client_share.as_slice().to_vec();
+    share[65..65 + 384].fill(0xff); // ML-KEM coefficients not reduced mod q
+    let bad = SecP256r1MlKem768ClientShare::try_from(&share[..]).unwrap();
+    assert_eq!(
+        secp256r1_mlkem768_encapsulate(&bad, [0x33; 64]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut share = kp384.client_share.as_slice().to_vec();
+    share[96] ^= 0x01;
+    let bad = SecP384r1MlKem1024ClientShare::try_from(&share[..]).unwrap();
+    assert_eq!(
+        secp384r1_mlkem1024_encapsulate(&bad, [0x44; 80]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    assert_eq!(
+        SecP256r1MlKem768ClientShare::try_from(
+            &[0u8; SECP256R1_MLKEM768_CLIENT_SHARE_BYTES - 1][..]
+        )
+        .err(),
+        Some(PqcError::InvalidParameter)
+    );
+    assert!(SecP384r1MlKem1024ClientShare::try_from(
+        &[0u8; SECP384R1_MLKEM1024_CLIENT_SHARE_BYTES + 1][..]
+    )
+    .is_err());
+
+    // Out-of-range ECDH scalars are reported rather than reduced
+    assert_eq!(
+        secp256r1_mlkem768_generate_key_pair([0xff; 96]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut zero_scalar = [0x44; 80];
+    zero_scalar[..48].fill(0);
+    assert_eq!(
+        secp384r1_mlkem1024_encapsulate(&kp384.client_share, zero_scalar).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    // Approved-mode gating applies to the hybrid services
+    reset_fips_state();
+    assert_eq!(
+        secp256r1_mlkem768_generate_key_pair([0x11; 96]).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+    run_post_or_panic();
+}
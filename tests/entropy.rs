@@ -0,0 +1,85 @@
+// tests/entropy.rs
+// SP 800-90B startup and continuous health tests on caller-provided noise sources
+
+#![cfg(feature = "fips_140_3")]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::state::{get_fips_state, FipsState};
+use pqc_nostd::{
+    run_post_or_panic, run_post_with_entropy, Drbg, EntropySource, HmacDrbg, NoiseSource, PqcError,
+};
+
+/// Xorshift noise that turns stuck after `healthy` samples.
+struct TestNoise {
+    state: u32,
+    healthy: usize,
+}
+
+impl NoiseSource for TestNoise {
+    fn sample(&mut self) -> u8 {
+        if self.healthy == 0 {
+            return 0x5a;
+        }
+        self.healthy -= 1;
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        (self.state >> 24) as u8
+    }
+}
+
+fn noise(healthy: usize) -> TestNoise {
+    TestNoise {
+        state: 0x1234_5678,
+        healthy,
+    }
+}
+
+#[test]
+fn entropy_source_health_tests() {
+    // Claimed min-entropy must be 1 to 8 bits per sample
+    assert!(EntropySource::new(noise(0), 0).is_err());
+    assert!(EntropySource::new(noise(0), 9).is_err());
+
+    // Healthy source: POST with startup tests, then seed a DRBG
+    let mut source = EntropySource::new(noise(usize::MAX), 6).unwrap();
+    run_post_with_entropy(&mut source).expect("POST with entropy failed");
+    assert_eq!(get_fips_state(), FipsState::Operational);
+    common::login(Role::User).expect("Login failed");
+    let mut seed = [0u8; 48];
+    source.fill(&mut seed).unwrap();
+    assert_ne!(seed, [0u8; 48]);
+    let mut drbg = HmacDrbg::instantiate(&seed[..32], &seed[32..], b"entropy test").unwrap();
+    let mut out = [0u8; 32];
+    assert!(drbg.generate(&mut out, &[]).is_ok());
+
+    // A stuck source fails the startup tests, so POST fails
+    let mut stuck = EntropySource::new(noise(0), 6).unwrap();
+    assert_eq!(
+        run_post_with_entropy(&mut stuck).err(),
+        Some(PqcError::EntropySourceFailure)
+    );
+    assert_eq!(get_fips_state(), FipsState::Error);
+
+    // A source that sticks after startup fails the continuous tests, which
+    // moves the module to the Error state
+    let mut failing = EntropySource::new(noise(1200), 6).unwrap();
+    run_post_with_entropy(&mut failing).expect("POST with entropy failed");
+    common::login(Role::User).expect("Login failed");
+    let mut buf = [0u8; 256];
+    assert_eq!(
+        failing.fill(&mut buf).err(),
+        Some(PqcError::EntropySourceFailure)
+    );
+    assert_eq!(get_fips_state(), FipsState::Error);
+    assert_eq!(
+        drbg.generate(&mut out, &[]).err(),
+        Some(PqcError::FipsErrorState)
+    );
+
+    // Re-running POST recovers the module
+    run_post_or_panic();
+    assert_eq!(get_fips_state(), FipsState::Operational);
+}
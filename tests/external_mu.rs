@@ -0,0 +1,57 @@
+// tests/external_mu.rs
+// External-μ ML-DSA-65: μ computed on one side, signed/verified on the other
+
+#![cfg(all(feature = "ml-dsa", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::{
+    dilithium_compute_mu, dilithium_generate_key_pair, dilithium_sign, dilithium_sign_mu,
+    dilithium_verify, dilithium_verify_mu, run_post_or_panic, PqcError,
+};
+
+#[test]
+fn external_mu_matches_dilithium_sign() {
+    run_post_or_panic(); // Includes the external-μ KAT
+    common::login(Role::User).expect("Login failed");
+
+    let ctx = b"split-hash";
+    let msg = [0x5Au8; 10_000];
+
+    for seed in 0u8..8 {
+        let kp = dilithium_generate_key_pair([seed; 32]).unwrap();
+        let randomness = [seed.wrapping_mul(31); 32];
+
+        let mu = dilithium_compute_mu(&kp.verification_key, &msg, ctx).unwrap();
+        let sig = dilithium_sign_mu(&kp.signing_key, &mu, randomness).unwrap();
+
+        // Identical to signing the whole message inside the module
+        let reference = dilithium_sign(&kp.signing_key, &msg, ctx, randomness).unwrap();
+        assert_eq!(sig.as_ref(), reference.as_ref());
+
+        assert!(dilithium_verify_mu(&kp.verification_key, &mu, &sig).is_ok());
+        assert!(dilithium_verify(&kp.verification_key, &msg, ctx, &sig).is_ok());
+
+        // μ binds message, context and public key
+        let other = dilithium_compute_mu(&kp.verification_key, &msg[1..], ctx).unwrap();
+        assert!(dilithium_verify_mu(&kp.verification_key, &other, &sig).is_err());
+        let other = dilithium_compute_mu(&kp.verification_key, &msg, b"").unwrap();
+        assert!(dilithium_verify_mu(&kp.verification_key, &other, &sig).is_err());
+
+        // Tampered signatures are rejected
+        let mut bad = reference.clone();
+        bad.as_mut_slice()[100] ^= 0x01;
+        assert!(dilithium_verify_mu(&kp.verification_key, &mu, &bad).is_err());
+        let mut bad = reference.clone();
+        let last = bad.as_ref().len() - 1;
+        bad.as_mut_slice()[last] ^= 0x01;
+        assert!(dilithium_verify_mu(&kp.verification_key, &mu, &bad).is_err());
+    }
+
+    let kp = dilithium_generate_key_pair([0x99u8; 32]).unwrap();
+    assert_eq!(
+        dilithium_compute_mu(&kp.verification_key, b"msg", &[0u8; 256]),
+        Err(PqcError::InvalidParameter)
+    );
+}
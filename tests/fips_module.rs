@@ -0,0 +1,133 @@
+// tests/fips_module.rs
+// Independent FipsModule instances and the global-module free functions
+
+#![cfg(feature = "fips_140_3")]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::state::reset_fips_state;
+use pqc_nostd::{
+    kyber_generate_key_pair, run_post_or_panic, FipsModule, FipsState, HmacDrbg, PqcError,
+    ServiceIndicator, FIPS_CONTEXT,
+};
+
+#[test]
+fn modules_keep_separate_state_and_authentication() {
+    let a = common::module();
+    let b = common::module();
+
+    // A fresh module refuses every service
+    assert_eq!(a.state(), FipsState::Uninitialized);
+    assert_eq!(
+        a.kyber_generate_key_pair([0x01; 64]).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+
+    // POST and login on one module leave the other untouched
+    a.run_post().expect("POST failed");
+    a.login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+    assert!(a.is_operational());
+    assert_eq!(b.state(), FipsState::Uninitialized);
+    assert!(!b.is_authenticated());
+
+    let kp = a.kyber_generate_key_pair([0x01; 64]).unwrap();
+    let (ct, ss) = a.encapsulate(kp.public_key(), [0x02; 32]).unwrap();
+    assert_eq!(a.decapsulate(kp.private_key(), &ct).unwrap(), ss);
+    assert!(a.last_service_approved());
+    assert_eq!(b.last_service_indicator(), ServiceIndicator::None);
+    assert_eq!(
+        b.encapsulate(kp.public_key(), [0x02; 32]).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+
+    // An operational module still needs its own login
+    b.run_post().expect("POST failed");
+    assert_eq!(
+        b.kyber_generate_key_pair([0x01; 64]).err(),
+        Some(PqcError::AuthenticationFailure)
+    );
+    b.login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+
+    // Logging out of or resetting one module does not affect the other
+    a.logout();
+    assert!(b.is_authenticated());
+    a.reset_state();
+    assert_eq!(
+        a.dilithium_generate_key_pair([0x03; 32]).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+    let dk = b.dilithium_generate_key_pair([0x03; 32]).unwrap();
+    let sig = b
+        .dilithium_sign(&dk.signing_key, b"msg", FIPS_CONTEXT, [0x04; 32])
+        .unwrap();
+    b.dilithium_verify(&dk.verification_key, b"msg", FIPS_CONTEXT, &sig)
+        .unwrap();
+
+    // Crypto Officer policy is per module too
+    assert!(!b.deterministic_signing_allowed());
+    b.login(Role::CryptoOfficer, common::CO_PASSWORD)
+        .expect("Login failed");
+    b.set_deterministic_signing(true).unwrap();
+    assert!(b.deterministic_signing_allowed());
+    assert!(!FipsModule::global().deterministic_signing_allowed());
+    b.set_deterministic_signing(false).unwrap();
+    b.login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+
+    // DRBG and streaming services are gated on the module that runs them
+    let mut drbg: HmacDrbg = b
+        .drbg_instantiate(&[0x05; 32], &[0x06; 16], b"fips_module")
+        .unwrap();
+    let kp = b.kyber_generate_key_pair_with_drbg(&mut drbg).unwrap();
+    b.encapsulate_with_drbg(kp.public_key(), &mut drbg).unwrap();
+    let mut out = [0u8; 32];
+    assert_eq!(
+        a.drbg_generate(&mut drbg, &mut out, &[]).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+
+    let mut signer = b.dilithium_signer(&dk.signing_key, FIPS_CONTEXT).unwrap();
+    signer.update(b"m");
+    signer.update(b"sg");
+    let streamed = signer.finalize([0x04; 32]).unwrap();
+    assert_eq!(streamed.as_slice(), sig.as_slice());
+    let mut verifier = b
+        .dilithium_verifier(&dk.verification_key, FIPS_CONTEXT)
+        .unwrap();
+    verifier.update(b"msg");
+    b.logout();
+    assert_eq!(
+        verifier.finalize(&sig).err(),
+        Some(PqcError::AuthenticationFailure)
+    );
+}
+
+#[test]
+fn free_functions_use_the_global_module() {
+    run_post_or_panic();
+    common::login(Role::User).expect("Login failed");
+    assert!(FipsModule::global().is_operational());
+    assert!(FipsModule::global().is_authenticated());
+    kyber_generate_key_pair([0x01; 64]).unwrap();
+    assert!(FipsModule::global().last_service_approved());
+
+    // A local module does not see the global login
+    let local = common::module();
+    local.run_post().expect("POST failed");
+    assert_eq!(
+        local.kyber_generate_key_pair([0x01; 64]).err(),
+        Some(PqcError::AuthenticationFailure)
+    );
+
+    reset_fips_state();
+    assert_eq!(FipsModule::global().state(), FipsState::Uninitialized);
+    assert!(local.is_operational());
+    assert_eq!(
+        kyber_generate_key_pair([0x01; 64]).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+    run_post_or_panic();
+}
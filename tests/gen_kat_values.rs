@@ -1,12 +1,17 @@
-use pqc_nostd::auth::{login, Role};
+use pqc_nostd::auth::Role;
+mod common;
+
 use pqc_nostd::*;
 
 #[test]
 fn generate_kat_values() {
     println!("--- BEGIN KAT VALUES ---");
 
+    // Services require the Operational state
+    run_post().unwrap();
+
     // Authenticate as User to allow operations
-    login(Role::User, b"user123").unwrap();
+    common::login(Role::User).unwrap();
 
     // ML-KEM-1024
     #[cfg(feature = "ml-kem")]
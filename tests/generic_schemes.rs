@@ -0,0 +1,48 @@
+// tests/generic_schemes.rs
+// Code written once against the Kem / SignatureScheme traits
+
+#![cfg(all(feature = "ml-kem", feature = "ml-dsa"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::traits::{
+    kem_decapsulate, kem_encapsulate, kem_generate_key_pair, signature_generate_key_pair,
+    signature_sign, signature_verify,
+};
+use pqc_nostd::{run_post_or_panic, Kem, MlDsa65, MlKem1024, SignatureScheme, FIPS_CONTEXT};
+
+fn kem_round_trip<K: Kem>() {
+    let kp = kem_generate_key_pair::<K>([0x11u8; 64]).unwrap();
+    let (ct, ss1) = kem_encapsulate::<K>(K::public_key(&kp), [0x22u8; 32]).unwrap();
+    let ss2 = kem_decapsulate::<K>(K::private_key(&kp), &ct).unwrap();
+    assert!(ss1 == ss2, "{} round-trip failed", K::NAME);
+}
+
+fn sign_verify<S: SignatureScheme>() {
+    let kp = signature_generate_key_pair::<S>([0x33u8; 32]).unwrap();
+    let msg = b"generic";
+    let sig = signature_sign::<S>(S::signing_key(&kp), msg, FIPS_CONTEXT, [0x44u8; 32]).unwrap();
+    assert!(signature_verify::<S>(S::verifying_key(&kp), msg, FIPS_CONTEXT, &sig).is_ok());
+}
+
+#[test]
+fn generic_services_work_for_every_enabled_parameter_set() {
+    run_post_or_panic();
+    common::login(Role::User).expect("Login failed");
+
+    assert_eq!(MlKem1024::PK_BYTES, pqc_nostd::ML_KEM_1024_PK_BYTES);
+    assert_eq!(MlDsa65::SIG_BYTES, pqc_nostd::ML_DSA_65_SIG_BYTES);
+
+    kem_round_trip::<MlKem1024>();
+    #[cfg(feature = "ml-kem-768")]
+    kem_round_trip::<pqc_nostd::MlKem768>();
+    #[cfg(feature = "ml-kem-512")]
+    kem_round_trip::<pqc_nostd::MlKem512>();
+
+    sign_verify::<MlDsa65>();
+    #[cfg(feature = "ml-dsa-44")]
+    sign_verify::<pqc_nostd::MlDsa44>();
+    #[cfg(feature = "ml-dsa-87")]
+    sign_verify::<pqc_nostd::MlDsa87>();
+}
@@ -0,0 +1,77 @@
+// tests/hash_ml_dsa.rs
+// HashML-DSA-65: sign a caller-computed digest of a streamed message
+
+#![cfg(all(feature = "ml-dsa", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::{
+    dilithium_generate_key_pair, dilithium_sign_prehashed, dilithium_verify_prehashed,
+    run_post_or_panic, PqcError, PreHashAlgorithm,
+};
+use sha2::{Digest, Sha256, Sha512};
+
+#[test]
+fn prehashed_sign_verify() {
+    run_post_or_panic(); // Includes the HashML-DSA KAT
+    common::login(Role::User).expect("Login failed");
+
+    let kp = dilithium_generate_key_pair([0x33u8; 32]).unwrap();
+    let ctx = b"firmware-v2";
+
+    // The image is hashed chunk by chunk; only the digest reaches the module.
+    let mut hasher = Sha512::new();
+    for chunk in [&[0xA5u8; 4096][..], &[0x5Au8; 4096][..], b"tail"] {
+        hasher.update(chunk);
+    }
+    let digest = hasher.finalize();
+
+    let sig = dilithium_sign_prehashed(
+        &kp.signing_key,
+        &digest,
+        PreHashAlgorithm::Sha512,
+        ctx,
+        [0x44u8; 32],
+    )
+    .unwrap();
+    assert!(dilithium_verify_prehashed(
+        &kp.verification_key,
+        &digest,
+        PreHashAlgorithm::Sha512,
+        ctx,
+        &sig
+    )
+    .is_ok());
+
+    // Pure ML-DSA and other pre-hash functions are domain-separated.
+    let sha256 = Sha256::digest(b"tail");
+    assert!(dilithium_verify_prehashed(
+        &kp.verification_key,
+        &sha256,
+        PreHashAlgorithm::Sha256,
+        ctx,
+        &sig
+    )
+    .is_err());
+    assert!(pqc_nostd::dilithium_verify(&kp.verification_key, &digest, ctx, &sig).is_err());
+
+    // Digest length must match the algorithm.
+    assert_eq!(
+        dilithium_sign_prehashed(
+            &kp.signing_key,
+            &sha256,
+            PreHashAlgorithm::Sha512,
+            ctx,
+            [0x44u8; 32]
+        )
+        .err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    let oid = PreHashAlgorithm::Shake128.oid();
+    assert_eq!(
+        PreHashAlgorithm::from_oid(oid),
+        Some(PreHashAlgorithm::Shake128)
+    );
+}
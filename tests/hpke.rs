@@ -0,0 +1,456 @@
+// tests/hpke.rs
+// HPKE (RFC 9180) with ML-KEM-1024 and X-Wing against independently generated vectors
+
+#![cfg(all(feature = "hpke", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::state::reset_fips_state;
+use pqc_nostd::{
+    hpke_open, hpke_recipient_export, hpke_seal, hpke_sender_export,
+    hpke_setup_recipient_mlkem1024, hpke_setup_sender_mlkem1024, kyber_generate_key_pair,
+    run_post_or_panic, HpkeAead, HpkeKdf, HpkeMode, HpkeSuite, KyberCiphertext, PqcError,
+    HPKE_TAG_BYTES, ML_KEM_1024_CT_BYTES,
+};
+
+// The vectors come from a Python RFC 9180 implementation that was checked
+// against OpenSSL's HPKE for every KDF, AEAD and mode used here, with the
+// KEM shared secrets taken from OpenSSL's ML-KEM. pyca/cryptography 48.0,
+// which implements ML-KEM-1024 (0x0042) and X-Wing (0x647A) from
+// draft-ietf-hpke-pq, opens their base-mode ciphertexts, and its own sealed
+// ciphertexts are opened here. The draft's published vectors are not
+// included yet.
+
+const INFO: &[u8] = b"Ode on a Grecian Urn";
+const PLAINTEXT: &[u8] = b"Beauty is truth, truth beauty";
+const AAD: [&[u8]; 3] = [b"Count-0", b"Count-1", b"Count-2"];
+const EXPORTER_CONTEXT: &[u8] = b"TestContext";
+const PSK: [u8; 32] = [
+    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x3b, 0x3c, 0x3d, 0x3e, 0x3f,
+];
+const PSK_ID: &[u8] = b"Ennyn Durin aran Moria";
+
+/// `enc` for the ML-KEM-1024 recipient whose seed (d || z) is 0x00..0x3f.
+const MLKEM_ENC: &str = "\
+    59a6bacb415640f38b91e8115058be03aa9d18197a8f72d0272655fb72d38336d67dda1ea29a32060fcbc506217a3a26\
+    77b8f08e2f7a75267d5723301987855d30a837ed07f1de3728d532f6fb05855e08c4aa09417030b2f633398c87a60bfe\
+    e18428431f9ce0f875d51afefc35c63672e28faa02ab2c15bcb7f9334a4b489b6bc8464124afb30f2d2bf4853eec2bf8\
+    9de832f3ad0e3b5f6324de4adfab0702cea1c10d2e0677ccc5cd2ec47bb70f4537ef7f00f62afd260aee05d00384d8de\
+    4bdcc9c3d5d7d2c2288ff6f3a8b453953403248f99b53e42be99d5197df502761993e187e9d842237592a95770e8be68\
+    b240b5c6fba53745edb299a75fe64ee97d73e5ccdb501df75b0d3a77e737e41f154eddc0316046637538a65dc302c27f\
+    a26e5ed94c6e923ad31b9ebcc4e4de219b6bf42cd37d9114ca92ccf80fece15ce07e7cc9d85dc61a1459bf970fa417a7\
+    528a04256932b4079f13cd87072a049bba91baf33581e34a942916fb620624f9bdb407c905cfda8727a4f5c30ce9731e\
+    a25260ee9312f924af7377f979c7508384ff5e632f7927dfa81bef1d2fb0f63463b0218fa51a2edec35fb8a19d165c1d\
+    cdd563e8e33dc6844cfec0c943b108e79a2cbb49291f8afb447abb4237077ff06a6b0ba2b906b2c8121834621967d271\
+    d8d0ddc60d026fc621741ad11b5719f58b42c79ca86fc1b7535be2121a947734fc47536dc18fad6ebc214e81fae01574\
+    551933aebc83df2b4d880d3fef87299e219300f769876d4f76e9d25a423054f2e6b1f5be409fd48b751e5dab56151b07\
+    7f33d74c46dff79aa2be43dfd87d189fbf287f20ae560bff273d6f8cf90e00420e489595db04194870f2642fc7f5fe81\
+    5bf48fc352bc9015a6a9dc1ceb4c5e4e6536024c7ad613db3d14d86d7cfa49195b097e6591c4ebe38937084ed8bf636f\
+    2ac330dd3fed1439fe867564e62ca0bb813262461c0827f315c2cf4b1e6d8e7747b3351ecc3eee947c80407669281d65\
+    700365bab7e8b023380e3882b43adb51aec5a2ba5dae4358ef929ef3c0f49d9fb3bf529dc3028930b41f08ad1a48f596\
+    5765fc5b3a23b22d9feb21ed2cb6a695a633eadc09eb8146765aa3b0d4d8bb63ff3b079aaf5fe230a513c011e56b179c\
+    f9701026b844e45e73e984acf62ff17be840b63061474d63d7e7ab6f8d2122892cd55243826117f43acd38be3f23d47a\
+    ebe59ebe0db55de7a61d61d59318dcdc84b8d272c5e35f18d16cc17698253ff8ddce6e1d9a113f157bb4afaf828f7e14\
+    3e169f745c94f55f2bee5218191afcfab9f453f2e18929422673dba9083de0bfb1ac572a934e8c5bf5c116d555284d37\
+    8fede88fcd56136e6a9c2fcc9ca8abc41491015ce5a61255325dcf1920d536b3f5551cb42aa7e67c9668bd8468282996\
+    262c50ba322807717dd00934dbf00990b664959ecb7723b7bee7daca93a68bdc9ee438f31b84e6c304ad39800cafadeb\
+    bfe18414c539e32a81b5a99a69499649f282a3e0b547ae5c6ad7d64673798067720c56654534d42d121fe3298fb87089\
+    c778e126f9a051511fde96da1674ccf1279924149e0da6cfe609579af4e35cc29c4516c07f053c937ab2bcfd0466a0f0\
+    5f4535677768cff1e8a1b4dcf8f39242e5e22c8fad3cb9dacdfbf664e997f6d3c339b0b06ff2b1e3965d449469aa5130\
+    a1fb0b0e0d414cf64f5afa3202d63adeea82f7afd283caf190e00882f3f03f052525fbb09646ec842a0615b1f4f07a44\
+    04d7323ee05da2e28a416d1b533d0c3a0646fd5bc3bad79e1eebf825369c589e891fd8ccc4647659245145c86392d846\
+    403dc6d5cdf928e69de063d9bd85be4665478e4ec6b6cc02753b809a151d51918f4533676c979fc9cc772cf5b93785e7\
+    48b003d004a99860b817520e831e7e3b110e9b155debcf1e955fd9825e383fa253ab40c9c592e1dabe30f84795fc625d\
+    3de5b1df87df99b30bd62d9711b64effbcc0db1c4b12395fca565d8a3dcad2c7fb18e318c4ae410ffb0cbffe15cdfd9e\
+    0e1b6498caced760677902050059f79116ff426a7d40b78dcc2a7ec53a88ddd0d726dc1a0177313ada2e8485524133c4\
+    51e9dc8299b2befa4609b3ed4db5b7b53ca168a17f41f1b94b35d9943567cd7388367a9e4e7391243b2359895cfd54b0\
+    371d2bb6d41e9e635b84b85e8d7085f3fdfea56ec30cf7410f2e6916c140c664";
+
+struct HpkeVector {
+    kdf: HpkeKdf,
+    aead: HpkeAead,
+    psk: bool,
+    ct: [&'static str; 3],
+    export: &'static str,
+}
+
+const MLKEM_VECTORS: [HpkeVector; 3] = [
+    HpkeVector {
+        kdf: HpkeKdf::HkdfSha256,
+        aead: HpkeAead::Aes128Gcm,
+        psk: false,
+        ct: [
+            "ccef49b352e70b0e996a80c998c95e48259917175335289c7ccc8ef5c83d7a6f88b9c5468f1b676d06d5f09356",
+            "6c659fd6ba2b5dcd58a9ed5b8746fdfbba59072fa38c95a4c69c44ec86266b5dc1db808ca9e3bb76aa6fdc297d",
+            "1fe11716b76c9b13029cbad506012cc28781041539ff902ea215942fed9da0f4593a385226f90eca1b13a788c7",
+        ],
+        export: "d12a8dec10619d0c3a5067750430c516da0a7113879f382b115be9717e5ef7ce",
+    },
+    HpkeVector {
+        kdf: HpkeKdf::HkdfSha384,
+        aead: HpkeAead::Aes256Gcm,
+        psk: true,
+        ct: [
+            "3a1a2c9085ac64beea8a6de6a4b7e8ef24384717811a58d5a7ce7d916070c46320174c4e7ff54e1d04fe5da59f",
+            "5fb5a911ef8b5774344e16424c5eaefd47a0dd35f2d8f003abafe8f768a334bc77ed5f0158a014660e71587f15",
+            "2f5a9aab631571b693b8b6dee203ff4a309593e7abe7b65a1638faf68618e3ffee39a3b608f881dec4143e7421",
+        ],
+        export: "3ee749cf21e6f5ab454e07a012424bd8d81dc599d9c44fc8f662d86370f544c4",
+    },
+    HpkeVector {
+        kdf: HpkeKdf::HkdfSha256,
+        aead: HpkeAead::ChaCha20Poly1305,
+        psk: false,
+        ct: [
+            "7f1e1a459f1da0127fe16ea8e2a2f58464799be9eccdc15b9e75fd00034d5e7982a5fabaaf37c916efee5f5ce0",
+            "96f3801039cd2ac97bc4fe8c4960aa0b216dc681486d41c15b263f781d07e989223767f9d6af426b05dc1befcb",
+            "f8fce2dd7c1b8fa06b9b1b56365329a5ff27d2849727334b6898682ea5d3fd8cff57b8a255b9b573362597ec83",
+        ],
+        export: "adfbe569af2b7076cef39313d10df2180ae580a5a896f3aad8de0cca9e01ba2e",
+    },
+];
+
+/// 64-byte export of the export-only HKDF-SHA384 suite for the same `enc`.
+const MLKEM_EXPORT_ONLY: &str = "\
+    04c8145d297cc3e69c849c43d359fe4e2b365b3611cf2e2bb230f535d23aba7a7e837312d1b39a9f380f308a49e46394\
+    0689466feea2d781f2049b7a799288a5";
+
+/// `enc` and first ciphertext sealed to the same recipient by pyca/cryptography
+/// 48.0 (HKDF-SHA384, AES-256-GCM, base mode).
+const PYCA_MLKEM_ENC: &str = "\
+    fd5041fdc502f97ed817b1a44a7369cc88b4868d5c83fccfdc1901663ffaf9181ff2f94e832cc9e9d95238b61d3fb10d\
+    3b79812cfc5ceee148596b3b355072b62b31e170f4b9e5d4fda02234bc28bbd932efecee4c8e8f2ab80d9b7e92902df1\
+    f64c0036714c320469a4553da0cdabc6dbc0d881ea2223bb11f7ec9a3493cbb54fc3550ad2dc4db4b82105ed646e9022\
+    e1437339ea882a818ccc5a21899765144e58e6ccc2f6d3be105a17b2c90b12cc5615a7d6300a951e56c6b6f4f5afc606\
+    0ea03a92bd2301de078289a9639579c2d169cdfac8eae3a86066548f5a8b3c75255e1e066540f751d19c5921c22da588\
+    4ac611261be546a8c63b246377792d563e162e4b6d25a370cbb2b7986a2d23971e5a10801a4e6fdba5b180b2e0e03617\
+    99a511a9c6669e2d0a65d52b338b55748bac14c3fa5088b60d1a949c28073eee03539136c51869959da7ca4b29057344\
+    b0a3acc18edd4c1db4a3ce6be459c1f846ac5de83eb590f5292854d6016239c66bfdae68c0a183dd75324e0bda2725c4\
+    2c1ccfc3e8a8258be2743e2f0a27400f43bf76e6974d4ecfcdb4a00e56a2c97de9fb542c72c1de1123d3945d43b537b8\
+    5fabb3abcc29ae296bd9ebc83481305bcaa1748333b6eebb57e84e146a504ba6b7e11461d40115cb7d8ebbc0189347ab\
+    86315a230ee1f44443c76fd685b9ca2ae32e1ba0705eb7cb5427d829e0a992ef71d1bf7ff26ed24de35e7c77b0da7dbd\
+    c6b54f3d602b3ddbfd6649a9f895a4bac55fd2bc1b47dfb089797bc21551ce727dd72e2c9a43e304183d85523cf239ab\
+    d64e0770c69edf6973b9ff35847c7c557e8d38f2d12c4ab63405dda4d6c176fac1e0ae9dfbc6e595fc3282630590890b\
+    5535dc9ef347a2dd1009aeabb101955bf6eaf8d1adfc7f870492818e7121e6fff2c0adf5545f65af9edfbe0e4073aaab\
+    cbbf1839ff16871cd2e29844b1749c79262f10e14246567bad7c8ea28faf1b9946c878e1cd469132c759d012f17b5a93\
+    2f38bbfab37568d42cb759ffb66e39db606e646b77ac1600f195f3491a8e38869795786d4c179b4145a4f11bf974a598\
+    1621b0ccc28af68b89cb6bc86d414eb06a966c51c78825102acb0116ab3a7d3210093701f0058306beab08df68cdc7ff\
+    771744b85c5999079acfe811bd6c3ba8aad140cb46af0b489d6e63a07ab6765f6772d2f339983e17f0943cd9f1a4b941\
+    dcc83bf991e4b0293f64a5184a61a8933d31628f76032adf93ff9b29951047dad2895b2dea034dbf5b33bae660ba6399\
+    e88cd86a3c34f7d489b1d6f09958dab6f9c0987ca6a12f2ba6b4a415665cbc1980084f2b3e1bbce07d7eeaaf21b76cd7\
+    681e7c1c008f7693a9d0d9a9d436fe3f5450474db33c823b321760217c97df337ed17870a3af5cdb92d28f271d9d7daa\
+    5315916eaa71adfbf77208e34d0c4e2915269a535f3d1d3cdc2b9fbfc447aab8d0a66559c529ebaccfd320960abe2063\
+    8a0878df7b9fe97ac5a334a74e282d8651367e8452b6498ffb9e24cd3b1af58c012b9d4bc9e11ee2943455509c71284d\
+    9e85c285a312eb2e3bf0377567983dbf8c35b333e73aed7d5fee300845e589951acd07a0d0e78d65d2e17dd2e1f8219f\
+    7edc21cf865fc4ad1fcea6b3e286fe1f44a0af45f846d566990d699d98f3bd3ba1d0313791d40aa317f5f2b27e2d6296\
+    ad14ff2e8154bf95ae8c7c860e86ffa021385c47255c9ee1c58582f4c4e2ea32e6aebec14a88655c89c3837aec94d37d\
+    c01cf76f8bfefd0b4e269cb180631e8cd63200d7a03d5dbffcb6e33a17353872bb3aa7c440d8dcbbfc63fd250524e421\
+    e0840855de430a07240bb93ca63a4afe9988977eb70ac72d61b652a411c47af74eec53a99864a07a2febd666f8a84c7e\
+    69eda45fd6fbbd53b1d706d5e4b86e58055d464958940f907c2c89cc69e4e9b5e8d122f409e2f0aaef4f8fa0e7d44022\
+    36e1fd47c87a8a9a1a1a62aa380b6fdb77563f046d3a90d4ac75592406e91d8f0261846f63f7162b18cbfabcf64bb030\
+    aea8f81a42f18cccf7b4b5e6dbe24d4ad0f4076ef9558d17c2dd16e92a6183f19d290ce78fc6268074a0141daa2e9116\
+    679ed30560d3bb1234ca21917bee56a02fd03485fb229686a1ae8c23bf031e91594df7775f854830ab4219ac3515ef65\
+    30b701c82814234408bebcd7c947598a1c4c7b7df5a96130e91a0def2bf1b6e4";
+const PYCA_MLKEM_CT0: &str =
+    "be93121f30a0520242900c2fe46fae203b077daf56041f4a1f7dd6d126adccb26499c71af3f3355841652cc90d";
+
+/// `enc`, first ciphertext and export for the X-Wing recipient whose seed is
+/// 0x40..0x5f (HKDF-SHA256, AES-128-GCM, base mode).
+#[cfg(feature = "xwing")]
+const XWING_ENC: &str = "\
+    0ba2e9071092fc91a0ce4986d501697ca8a280d6d5c2d0337ccfdc1d28feb00697e6c51c72b2f638c6d9b58319d4dd58\
+    4fb75eda8785358a967b0a32c16954225c10f453959ac485b45e27145f33aff7dd5fa56921c5f109bf8e1959a338b531\
+    922ba7097450264c2172601647df52151bc635697bfa478dbb65edb0bb80e6d212d53af01d07bfb429e8c6bb08b1ef43\
+    97ead2a9f0bba7873cf0a1b4faa927250e8d6d6c3205e03ef4c9f841e940565d9e88740ebaa65688eb3ace8a69d09291\
+    ed9c4136d68978240859ae1ed9dd8fa7434b1313653d6d0558e4c2aeb2f305cddad56066b852aecf8680906a74b42b57\
+    33c3cd4a9357081597871ba2174e92cc6f5c1c9842da481c3223ed7deef16a8a15ca889d2f083b26d68483972685f679\
+    4975ffe6d15c9b1986568216c5575e43f29b7fce201543aa90be4a8429f0426e85c6dae1b882761c498b5abedad46a9b\
+    cb74ad121beda1a246e0c160207834f825cbf2908c4ba15808ef5bbce211b8be0e1844dddb5afef25f410d9e738a6d3f\
+    05c366fc72cd4a00ca75e9d1cdd1ed144006be7a4dae2eb70f6980f3c2a1c16676fdb69bd662d30a69d8feead1af9f0e\
+    908d9fa57ebf9ced1d7107bc394debc65a62b19daf6439eaca67e35941f3298946964db919f718fddc44a12bf37e5b3e\
+    f2594f5037db0c2984aabd72889fc461b5f1b2ea84bef51a60639538ad1d363fc24d6fecde27991a61eeb25c560e6d6b\
+    14acbb5ab622de8c80acc56e2d1c67268dfb3d7bbbafeba626e11275f374d24989690ec851d90a542597e39c90be004e\
+    944220f02f5b19cf1ed4bcad23bfafa6392935da65babeaee0ffb0a02a9042cec30975a9f591230f644467cf5f73c74f\
+    68694d013a132b0d9e7d577a21effc2df1d0b751303f07644f85732d1a455a97ffadb830b880266c4693e41ed20a35c9\
+    94a222ba3cceac08378ee4c16029b6c936b239c41195342428870154f96a5aa165ba41c33afbab490efcc5a892894b75\
+    dce0e8c1cc21ede17c95be810d43635916c279b1efd73ac9cc69ec875b550a40ad2af3919f0ea57fd822171a0bdcd000\
+    26278e88e7cfb6f13485879b1e9c39068c28588c663005d29956b7c213b5fd7881972634bb61b1978ca0135b47b2b903\
+    c726dbf5faa2d9414436b6deeec578ff2db96fedd85368e874adab8f86679f9b25a072076500d864d22bbfbe164e03ac\
+    5982d4b0c9123a1a124352f890229fc0cf58c07b614db12ff798721ce77c350760d845e5cfe1bb09e83e81d5cda2f784\
+    9df3dd19f898e9de2108008d21c725341457eefeb4acda1c6078b091370ec6b1835c7806be09e243489de37698677b22\
+    aa893932113242a5dd7fdc3ad18b0e9b1d49445867b7d9c54b5857afe2a8ee73184c161d33cd201a6be0cf983535c8f8\
+    d0475f3eda56c161e728a2d987b3a7c84c952325aaea125c7d0b0a5c23cffac5b6123a75a12104f6810349e21b6275bc\
+    2b524f7a0e42f3556e2e318cf375f197739d1c386045c86285bd72d24670c5a90c22bb8b361fa0d259e74d190fa6d925\
+    a58f3273e9a43e8d2458407e3b59e00f";
+#[cfg(feature = "xwing")]
+const XWING_CT0: &str =
+    "eecca335dd8b6bf24344accc84aa7622ca34e8f89e7ce9a9bf6cc63888eb97e27f147cdb82dfff35ea6f147ec1";
+#[cfg(feature = "xwing")]
+const XWING_EXPORT: &str = "391941545522f498d13f54ddb618d5bbb45d0e51a7d00d6ce27b8532e254c02c";
+
+/// `enc` and first ciphertext sealed to the X-Wing recipient by
+/// pyca/cryptography 48.0 (HKDF-SHA384, AES-256-GCM, base mode).
+#[cfg(feature = "xwing")]
+const PYCA_XWING_ENC: &str = "\
+    73b700de790564be8c0ea08386c8a2d126dc570748627f9eb587c93aba36ca187e31cabc1bb3a8087e294ee42f020f7c\
+    b69182471cbad65e83d4b34cb347fdb3032f5975bdf472f14ebc043de10ae6b895e23108a243e6b5001481b83331f116\
+    ab2196d31d005ec2e7c2841c64ffb51b61313ba62dabd948614097c68edbbbcccecd3e21b347490f8f015ed753e7ed35\
+    1d735364db04f9dc4d186a3857543d7cfeb1d9fa44b2ab90c4463b3be7eee5e849b3143619798f235481d377619dd48d\
+    d0645b34ae98d07b519e38a440cb7e2a29e202305ae6e63061bcc1d5e475bf34a323eba0545c068b2831232a5d7b33b8\
+    0797ca81c72a7e538b7552b396bc75130ad44a98b87508cb00f44994c5b15211db17c93d93c401057ee34c50ae3b6bbe\
+    471b840ae815690f8c5b0fceeb9a447a6b6757138d4fc30b37dc81f53f0d63bae0cb289a98f04c86637f6f596792720f\
+    6309e23d5c424671fda6fa8fff3ecd4e892852551996dd1aa8e2cc28fae67da91f3af691b0ef7dbbff43b3e0c66d1b11\
+    b21ce7947d2f14a1ce70eaead3ca4f9ce6e04f1adc15b5e513a861367447c97a64215635dcc839d353f230b7d3d0a8c0\
+    0bc3a76cac9e2a24194fff45b5f72ecca589341684a186a2024d118f3e0114d1ae4274027ae9caa23b6cf54817201eca\
+    fc897efb7b106e0e5c2a47c41820baea3ffc29b772cbebdc0abd025b11620bc6b78517cdb03dcc36d1933bfdedbd7c81\
+    79305aa672de24be0098a7a2125247f666d197afa5d625cd9b53c606c210d4f6ddae6739f2f47d257db32a309341e410\
+    82878f2b3c3ebd0ede15dcc6a8fdfd94669ef62265cf46a6fdf475740a7671d5e0604a363df9ca3bc49b20b6dc1cbb47\
+    dbef7ed06d56d8b57e6c33fb98ef9422bfa25ba97314485e8ec5fbb1517312dd7bb07dd93563fd583224235f6a3b7128\
+    3fdfcd956a2fcb47e9805af08c3da23acc78624f62baf8b8cdf9c735c0d62b62bdb9bcfeb700645f8a8bfbf32653e58d\
+    3891584f2b4d7dedeb1e677165c538d2fa542922f8e8018f2a7c332635e492c3756a2dd64c12ea5e24375964477494cf\
+    188b593895265e93a2a23a54dae5a6b4991e53e4a4ad01d185e0435e6e6c50870f42fb8489cae6dad48314aed5f601d9\
+    69816b69734446bb9c6dc55aedd259593324246aa2ea953849dd8fd070ef5b407333b0051c631e3e2b699a366b513bf3\
+    e5bbc1e921cbd850a6283f9859f8a38485433e876355bec3802ccd36a58340522277ea7f0d0deb569b5da3da9b797ec5\
+    dec48faf25bc1bdd321e22598c61c16d612a0b74b78bdfd875a5c5416436597440582e4fa4bb691e740d676baac119dd\
+    243a3c680f47d5f09a3e0d9fa324863ece80d206a36c629c81523d9f69f683e6dc8e3be961d0d6802d1e064a98bdd44b\
+    e870fe694803676ebf09deac96beb27a57bb1df2a0501ccd18e2cbd587e2e99f910af09403323a645d451e567a677a79\
+    aa21cb06a6ceb106b3caa3c71efb556118b4d758560006b2bfd5c78d02f6a914023a433203277950f278c0a7e053e4d7\
+    aaab46f0d37e523296e16e3c5ba8cc35";
+#[cfg(feature = "xwing")]
+const PYCA_XWING_CT0: &str =
+    "226ffdc9fb2fc923b332119484894ae367a67d79c45edf6af32b23bcea4989b0964a908bb12a6f306aaac1a0de";
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn seed(start: u8, len: u8) -> Vec<u8> {
+    (start..start + len).collect()
+}
+
+#[test]
+fn hpke_vectors_and_modes() {
+    run_post_or_panic(); // Includes the HPKE KAT
+    common::login(Role::User).expect("Login failed");
+
+    let kp = kyber_generate_key_pair(seed(0, 64).try_into().unwrap()).unwrap();
+    let enc: [u8; ML_KEM_1024_CT_BYTES] = from_hex(MLKEM_ENC).try_into().unwrap();
+    let enc = KyberCiphertext::from(enc);
+
+    // Recipient side against the reference vectors, including the nonce sequence
+    for v in &MLKEM_VECTORS {
+        let suite = HpkeSuite {
+            kdf: v.kdf,
+            aead: v.aead,
+        };
+        let mode = if v.psk {
+            HpkeMode::Psk {
+                psk: &PSK,
+                psk_id: PSK_ID,
+            }
+        } else {
+            HpkeMode::Base
+        };
+        let mut ctx =
+            hpke_setup_recipient_mlkem1024(suite, mode, kp.private_key(), &enc, INFO).unwrap();
+        let mut pt = [0u8; 64];
+        for (aad, ct) in AAD.iter().zip(v.ct) {
+            let len = hpke_open(&mut ctx, aad, &from_hex(ct), &mut pt).unwrap();
+            assert_eq!(&pt[..len], PLAINTEXT);
+        }
+        let mut exported = [0u8; 32];
+        hpke_recipient_export(&ctx, EXPORTER_CONTEXT, &mut exported).unwrap();
+        assert_eq!(&exported[..], &from_hex(v.export)[..]);
+    }
+
+    // Recipient side against a ciphertext sealed by pyca/cryptography
+    let suite = HpkeSuite {
+        kdf: HpkeKdf::HkdfSha384,
+        aead: HpkeAead::Aes256Gcm,
+    };
+    let pyca_enc: [u8; ML_KEM_1024_CT_BYTES] = from_hex(PYCA_MLKEM_ENC).try_into().unwrap();
+    let mut ctx = hpke_setup_recipient_mlkem1024(
+        suite,
+        HpkeMode::Base,
+        kp.private_key(),
+        &KyberCiphertext::from(pyca_enc),
+        INFO,
+    )
+    .unwrap();
+    let mut pt = [0u8; 64];
+    let len = hpke_open(&mut ctx, AAD[0], &from_hex(PYCA_MLKEM_CT0), &mut pt).unwrap();
+    assert_eq!(&pt[..len], PLAINTEXT);
+
+    // Export-only suites derive secrets but refuse to seal or open
+    let export_only = HpkeSuite {
+        kdf: HpkeKdf::HkdfSha384,
+        aead: HpkeAead::ExportOnly,
+    };
+    let mut ctx =
+        hpke_setup_recipient_mlkem1024(export_only, HpkeMode::Base, kp.private_key(), &enc, INFO)
+            .unwrap();
+    let mut exported = [0u8; 64];
+    hpke_recipient_export(&ctx, EXPORTER_CONTEXT, &mut exported).unwrap();
+    assert_eq!(&exported[..], &from_hex(MLKEM_EXPORT_ONLY)[..]);
+    let ct = from_hex(MLKEM_VECTORS[0].ct[0]);
+    assert_eq!(
+        hpke_open(&mut ctx, AAD[0], &ct, &mut [0u8; 64]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut too_long = [0u8; 255 * 48 + 1];
+    assert_eq!(
+        hpke_recipient_export(&ctx, EXPORTER_CONTEXT, &mut too_long).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    // Sender round trip with caller-provided buffers
+    let suite = HpkeSuite {
+        kdf: HpkeKdf::HkdfSha256,
+        aead: HpkeAead::Aes128Gcm,
+    };
+    let (enc, mut sender) =
+        hpke_setup_sender_mlkem1024(suite, HpkeMode::Base, kp.public_key(), INFO, [0x11; 32])
+            .unwrap();
+    let mut recipient =
+        hpke_setup_recipient_mlkem1024(suite, HpkeMode::Base, kp.private_key(), &enc, INFO)
+            .unwrap();
+    let mut ct = [0u8; 64];
+    let mut pt = [0u8; 64];
+    let ct_len = hpke_seal(&mut sender, AAD[0], PLAINTEXT, &mut ct).unwrap();
+    assert_eq!(ct_len, PLAINTEXT.len() + HPKE_TAG_BYTES);
+
+    // A failed open clears the output and leaves the sequence number alone
+    assert_eq!(
+        hpke_open(&mut recipient, AAD[1], &ct[..ct_len], &mut pt).err(),
+        Some(PqcError::FipsErrorState)
+    );
+    assert_eq!(pt, [0u8; 64]);
+    let len = hpke_open(&mut recipient, AAD[0], &ct[..ct_len], &mut pt).unwrap();
+    assert_eq!(&pt[..len], PLAINTEXT);
+    assert_eq!(
+        hpke_open(&mut recipient, AAD[0], &ct[..ct_len], &mut pt).err(),
+        Some(PqcError::FipsErrorState)
+    );
+
+    let mut sender_secret = [0u8; 100];
+    let mut recipient_secret = [0u8; 100];
+    hpke_sender_export(&sender, EXPORTER_CONTEXT, &mut sender_secret).unwrap();
+    hpke_recipient_export(&recipient, EXPORTER_CONTEXT, &mut recipient_secret).unwrap();
+    assert_eq!(sender_secret, recipient_secret);
+
+    // Undersized buffers are rejected
+    assert_eq!(
+        hpke_seal(&mut sender, AAD[1], PLAINTEXT, &mut ct[..PLAINTEXT.len()]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    assert_eq!(
+        hpke_open(&mut recipient, AAD[1], &ct[..HPKE_TAG_BYTES - 1], &mut pt).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    // PSK inputs: short PSKs and empty IDs are rejected, a wrong PSK fails to open
+    let short = HpkeMode::Psk {
+        psk: &PSK[..31],
+        psk_id: PSK_ID,
+    };
+    assert!(hpke_setup_sender_mlkem1024(suite, short, kp.public_key(), INFO, [0x11; 32]).is_err());
+    let no_id = HpkeMode::Psk {
+        psk: &PSK,
+        psk_id: b"",
+    };
+    assert_eq!(
+        hpke_setup_recipient_mlkem1024(suite, no_id, kp.private_key(), &enc, INFO).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let psk = HpkeMode::Psk {
+        psk: &PSK,
+        psk_id: PSK_ID,
+    };
+    let (enc, mut sender) =
+        hpke_setup_sender_mlkem1024(suite, psk, kp.public_key(), INFO, [0x22; 32]).unwrap();
+    let ct_len = hpke_seal(&mut sender, AAD[0], PLAINTEXT, &mut ct).unwrap();
+    let mut wrong = hpke_setup_recipient_mlkem1024(
+        suite,
+        HpkeMode::Psk {
+            psk: &[0x55; 32],
+            psk_id: PSK_ID,
+        },
+        kp.private_key(),
+        &enc,
+        INFO,
+    )
+    .unwrap();
+    assert!(hpke_open(&mut wrong, AAD[0], &ct[..ct_len], &mut pt).is_err());
+    let mut right =
+        hpke_setup_recipient_mlkem1024(suite, psk, kp.private_key(), &enc, INFO).unwrap();
+    let len = hpke_open(&mut right, AAD[0], &ct[..ct_len], &mut pt).unwrap();
+    assert_eq!(&pt[..len], PLAINTEXT);
+
+    #[cfg(feature = "xwing")]
+    xwing_vector(suite);
+
+    // Approved-mode gating applies to the context operations too
+    reset_fips_state();
+    assert_eq!(
+        hpke_seal(&mut sender, AAD[1], PLAINTEXT, &mut ct).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+    run_post_or_panic();
+}
+
+#[cfg(feature = "xwing")]
+fn xwing_vector(suite: HpkeSuite) {
+    use pqc_nostd::{
+        hpke_setup_recipient_xwing, hpke_setup_sender_xwing, xwing_generate_key_pair,
+        XWingCiphertext, XWingPublicKey,
+    };
+
+    let kp = xwing_generate_key_pair(seed(0x40, 32).try_into().unwrap()).unwrap();
+    let enc = XWingCiphertext::try_from(&from_hex(XWING_ENC)[..]).unwrap();
+    let mut ctx =
+        hpke_setup_recipient_xwing(suite, HpkeMode::Base, &kp.private_key, &enc, INFO).unwrap();
+    let mut pt = [0u8; 64];
+    let len = hpke_open(&mut ctx, AAD[0], &from_hex(XWING_CT0), &mut pt).unwrap();
+    assert_eq!(&pt[..len], PLAINTEXT);
+    let mut exported = [0u8; 32];
+    hpke_recipient_export(&ctx, EXPORTER_CONTEXT, &mut exported).unwrap();
+    assert_eq!(&exported[..], &from_hex(XWING_EXPORT)[..]);
+
+    // Recipient side against a ciphertext sealed by pyca/cryptography
+    let pyca_suite = HpkeSuite {
+        kdf: HpkeKdf::HkdfSha384,
+        aead: HpkeAead::Aes256Gcm,
+    };
+    let pyca_enc = XWingCiphertext::try_from(&from_hex(PYCA_XWING_ENC)[..]).unwrap();
+    let mut ctx =
+        hpke_setup_recipient_xwing(pyca_suite, HpkeMode::Base, &kp.private_key, &pyca_enc, INFO)
+            .unwrap();
+    let len = hpke_open(&mut ctx, AAD[0], &from_hex(PYCA_XWING_CT0), &mut pt).unwrap();
+    assert_eq!(&pt[..len], PLAINTEXT);
+
+    // Sender round trip
+    let (enc, mut sender) =
+        hpke_setup_sender_xwing(suite, HpkeMode::Base, &kp.public_key, INFO, [0x33; 64]).unwrap();
+    let mut recipient =
+        hpke_setup_recipient_xwing(suite, HpkeMode::Base, &kp.private_key, &enc, INFO).unwrap();
+    let mut ct = [0u8; 64];
+    let ct_len = hpke_seal(&mut sender, AAD[0], PLAINTEXT, &mut ct).unwrap();
+    let len = hpke_open(&mut recipient, AAD[0], &ct[..ct_len], &mut pt).unwrap();
+    assert_eq!(&pt[..len], PLAINTEXT);
+
+    // The ML-KEM-768 part of the public key must pass the FIPS 203 check
+    let mut bad_pk = [0u8; XWingPublicKey::SIZE];
+    bad_pk.copy_from_slice(kp.public_key.as_slice());
+    bad_pk[0] = 0xff;
+    bad_pk[1] = 0xff;
+    let bad_pk = XWingPublicKey::new(bad_pk);
+    assert!(matches!(
+        hpke_setup_sender_xwing(suite, HpkeMode::Base, &bad_pk, INFO, [0x33; 64]),
+        Err(PqcError::InvalidParameter)
+    ));
+}
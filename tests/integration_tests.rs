@@ -2,7 +2,9 @@
 // Minimal integration test required for FIPS 140-3 submission
 // Proves: POST passes → module enters Operational → approved algorithms work
 
-use pqc_nostd::auth::{login, Role};
+mod common;
+
+use pqc_nostd::auth::Role;
 use pqc_nostd::{
     dilithium_generate_key_pair, is_operational, kyber_generate_key_pair, run_post_or_panic,
     FIPS_CONTEXT,
@@ -17,7 +19,7 @@ fn fips_module_becomes_operational_and_algorithms_work() {
     );
 
     // Level 2: Must login before operations
-    login(Role::User, b"user123").expect("Login failed");
+    common::login(Role::User).expect("Login failed");
 
     // ML-KEM-1024 basic round-trip (fixed seeds – deterministic)
     let kyber_kp = kyber_generate_key_pair([0x11u8; 64]).unwrap();
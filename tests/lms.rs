@@ -0,0 +1,119 @@
+// tests/lms.rs
+// HSS verification of a two-level LMS_SHA256_M32_H5 / LMOTS_SHA256_N32_W8 key
+
+#![cfg(all(feature = "lms", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::{hss_verify, lms_verify, run_post_or_panic, PqcError};
+
+// HSS public key (L = 2) and signature over MSG, generated with an
+// independent RFC 8554 implementation.
+const MSG: &[u8] = b"secure boot image";
+const PK_HEX: &str = "\
+    000000020000000500000004d08fabd4a2091ff0a8cb4ed834e745348b9a583d74439771b52302706a4463e3e342a868\
+    bcb02f698f6316b6d92279f8";
+const SIG_HEX: &str = "\
+    00000001000000000000000471aaf016142e016ce6de46bf917393f239411302d761c8453cb9cd96f1240aff639c060b\
+    65e78a717d7dc157bee3ec1c2a96e6b41c43445d11d596411e2cead607bc57c473d802b5a20d984c58217f3537fcc8cc\
+    2f6000a5752016e54058eeb511d766e2e8aa83a0fae2075dc6b1280b35ad006bb7b9e6036db1a265dbc564ed6d9bdbf0\
+    a0931a36335f7d8441bc2378442a3363f6bdbdd3adbab4b42e3acc41b1e6aadd0606e2ff7c6faf30ed7067c28907a7b8\
+    9459818929f6428541c95558eb0d0587fd2b4e8c161b327ae037118dc034bc458b15c343cfee9fb64d9067f023d1d58b\
+    4af12b90eb522051e4260359f16c3ba8eba67a667aa54b715f423dc3e1f855342e43c6a2534cb1860eb7d3942a09c9ec\
+    815ee5afcac0849b8ac213050fb941a6e96158f8c48971e79c0d5b65b2e622f02470cff7e3bd6b2e429ec2b6b6d26156\
+    a251a6701f03eae5acf63b5720e3ab6a3c34994774a47fc1aa9670c612009cad99ee3b94eee56adc09d34764656e18d0\
+    bebca0beef7ae63d5660f4b6fa28ba7c5294a2910d1081abe758e62da9bf88c159158c0347d001c67c072c2b39392f67\
+    c32a3a73bfcf1e325f5dc251c8b0fce542624d7bc220d999f99480fe6f14f43800c4d16923edbd653956f7849c2807cd\
+    046983219e326fa67a8603ef12d6718ffd0f178f60f21f883799210f01ed282e41073ef042c15cda541a2c1ef5d878f7\
+    216a4e5f3b16df0ed43779a729c76a883f8769b76f54a5e8993003bd6416777f8378ec25d5c0754e6ed29ea07e1fe7c7\
+    5f2a1ddaab9ae99b9574d54b93912a2f59bcf23430826b9403f44e7924e77c10b6d6d921e808587e27dc80103590fd17\
+    13fc0c935bb3ed51b6993603b278121fe3b42657b413be64024d4cc2b483f3c7a107a6b58fe7e4aec5addc976c1f6739\
+    f4f066b2df81ff75a7703dc3b935c547cc59b674532f317d849ad43f92303398dc74aa99b81f7a22c01eed4e5ecd8ee3\
+    4c40d101912a7d6a9f12bc2444b485d555ec0cf67595939e682014b36bf4ae89bd118327ac05d24d71f343a8dd33a079\
+    d7b6e01563572d58ad4723dc8a67634dd6691b977b57a06322f5ccff0e8d9a20a094ea43ad155fe6e892f6cbe3cee2ff\
+    0993caaf6fcac77e2c13a7f2d511e6741985cc8b9c808d0e997a4c73474d50cee722838001bab87c20398c74b71c3a30\
+    9c2463c394e7062ae9572434c1b39c4d9254a36f6b70dbe947408176776a04620769858858891a3d3764ba5fa2de5d53\
+    aadfaffa17c31735926bf902c261b76494ca5f9cc2bec61c2cf6cb2842c2f20e6898dc7918c2bbfc8b50c6d89c42235f\
+    ee87421e822fb52a7fbe46fb0376715fdbdbe6ec031de5db720f4bca54e668704805a9e4086ada5df12627a2aa6872f9\
+    fdf38b5fdca31b41edd6a38e6315754a3c748a2665916adec85a1c387009c53ad0667e269be79daca2f7fda7ec16672a\
+    11f4bfb25d3b395e52fbc70709beedb2c9dcd6a3929fe995b29e64633ff6671ff3fe6a85b7fb8d30f055abde758a9f1c\
+    71f9a169a0fb7c693dc70714aee1a07770a10c4a43fa329483cfe464000000058f10e6d54680f6e1bcbf5dbcb9c1d162\
+    8323b71c7247d6536858cb87dd52628955d38bb6d66d7f3c300e73b6dae29c30f474426590645ab69f7d317878ad0efb\
+    ba2c6dc673188e75420b6b08350c8d269988a9cb106f5a70ef38f127b89e6addfd9aab02995e67e2ca5b8129a635b942\
+    9e7fb1606931047509a4240c425ac412fa4f51344dceef63bfd8d70fc2874f740ba8f9e77485958ddafb03c9f76a9580\
+    0000000500000004cc79ab2d045fafd3d9345c1d2c4aeb555fb2386997f70fd80cdfdab127d84f5ea4c430e5a642c984\
+    8b9dc8daad956d1c000000000000000471aaf016142e016ce6de46bf917393f239411302d761c8453cb9cd96f1240aff\
+    fb1c2feba31b66664f8e62745aeb9d572e878ebcf6e5efa2010db69ad99181afb1acde26b599b3f5abf43fbb77e2ed95\
+    c9af270512b0d5968f421d625d36e467a9f72a65227c385a9d5c1f3841f24000160b7784ad5404663d5a0feb47a74a97\
+    78635e02073402222ca2cf312a3f2b275a8d0d7a2423d7e98865e84d4336c2004ab3c846a59c755296ba1d12af72b9f9\
+    2a10d85ad96aa6cd018ebfd0ee5e3a58154116b572607e779b4ae53deb1aca7420b5e6f800ba72ffb016ac969ea9e568\
+    d40957d64455b2dd62a5d9dd1eb4008d29d1d7c9da1484ed6e8fe051e3bb242481e91ec6dc29148ac92dc2ad6a5284ed\
+    d16fc4b49436f42d673f285cdcc8fc558ead37d17e7efb67d5f8b4b0bbe78d283880d6a4e3c1f7596da63f7cd5383abe\
+    802cdf35255b532bc5a14d60f4cf9e87a2646dccd3fec9f7cb43254ff0502a226bc6b17237e7d872d1792019642dc163\
+    bad53fc3bb80ca9a39f2e3f7eefcb97773b7fbc8d8129f8c2317cafaa37224fd1752d5baba95e7325e35d411ce48366e\
+    f4770f930edbae6b3ab5f211e4919cf6e15b615f9a955d335e2b1777887e74f37af4a4a28c7180fac907f7c5a8149853\
+    ffeba14d414e952c50a1f12f890b880200619fa3d424c3e297c665a8cf6788f590fe07587714166b628c1682340cdce6\
+    6991314f48aac286ba3584e3002d75010314424bbea270dce9086f3a2d068d8af76a697d2663afec1157a385c43ee459\
+    ee64fff57066b7bcc6ec1c3adee4d69af093f6338aad0884c5586568fa8d92db9ddddcbafc57b3d43a2ce9b042a3d0e5\
+    e40e4318bd72eed0c754f9523d86579ebe511fc07d95d4f48fde74e77499b8688a3c5c61278bdda1b8df8b7e5565799d\
+    6e8604b7a19ce23bf4bbeb40c2a36379b455dd68f7695cebc97ae54998bd4438bf7f0d5da109a9a4969b94b54026d0af\
+    f5ff75e7e3c024f6fc00bdb374d637b2d292f0b4d213b94579ac652e2cecda0a454adcbd46ffefc83d276c4597400ae7\
+    fa2e02402463c9b460f905adcb17311325933cce228af9140f03b65e1fda613c61083240d7cc8e5cbd6d202f4f8dac52\
+    89f93f9043bffd807195408faa5527652baac877cb622b54db4a4374bfe6df9503bf79dfcb4f1eb836c9423ec467fcf0\
+    9b2e0c364f3794a15321c77bc7d68c5428ce1ec0b1dae68d4659fcbe63ab21fbba1c7a3955ae28830f2a4b231ba3cf8a\
+    a612624a41c823d2d572c64785391785e07ad8a74bc58bf589942b6e99bf6f57814fc2e0417ddaead81b4cb68b0e5d4b\
+    7d7460b58c58f2e3a30e7ee89390f3c7fb9f3023e455b8a73cdb5ed1a180e62f07adabee1158ce8f8c8134ca82e24a6c\
+    040fc72a9cd19d1b712ad8b8b296029f12fa8e5caaeba25c5e1c8ab41c692a0b0328f88c4ea6cde76b509f9dae23b1e9\
+    f7f4e3bec521ae8abb0e1a8219380c6f8ea3b09098f26f9d61865ea734e9063923535b61fe7a8f57e528b30050191817\
+    4538ec235735bb654e8c1ceac4634af0836a9e38be5230a821e0aaa680bbfc0f000000058a450062efb6f3bbbb7cc990\
+    9c6e4704ea611f077d8412a24bffe244d0d661ce971bbe3eb7d14a23bf578996cce1a0d35178b290b02e08abde525e6f\
+    086aaba71f5910ae56f698198c22dddbe085f5ed9e34eec4cd14c5b4d98ddf77ffc5d6666e0b6c3b22db01b5acc7aeff\
+    e83bdd39f74517de68bc0761d3a8d91c5f0dd45ca3e7485bfc23bb98e567ce8c69cb09be68b2d84a4177b03f7136d269\
+    7f80fd0b";
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn hss_verify_two_levels() {
+    run_post_or_panic(); // Includes the RFC 8554 LMS/HSS KAT
+    common::login(Role::User).expect("Login failed");
+    let pk = from_hex(PK_HEX);
+    let sig = from_hex(SIG_HEX);
+
+    assert!(hss_verify(&pk, MSG, &sig).is_ok());
+    assert!(hss_verify(&pk, b"tampered image", &sig).is_err());
+
+    // Flipping a bit in the top-level signature or the signed key fails
+    for pos in [100, 1300, sig.len() - 1] {
+        let mut bad = sig.clone();
+        bad[pos] ^= 0x01;
+        assert!(hss_verify(&pk, MSG, &bad).is_err());
+    }
+
+    // The bottom-level LMS signature verifies under the signed LMS key
+    let lms_sig_len = (sig.len() - 4 - 56) / 2;
+    let signed_key = &sig[4 + lms_sig_len..4 + lms_sig_len + 56];
+    assert!(lms_verify(signed_key, MSG, &sig[4 + lms_sig_len + 56..]).is_ok());
+
+    // Malformed encodings are rejected as invalid parameters
+    assert_eq!(
+        hss_verify(&pk, MSG, &sig[..sig.len() - 1]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut wrong_levels = pk.clone();
+    wrong_levels[3] = 1;
+    assert_eq!(
+        hss_verify(&wrong_levels, MSG, &sig).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    assert_eq!(
+        lms_verify(&pk[4..], MSG, &[0u8; 8]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+}
@@ -0,0 +1,265 @@
+// tests/login_lockout.rs
+// Failed login counting, back-off and Crypto Officer unlock
+
+#![cfg(feature = "fips_140_3")]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::policy::{LockoutPolicy, MAX_BACKOFF_SHIFT};
+use pqc_nostd::{FipsModule, PqcError};
+
+#[test]
+fn failed_logins_lock_the_role() {
+    let module = common::module();
+    module.run_post().expect("POST failed");
+    assert_eq!(module.lockout_policy(), LockoutPolicy::default());
+
+    // Failures count per role and a successful login clears them
+    for expected in 1..=3 {
+        assert_eq!(
+            module.login(Role::User, b"wrong"),
+            Err(PqcError::AuthenticationFailure)
+        );
+        assert_eq!(module.failed_logins(Role::User), expected);
+    }
+    assert_eq!(module.failed_logins(Role::CryptoOfficer), 0);
+    // Past the back-off threshold the correct password works once the
+    // delay has passed
+    module.update_clock(1000);
+    module
+        .login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+    assert_eq!(module.failed_logins(Role::User), 0);
+
+    // The policy is a Crypto Officer service with validated limits
+    let policy = LockoutPolicy {
+        lockout_threshold: 3,
+        backoff_threshold: 1,
+        backoff_delay_ms: 0,
+        max_backoff_shift: 2,
+    };
+    assert_eq!(
+        module.set_lockout_policy(policy),
+        Err(PqcError::AuthenticationFailure)
+    );
+    module
+        .login(Role::CryptoOfficer, common::CO_PASSWORD)
+        .expect("Login failed");
+    for invalid in [
+        LockoutPolicy {
+            lockout_threshold: 0,
+            ..policy
+        },
+        LockoutPolicy {
+            max_backoff_shift: MAX_BACKOFF_SHIFT + 1,
+            ..policy
+        },
+    ] {
+        assert_eq!(
+            module.set_lockout_policy(invalid),
+            Err(PqcError::InvalidParameter)
+        );
+    }
+    module.set_lockout_policy(policy).unwrap();
+    assert_eq!(module.lockout_policy(), policy);
+
+    // Reaching the threshold locks the role, even for the right password
+    for _ in 0..3 {
+        assert_eq!(
+            module.login(Role::User, b"wrong"),
+            Err(PqcError::AuthenticationFailure)
+        );
+    }
+    assert!(module.is_locked(Role::User));
+    assert!(!module.is_locked(Role::CryptoOfficer));
+    assert_eq!(
+        module.login(Role::User, common::USER_PASSWORD),
+        Err(PqcError::AuthenticationLocked)
+    );
+    assert_eq!(module.failed_logins(Role::User), 3);
+
+    // Only the Crypto Officer can unlock it
+    module.check_authority(Role::CryptoOfficer).unwrap();
+    module.unlock(Role::User).unwrap();
+    assert!(!module.is_locked(Role::User));
+    module
+        .login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+    assert_eq!(
+        module.unlock(Role::User),
+        Err(PqcError::AuthenticationFailure)
+    );
+
+    // A locked-out Crypto Officer stays locked in this module
+    for _ in 0..3 {
+        assert!(module.login(Role::CryptoOfficer, b"wrong").is_err());
+    }
+    assert_eq!(
+        module.login(Role::CryptoOfficer, common::CO_PASSWORD),
+        Err(PqcError::AuthenticationLocked)
+    );
+    // The User keeps working, and other modules are unaffected
+    module.kyber_generate_key_pair([0x01; 64]).unwrap();
+    let other = common::module();
+    other
+        .login(Role::CryptoOfficer, common::CO_PASSWORD)
+        .expect("Login failed");
+    assert_eq!(FipsModule::global().failed_logins(Role::CryptoOfficer), 0);
+
+    // Lowering the threshold locks a role at once; raising it lifts the lock
+    let module = common::module();
+    module.run_post().expect("POST failed");
+    for _ in 0..3 {
+        assert!(module.login(Role::User, b"wrong").is_err());
+    }
+    module
+        .login(Role::CryptoOfficer, common::CO_PASSWORD)
+        .expect("Login failed");
+    module
+        .set_lockout_policy(LockoutPolicy {
+            lockout_threshold: 2,
+            ..LockoutPolicy::default()
+        })
+        .unwrap();
+    assert!(module.is_locked(Role::User));
+    module.set_lockout_policy(LockoutPolicy::default()).unwrap();
+    assert!(!module.is_locked(Role::User));
+    module.update_clock(1000);
+    module
+        .login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+}
+
+#[test]
+fn backoff_delays_attempts_on_the_module_clock() {
+    let module = common::module();
+    module.run_post().expect("POST failed");
+    module
+        .login(Role::CryptoOfficer, common::CO_PASSWORD)
+        .expect("Login failed");
+    module
+        .set_lockout_policy(LockoutPolicy {
+            lockout_threshold: 10,
+            backoff_threshold: 2,
+            backoff_delay_ms: 500,
+            max_backoff_shift: 2,
+        })
+        .unwrap();
+
+    // No delay before the back-off threshold
+    module.update_clock(10_000);
+    for _ in 0..2 {
+        assert_eq!(
+            module.login(Role::User, b"wrong"),
+            Err(PqcError::AuthenticationFailure)
+        );
+    }
+
+    // Then each failure doubles the delay, up to the maximum shift. Attempts
+    // inside the delay are refused without counting as failures, even with
+    // the right password.
+    let mut now = 10_000;
+    for delay in [500, 1000, 2000, 2000] {
+        assert_eq!(
+            module.login(Role::User, common::USER_PASSWORD),
+            Err(PqcError::AuthenticationLocked)
+        );
+        module.update_clock(now + delay - 1);
+        assert_eq!(
+            module.login(Role::User, b"wrong"),
+            Err(PqcError::AuthenticationLocked)
+        );
+        now += delay;
+        module.update_clock(now);
+        assert_eq!(
+            module.login(Role::User, b"wrong"),
+            Err(PqcError::AuthenticationFailure)
+        );
+    }
+    assert_eq!(module.failed_logins(Role::User), 6);
+
+    // Concurrent attempts get one slot per delay
+    module.update_clock(now + 2000);
+    let results: Vec<Result<(), PqcError>> = std::thread::scope(|s| {
+        let threads: Vec<_> = (0..8)
+            .map(|_| s.spawn(|| module.login(Role::User, b"wrong")))
+            .collect();
+        threads.into_iter().map(|t| t.join().unwrap()).collect()
+    });
+    assert_eq!(
+        results
+            .iter()
+            .filter(|r| **r == Err(PqcError::AuthenticationFailure))
+            .count(),
+        1
+    );
+    assert_eq!(module.failed_logins(Role::User), 7);
+
+    // The clock never goes backwards, and other roles are not delayed
+    module.update_clock(0);
+    assert_eq!(
+        module.login(Role::User, common::USER_PASSWORD),
+        Err(PqcError::AuthenticationLocked)
+    );
+    module
+        .login(Role::CryptoOfficer, common::CO_PASSWORD)
+        .expect("Login failed");
+
+    // Unlocking clears the delay along with the count
+    module.unlock(Role::User).unwrap();
+    module
+        .login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+    assert_eq!(module.failed_logins(Role::User), 0);
+}
+
+#[test]
+fn concurrent_failed_logins_stop_at_the_threshold() {
+    let module = common::module();
+    module.run_post().expect("POST failed");
+    module
+        .login(Role::CryptoOfficer, common::CO_PASSWORD)
+        .expect("Login failed");
+    module
+        .set_lockout_policy(LockoutPolicy {
+            lockout_threshold: 4,
+            backoff_threshold: 4,
+            backoff_delay_ms: 0,
+            max_backoff_shift: 0,
+        })
+        .unwrap();
+
+    // Every thread sees the same count at first, but each attempt is
+    // reserved before its password check runs
+    let results: Vec<Result<(), PqcError>> = std::thread::scope(|s| {
+        let threads: Vec<_> = (0..16)
+            .map(|_| {
+                s.spawn(|| {
+                    (0..4)
+                        .map(|_| module.login(Role::User, b"wrong"))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        threads
+            .into_iter()
+            .flat_map(|t| t.join().unwrap())
+            .collect()
+    });
+    let failures = results
+        .iter()
+        .filter(|r| **r == Err(PqcError::AuthenticationFailure))
+        .count();
+    let locked = results
+        .iter()
+        .filter(|r| **r == Err(PqcError::AuthenticationLocked))
+        .count();
+    assert_eq!(failures, 4);
+    assert_eq!(locked, results.len() - 4);
+    assert_eq!(module.failed_logins(Role::User), 4);
+    assert_eq!(
+        module.login(Role::User, common::USER_PASSWORD),
+        Err(PqcError::AuthenticationLocked)
+    );
+}
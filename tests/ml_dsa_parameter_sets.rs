@@ -0,0 +1,32 @@
+// tests/ml_dsa_parameter_sets.rs
+// ML-DSA-44 / ML-DSA-87 authenticated sign/verify alongside ML-DSA-65
+
+#![cfg(all(feature = "ml-dsa-44", feature = "ml-dsa-87"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::{
+    dilithium44_generate_key_pair, dilithium44_sign, dilithium44_verify,
+    dilithium87_generate_key_pair, dilithium87_sign, dilithium87_verify, run_post_or_panic,
+    FIPS_CONTEXT, ML_DSA_44_SIG_BYTES, ML_DSA_87_SIG_BYTES,
+};
+
+#[test]
+fn ml_dsa_44_and_87_sign_verify() {
+    run_post_or_panic(); // Includes the per-parameter-set KATs and PCTs
+    common::login(Role::User).expect("Login failed");
+    let msg = b"firmware image";
+
+    let kp = dilithium44_generate_key_pair([0x33u8; 32]).unwrap();
+    let sig = dilithium44_sign(&kp.signing_key, msg, FIPS_CONTEXT, [0x44u8; 32]).unwrap();
+    assert_eq!(sig.as_ref().len(), ML_DSA_44_SIG_BYTES);
+    assert!(dilithium44_verify(&kp.verification_key, msg, FIPS_CONTEXT, &sig).is_ok());
+    assert!(dilithium44_verify(&kp.verification_key, b"tampered", FIPS_CONTEXT, &sig).is_err());
+
+    let kp = dilithium87_generate_key_pair([0x33u8; 32]).unwrap();
+    let sig = dilithium87_sign(&kp.signing_key, msg, FIPS_CONTEXT, [0x44u8; 32]).unwrap();
+    assert_eq!(sig.as_ref().len(), ML_DSA_87_SIG_BYTES);
+    assert!(dilithium87_verify(&kp.verification_key, msg, FIPS_CONTEXT, &sig).is_ok());
+    assert!(dilithium87_verify(&kp.verification_key, b"tampered", FIPS_CONTEXT, &sig).is_err());
+}
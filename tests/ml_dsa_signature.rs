@@ -0,0 +1,78 @@
+// tests/ml_dsa_signature.rs
+// RustCrypto `signature` traits on the ML-DSA-65 wrapper types
+
+#![cfg(all(feature = "signature", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::policy::set_deterministic_signing;
+use pqc_nostd::{
+    dilithium_generate_key_pair, dilithium_sign_deterministic, dilithium_verify, run_post_or_panic,
+    MlDsa65Signature, MlDsa65SigningKey, MlDsa65VerifyingKey, FIPS_CONTEXT, ML_DSA_65_SIG_BYTES,
+};
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use signature::{Keypair, RandomizedSigner, SignatureEncoding, Signer, Verifier};
+
+/// Signs and verifies through the traits only, as a generic X.509 or JWT
+/// stack would.
+fn sign_then_verify<K, S>(key: &K, msg: &[u8]) -> S
+where
+    K: RandomizedSigner<S> + Keypair,
+    K::VerifyingKey: Verifier<S>,
+{
+    let sig = key.sign_with_rng(&mut ChaCha20Rng::from_seed([0x09; 32]), msg);
+    assert!(key.verifying_key().verify(msg, &sig).is_ok());
+    sig
+}
+
+/// Signs through `Signer` only, as a stack generic over it would.
+fn sign_with<S: Signer<MlDsa65Signature>>(
+    signer: &S,
+    msg: &[u8],
+) -> Result<MlDsa65Signature, signature::Error> {
+    signer.try_sign(msg)
+}
+
+#[test]
+fn signature_traits_for_ml_dsa_65() {
+    run_post_or_panic();
+    common::login(Role::User).expect("Login failed");
+    let kp = dilithium_generate_key_pair([0x5e; 32]).unwrap();
+    let vk_bytes = kp.verification_key.as_slice().to_vec();
+    let key = MlDsa65SigningKey::from_keypair(kp);
+    let msg = b"tbsCertificate";
+
+    // Hedged signatures verify through both the wrapper and the core API
+    let sig: MlDsa65Signature = sign_then_verify(&key, msg);
+    let vk = key.verifying_key();
+    assert_eq!(vk.as_ref(), &vk_bytes[..]);
+    assert!(dilithium_verify(vk.verifying_key(), msg, FIPS_CONTEXT, sig.signature()).is_ok());
+    assert!(vk.verify(b"other message", &sig).is_err());
+
+    // Encoding round trip
+    let bytes = sig.to_bytes();
+    assert_eq!(bytes.len(), ML_DSA_65_SIG_BYTES);
+    assert_eq!(sig.encoded_len(), ML_DSA_65_SIG_BYTES);
+    let decoded = MlDsa65Signature::try_from(&bytes[..]).unwrap();
+    assert!(vk.verify(msg, &decoded).is_ok());
+    assert!(MlDsa65Signature::try_from(&bytes[1..]).is_err());
+    let mut tampered = bytes;
+    tampered[10] ^= 0x01;
+    let tampered = MlDsa65Signature::try_from(&tampered[..]).unwrap();
+    assert!(vk.verify(msg, &tampered).is_err());
+    let parsed_vk = MlDsa65VerifyingKey::try_from(&vk_bytes[..]).unwrap();
+    assert!(parsed_vk.verify(msg, &sig).is_ok());
+    assert!(MlDsa65VerifyingKey::try_from(&vk_bytes[..100]).is_err());
+
+    // `Signer` is deterministic, so it follows the Crypto Officer policy
+    assert!(sign_with(&key, msg).is_err());
+    common::login(Role::CryptoOfficer).expect("CO login failed");
+    set_deterministic_signing(true).unwrap();
+    common::login(Role::User).expect("Login failed");
+    let det = sign_with(&key, msg).unwrap();
+    let expected = dilithium_sign_deterministic(key.signing_key(), msg, FIPS_CONTEXT).unwrap();
+    assert_eq!(det.to_bytes(), *expected.as_ref());
+    assert!(vk.verify(msg, &det).is_ok());
+}
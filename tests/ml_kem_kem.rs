@@ -0,0 +1,63 @@
+// tests/ml_kem_kem.rs
+// RustCrypto `kem` traits on the ML-KEM-1024 wrapper types
+
+#![cfg(all(feature = "kem", feature = "fips_140_3"))]
+
+mod common;
+
+use kem::{Decapsulate, Encapsulate};
+use pqc_nostd::auth::Role;
+use pqc_nostd::state::reset_fips_state;
+use pqc_nostd::{
+    decapsulate, kyber_generate_key_pair, run_post_or_panic, MlKem1024DecapsulationKey,
+    MlKem1024EncapsulationKey, PqcError,
+};
+use rand_chacha::rand_core::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+/// Runs one exchange through the traits only, as generic HPKE code would.
+fn exchange<EK, SS, E, D>(ek: &E, dk: &D, rng: &mut ChaCha20Rng) -> (EK, SS, SS)
+where
+    E: Encapsulate<EK, SS>,
+    D: Decapsulate<EK, SS>,
+{
+    let (ct, ss_sender) = ek.encapsulate(rng).unwrap();
+    let ss_receiver = dk.decapsulate(&ct).unwrap();
+    (ct, ss_sender, ss_receiver)
+}
+
+#[test]
+fn kem_traits_for_ml_kem_1024() {
+    run_post_or_panic();
+    common::login(Role::User).expect("Login failed");
+    let kp = kyber_generate_key_pair([0x2b; 64]).unwrap();
+    let pk_bytes = kp.public_key().as_ref().to_vec();
+    let (dk, ek) = MlKem1024DecapsulationKey::from_keypair(kp);
+    let mut rng = ChaCha20Rng::from_seed([0x0c; 32]);
+
+    let (ct, ss_sender, ss_receiver) = exchange(&ek, &dk, &mut rng);
+    assert_eq!(ss_sender, ss_receiver);
+    assert_eq!(decapsulate(dk.private_key(), &ct).unwrap(), ss_sender);
+
+    // Encapsulation keys parse from their encoding
+    let parsed = MlKem1024EncapsulationKey::try_from(&pk_bytes[..]).unwrap();
+    assert_eq!(parsed.as_ref(), ek.as_ref());
+    let (_, ss_parsed, ss_receiver) = exchange(&parsed, &dk, &mut rng);
+    assert_eq!(ss_parsed, ss_receiver);
+    assert_eq!(
+        MlKem1024EncapsulationKey::try_from(&pk_bytes[1..]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    // Approved-mode gating still applies through the traits
+    reset_fips_state();
+    assert_eq!(
+        ek.encapsulate(&mut rng).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+    assert_eq!(
+        dk.decapsulate(&ct).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+    run_post_or_panic();
+}
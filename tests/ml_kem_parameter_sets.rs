@@ -0,0 +1,33 @@
+// tests/ml_kem_parameter_sets.rs
+// ML-KEM-512 / ML-KEM-768 authenticated round-trips alongside ML-KEM-1024
+
+#![cfg(all(feature = "ml-kem-512", feature = "ml-kem-768"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::{
+    kyber512_decapsulate, kyber512_encapsulate, kyber512_generate_key_pair, kyber768_decapsulate,
+    kyber768_encapsulate, kyber768_generate_key_pair, run_post_or_panic, ML_KEM_512_CT_BYTES,
+    ML_KEM_512_PK_BYTES, ML_KEM_768_CT_BYTES, ML_KEM_768_PK_BYTES,
+};
+
+#[test]
+fn ml_kem_512_and_768_round_trip() {
+    run_post_or_panic(); // Includes the per-parameter-set KATs and PCTs
+    common::login(Role::User).expect("Login failed");
+
+    let kp = kyber768_generate_key_pair([0x11u8; 64]).unwrap();
+    assert_eq!(kp.public_key().as_ref().len(), ML_KEM_768_PK_BYTES);
+    let (ct, ss1) = kyber768_encapsulate(kp.public_key(), [0x22u8; 32]).unwrap();
+    assert_eq!(ct.as_ref().len(), ML_KEM_768_CT_BYTES);
+    let ss2 = kyber768_decapsulate(kp.private_key(), &ct).unwrap();
+    assert_eq!(ss1, ss2);
+
+    let kp = kyber512_generate_key_pair([0x11u8; 64]).unwrap();
+    assert_eq!(kp.public_key().as_ref().len(), ML_KEM_512_PK_BYTES);
+    let (ct, ss1) = kyber512_encapsulate(kp.public_key(), [0x22u8; 32]).unwrap();
+    assert_eq!(ct.as_ref().len(), ML_KEM_512_CT_BYTES);
+    let ss2 = kyber512_decapsulate(kp.private_key(), &ct).unwrap();
+    assert_eq!(ss1, ss2);
+}
@@ -0,0 +1,71 @@
+// tests/rand_core.rs
+// `_with_rng` key generation, encapsulation and signing services
+
+#![cfg(all(feature = "rand_core", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::{
+    decapsulate, dilithium_generate_key_pair, dilithium_generate_key_pair_with_rng,
+    dilithium_sign_with_rng, dilithium_verify, encapsulate_with_rng, kyber_generate_key_pair,
+    kyber_generate_key_pair_with_rng, run_post_or_panic, PqcError, FIPS_CONTEXT,
+};
+use rand_chacha::rand_core::{CryptoRng, Error, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+/// An RNG whose hardware backend has failed.
+struct BrokenRng;
+
+impl RngCore for BrokenRng {
+    fn next_u32(&mut self) -> u32 {
+        unimplemented!()
+    }
+    fn next_u64(&mut self) -> u64 {
+        unimplemented!()
+    }
+    fn fill_bytes(&mut self, _dest: &mut [u8]) {
+        unimplemented!()
+    }
+    fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Error> {
+        Err(Error::from(
+            core::num::NonZeroU32::new(Error::CUSTOM_START).unwrap(),
+        ))
+    }
+}
+
+impl CryptoRng for BrokenRng {}
+
+#[test]
+fn rng_backed_services() {
+    run_post_or_panic();
+    common::login(Role::User).expect("Login failed");
+
+    // The seed is the first 64 bytes of RNG output, so the seed-based
+    // version gives the same key pair
+    let mut rng = ChaCha20Rng::from_seed([0x07; 32]);
+    let kp = kyber_generate_key_pair_with_rng(&mut rng).unwrap();
+    let mut seed = [0u8; 64];
+    ChaCha20Rng::from_seed([0x07; 32]).fill_bytes(&mut seed);
+    let kp_seeded = kyber_generate_key_pair(seed).unwrap();
+    assert_eq!(kp.public_key().as_ref(), kp_seeded.public_key().as_ref());
+
+    let (ct, ss_alice) = encapsulate_with_rng(kp.public_key(), &mut rng).unwrap();
+    assert_eq!(ss_alice, decapsulate(kp.private_key(), &ct).unwrap());
+
+    let dil_kp = dilithium_generate_key_pair_with_rng(&mut rng).unwrap();
+    let msg = b"rng-backed signature";
+    let sig = dilithium_sign_with_rng(&dil_kp.signing_key, msg, FIPS_CONTEXT, &mut rng).unwrap();
+    assert!(dilithium_verify(&dil_kp.verification_key, msg, FIPS_CONTEXT, &sig).is_ok());
+
+    // RNG failures are reported instead of panicking
+    assert_eq!(
+        kyber_generate_key_pair_with_rng(&mut BrokenRng).err(),
+        Some(PqcError::PlatformError)
+    );
+    let other = dilithium_generate_key_pair([0x08; 32]).unwrap();
+    assert_eq!(
+        dilithium_sign_with_rng(&other.signing_key, msg, FIPS_CONTEXT, &mut BrokenRng).err(),
+        Some(PqcError::PlatformError)
+    );
+}
@@ -0,0 +1,222 @@
+// tests/service_gate.rs
+// Every service refuses to run unless the module is Operational, the required role is logged in
+// and the default passwords have been replaced
+
+#![cfg(feature = "fips_140_3")]
+
+mod common;
+
+use core::cell::RefCell;
+use pqc_nostd::auth::Role;
+use pqc_nostd::{
+    dilithium_generate_key_pair_internal, dilithium_sign_internal, kyber_encapsulate_internal,
+    kyber_generate_key_pair_internal, DilithiumKeypair, DilithiumSignature, EntropySource,
+    FipsModule, FipsState, HmacDrbg, KyberCiphertext, KyberKeypair, NoiseSource, PqcError, Result,
+    FIPS_CONTEXT,
+};
+
+/// Keys and inputs made with the unauthenticated functions.
+struct Inputs {
+    kyber: KyberKeypair,
+    ct: KyberCiphertext,
+    dilithium: DilithiumKeypair,
+    sig: DilithiumSignature,
+}
+
+fn inputs() -> Inputs {
+    let kyber = kyber_generate_key_pair_internal([0x01; 64]);
+    let (ct, _) = kyber_encapsulate_internal(kyber.public_key(), [0x02; 32]);
+    let dilithium = dilithium_generate_key_pair_internal([0x03; 32]);
+    let sig =
+        dilithium_sign_internal(&dilithium.signing_key, b"msg", FIPS_CONTEXT, [0x04; 32]).unwrap();
+    Inputs {
+        kyber,
+        ct,
+        dilithium,
+        sig,
+    }
+}
+
+/// Name, required role and result of each service.
+type Results = Vec<(&'static str, Role, Result<()>)>;
+
+fn ok<T>(result: Result<T>) -> Result<()> {
+    result.map(|_| ())
+}
+
+/// Runs one service of each kind on `module`.
+fn run_services(module: &FipsModule, inputs: &Inputs) -> Results {
+    let mu = || -> Result<()> {
+        let mu = module.dilithium_compute_mu(&inputs.dilithium.verification_key, b"msg", b"")?;
+        module.dilithium_sign_mu(&inputs.dilithium.signing_key, &mu, [0x04; 32])?;
+        Ok(())
+    };
+    let drbg = || -> Result<()> {
+        let mut drbg: HmacDrbg = module.drbg_instantiate(&[0x05; 32], &[0x06; 16], &[])?;
+        module.kyber_generate_key_pair_with_drbg(&mut drbg)?;
+        Ok(())
+    };
+    vec![
+        (
+            "kyber_generate_key_pair",
+            Role::User,
+            ok(module.kyber_generate_key_pair([0x01; 64])),
+        ),
+        (
+            "encapsulate",
+            Role::User,
+            ok(module.encapsulate(inputs.kyber.public_key(), [0x02; 32])),
+        ),
+        (
+            "decapsulate",
+            Role::User,
+            ok(module.decapsulate(inputs.kyber.private_key(), &inputs.ct)),
+        ),
+        (
+            "dilithium_generate_key_pair",
+            Role::User,
+            ok(module.dilithium_generate_key_pair([0x03; 32])),
+        ),
+        (
+            "dilithium_sign",
+            Role::User,
+            ok(module.dilithium_sign(
+                &inputs.dilithium.signing_key,
+                b"msg",
+                FIPS_CONTEXT,
+                [0x04; 32],
+            )),
+        ),
+        (
+            "dilithium_verify",
+            Role::User,
+            module.dilithium_verify(
+                &inputs.dilithium.verification_key,
+                b"msg",
+                FIPS_CONTEXT,
+                &inputs.sig,
+            ),
+        ),
+        ("dilithium_*_mu", Role::User, mu()),
+        (
+            "dilithium_signer",
+            Role::User,
+            ok(module.dilithium_signer(&inputs.dilithium.signing_key, FIPS_CONTEXT)),
+        ),
+        ("drbg_*", Role::User, drbg()),
+        (
+            "set_deterministic_signing",
+            Role::CryptoOfficer,
+            module.set_deterministic_signing(false),
+        ),
+    ]
+}
+
+/// The result the gate must give in `state` with `role` logged in.
+fn expected(state: FipsState, role: Option<Role>, required: Role, provisioned: bool) -> Result<()> {
+    match state {
+        FipsState::Uninitialized => Err(PqcError::FipsNotInitialized),
+        FipsState::POST => Err(PqcError::FipsPostInProgress),
+        FipsState::Error => Err(PqcError::FipsErrorState),
+        FipsState::Operational if role != Some(required) => Err(PqcError::AuthenticationFailure),
+        FipsState::Operational if !provisioned => Err(PqcError::DefaultCredentials),
+        FipsState::Operational => Ok(()),
+    }
+}
+
+/// Xorshift noise that turns stuck after `healthy` samples and, on its
+/// first sample, runs the services on `probe` while POST is in progress.
+struct TestNoise<'a> {
+    state: u32,
+    healthy: usize,
+    probe: Option<(&'a FipsModule, &'a Inputs, &'a RefCell<Results>)>,
+}
+
+impl NoiseSource for TestNoise<'_> {
+    fn sample(&mut self) -> u8 {
+        if let Some((module, inputs, results)) = self.probe.take() {
+            assert_eq!(module.state(), FipsState::POST);
+            *results.borrow_mut() = run_services(module, inputs);
+        }
+        if self.healthy == 0 {
+            return 0x5a;
+        }
+        self.healthy -= 1;
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 17;
+        self.state ^= self.state << 5;
+        (self.state >> 24) as u8
+    }
+}
+
+#[test]
+fn every_state_and_role_combination() {
+    let inputs = inputs();
+    let states = [
+        FipsState::Uninitialized,
+        FipsState::POST,
+        FipsState::Operational,
+        FipsState::Error,
+    ];
+    let roles = [None, Some(Role::User), Some(Role::CryptoOfficer)];
+
+    for state in states {
+        for role in roles {
+            for provisioned in [true, false] {
+                let module = if provisioned {
+                    common::module()
+                } else {
+                    FipsModule::new()
+                };
+                if let Some(role) = role {
+                    let password = match (provisioned, role) {
+                        (true, role) => common::password(role),
+                        (false, Role::User) => b"user123",
+                        (false, Role::CryptoOfficer) => b"admin456",
+                    };
+                    module.login(role, password).expect("Login failed");
+                }
+                let results = match state {
+                    FipsState::Uninitialized => run_services(&module, &inputs),
+                    FipsState::POST => {
+                        let results = RefCell::new(Vec::new());
+                        let noise = TestNoise {
+                            state: 0x1234_5678,
+                            healthy: usize::MAX,
+                            probe: Some((&module, &inputs, &results)),
+                        };
+                        let mut source = EntropySource::new(noise, 6).unwrap();
+                        module
+                            .run_post_with_entropy(&mut source)
+                            .expect("POST failed");
+                        results.into_inner()
+                    }
+                    FipsState::Operational => {
+                        module.run_post().expect("POST failed");
+                        run_services(&module, &inputs)
+                    }
+                    FipsState::Error => {
+                        let noise = TestNoise {
+                            state: 0x1234_5678,
+                            healthy: 0,
+                            probe: None,
+                        };
+                        let mut stuck = EntropySource::new(noise, 6).unwrap();
+                        assert!(module.run_post_with_entropy(&mut stuck).is_err());
+                        run_services(&module, &inputs)
+                    }
+                };
+                if state != FipsState::POST {
+                    assert_eq!(module.state(), state);
+                }
+                for (name, required, result) in results {
+                    assert_eq!(
+                        result,
+                        expected(state, role, required, provisioned),
+                        "{name} in {state:?} with {role:?} logged in, provisioned: {provisioned}"
+                    );
+                }
+            }
+        }
+    }
+}
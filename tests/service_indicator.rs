@@ -0,0 +1,228 @@
+// tests/service_indicator.rs
+// FIPS 140-3 approved service indicator (IG 2.4.C)
+
+#![cfg(feature = "fips_140_3")]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::state::reset_fips_state;
+use pqc_nostd::{
+    decapsulate, dilithium_compute_mu, dilithium_generate_key_pair, dilithium_sign,
+    dilithium_sign_deterministic, dilithium_verify, encapsulate, kyber_generate_key_pair,
+    last_service_approved, last_service_indicator, run_post_or_panic, EntropySource, HmacDrbg,
+    NoiseSource, PqcError, ServiceIndicator, FIPS_CONTEXT,
+};
+
+/// Xorshift noise for the entropy service.
+struct TestNoise(u32);
+
+impl NoiseSource for TestNoise {
+    fn sample(&mut self) -> u8 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 >> 24) as u8
+    }
+}
+
+#[test]
+fn service_indicator_tracks_last_service() {
+    run_post_or_panic();
+    common::login(Role::User).expect("Login failed");
+
+    // Approved ML-KEM services
+    let kp = kyber_generate_key_pair([0x01; 64]).unwrap();
+    assert_eq!(last_service_indicator(), ServiceIndicator::Approved);
+    let (ct, ss) = encapsulate(kp.public_key(), [0x02; 32]).unwrap();
+    assert!(last_service_approved());
+    assert_eq!(decapsulate(kp.private_key(), &ct).unwrap(), ss);
+    assert!(last_service_approved());
+
+    // ML-DSA is Approved only with the empty FIPS context
+    let dk = dilithium_generate_key_pair([0x03; 32]).unwrap();
+    assert!(last_service_approved());
+    let sig = dilithium_sign(&dk.signing_key, b"msg", FIPS_CONTEXT, [0x04; 32]).unwrap();
+    assert!(last_service_approved());
+    let custom = dilithium_sign(&dk.signing_key, b"msg", b"custom", [0x04; 32]).unwrap();
+    assert_eq!(last_service_indicator(), ServiceIndicator::NonApproved);
+    dilithium_verify(&dk.verification_key, b"msg", b"custom", &custom).unwrap();
+    assert_eq!(last_service_indicator(), ServiceIndicator::NonApproved);
+    dilithium_verify(&dk.verification_key, b"msg", FIPS_CONTEXT, &sig).unwrap();
+    assert!(last_service_approved());
+    dilithium_compute_mu(&dk.verification_key, b"msg", b"custom").unwrap();
+    assert!(!last_service_approved());
+
+    // A failed verification is still an Approved service
+    assert!(dilithium_verify(&dk.verification_key, b"other", FIPS_CONTEXT, &sig).is_err());
+    assert!(last_service_approved());
+
+    #[cfg(feature = "xwing")]
+    {
+        pqc_nostd::xwing_generate_key_pair([0x05; 32]).unwrap();
+        assert_eq!(last_service_indicator(), ServiceIndicator::NonApproved);
+    }
+
+    #[cfg(feature = "hpke")]
+    {
+        use pqc_nostd::{
+            hpke_seal, hpke_setup_sender_mlkem1024, HpkeAead, HpkeKdf, HpkeMode, HpkeSuite,
+        };
+
+        let mut out = [0u8; 32];
+        for (aead, approved) in [
+            (HpkeAead::Aes256Gcm, true),
+            (HpkeAead::ChaCha20Poly1305, false),
+        ] {
+            let suite = HpkeSuite {
+                kdf: HpkeKdf::HkdfSha256,
+                aead,
+            };
+            let (_, mut ctx) = hpke_setup_sender_mlkem1024(
+                suite,
+                HpkeMode::Base,
+                kp.public_key(),
+                b"",
+                [0x06; 32],
+            )
+            .unwrap();
+            assert_eq!(last_service_approved(), approved);
+            dilithium_verify(&dk.verification_key, b"msg", b"custom", &custom).unwrap();
+            hpke_seal(&mut ctx, b"", b"msg", &mut out).unwrap();
+            assert_eq!(last_service_approved(), approved);
+        }
+    }
+
+    // A service the module refuses is never reported as Approved
+    reset_fips_state();
+    assert_eq!(
+        kyber_generate_key_pair([0x01; 64]).err(),
+        Some(PqcError::FipsNotInitialized)
+    );
+    assert_eq!(last_service_indicator(), ServiceIndicator::NonApproved);
+    run_post_or_panic();
+    common::login(Role::CryptoOfficer).expect("Login failed");
+    assert_eq!(
+        kyber_generate_key_pair([0x01; 64]).err(),
+        Some(PqcError::AuthenticationFailure)
+    );
+    assert_eq!(last_service_indicator(), ServiceIndicator::NonApproved);
+
+    // Deterministic signing refused by the CO policy is not Approved either
+    common::login(Role::User).expect("Login failed");
+    let dk = dilithium_generate_key_pair([0x03; 32]).unwrap();
+    assert!(last_service_approved());
+    assert_eq!(
+        dilithium_sign_deterministic(&dk.signing_key, b"msg", FIPS_CONTEXT).err(),
+        Some(PqcError::ServiceDisabled)
+    );
+    assert_eq!(last_service_indicator(), ServiceIndicator::NonApproved);
+}
+
+#[test]
+fn drbg_entropy_and_streaming_services_record_the_indicator() {
+    let module = common::module();
+    let mut source = EntropySource::new(TestNoise(0x1234_5678), 6).unwrap();
+    module
+        .run_post_with_entropy(&mut source)
+        .expect("POST failed");
+    module
+        .login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+    let dk = module.dilithium_generate_key_pair([0x03; 32]).unwrap();
+    let custom = module
+        .dilithium_sign(&dk.signing_key, b"msg", b"custom", [0x04; 32])
+        .unwrap();
+    let sig = module
+        .dilithium_sign(&dk.signing_key, b"msg", FIPS_CONTEXT, [0x04; 32])
+        .unwrap();
+
+    // Entropy and DRBG services are Approved
+    let mut seed = [0u8; 48];
+    module.entropy_fill(&mut source, &mut seed).unwrap();
+    assert!(module.last_service_approved());
+    module
+        .dilithium_verify(&dk.verification_key, b"msg", b"custom", &custom)
+        .unwrap();
+    let mut drbg: HmacDrbg = module
+        .drbg_instantiate(&seed[..32], &seed[32..], b"indicator")
+        .unwrap();
+    assert!(module.last_service_approved());
+    module
+        .dilithium_verify(&dk.verification_key, b"msg", b"custom", &custom)
+        .unwrap();
+    let mut out = [0u8; 32];
+    module.drbg_generate(&mut drbg, &mut out, &[]).unwrap();
+    assert!(module.last_service_approved());
+    module
+        .dilithium_verify(&dk.verification_key, b"msg", b"custom", &custom)
+        .unwrap();
+    module.drbg_reseed(&mut drbg, &seed, &[]).unwrap();
+    assert!(module.last_service_approved());
+
+    // Streaming signing and verification follow the context, when started
+    // and when finished
+    for (ctx, expected, approved) in [(FIPS_CONTEXT, &sig, true), (&b"custom"[..], &custom, false)]
+    {
+        module
+            .dilithium_verify(&dk.verification_key, b"msg", FIPS_CONTEXT, &sig)
+            .unwrap();
+        let mut signer = module.dilithium_signer(&dk.signing_key, ctx).unwrap();
+        assert_eq!(module.last_service_approved(), approved);
+        signer.update(b"msg");
+        module
+            .dilithium_verify(&dk.verification_key, b"msg", b"custom", &custom)
+            .unwrap();
+        assert_eq!(
+            signer.finalize([0x04; 32]).unwrap().as_slice(),
+            expected.as_slice()
+        );
+        assert_eq!(module.last_service_approved(), approved);
+
+        let mut verifier = module
+            .dilithium_verifier(&dk.verification_key, ctx)
+            .unwrap();
+        assert_eq!(module.last_service_approved(), approved);
+        verifier.update(b"msg");
+        module
+            .dilithium_verify(&dk.verification_key, b"msg", FIPS_CONTEXT, &sig)
+            .unwrap();
+        verifier.finalize(expected).unwrap();
+        assert_eq!(module.last_service_approved(), approved);
+    }
+
+    // A refused streaming or DRBG service is not Approved
+    let mut signer = module
+        .dilithium_signer(&dk.signing_key, FIPS_CONTEXT)
+        .unwrap();
+    signer.update(b"msg");
+    module.logout();
+    assert_eq!(
+        signer.finalize([0x04; 32]).err(),
+        Some(PqcError::AuthenticationFailure)
+    );
+    assert_eq!(
+        module.last_service_indicator(),
+        ServiceIndicator::NonApproved
+    );
+    module
+        .login(Role::User, common::USER_PASSWORD)
+        .expect("Login failed");
+    module.kyber_generate_key_pair([0x01; 64]).unwrap();
+    assert_eq!(
+        module
+            .dilithium_sign_deterministic(&dk.signing_key, b"msg", FIPS_CONTEXT)
+            .err(),
+        Some(PqcError::ServiceDisabled)
+    );
+    assert_eq!(
+        module.last_service_indicator(),
+        ServiceIndicator::NonApproved
+    );
+    module.logout();
+    assert!(module.drbg_generate(&mut drbg, &mut out, &[]).is_err());
+    assert_eq!(
+        module.last_service_indicator(),
+        ServiceIndicator::NonApproved
+    );
+}
@@ -0,0 +1,48 @@
+// tests/slh_dsa.rs
+// SLH-DSA-SHAKE-128s / SLH-DSA-SHAKE-256s authenticated sign/verify
+
+#![cfg(all(feature = "slh-dsa", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::{
+    run_post_or_panic, slh_dsa_shake_128s_generate_key_pair, slh_dsa_shake_128s_sign,
+    slh_dsa_shake_128s_verify, slh_dsa_shake_256s_generate_key_pair, slh_dsa_shake_256s_sign,
+    slh_dsa_shake_256s_verify, PqcError, FIPS_CONTEXT, SLH_DSA_SHAKE_128S_SIG_BYTES,
+    SLH_DSA_SHAKE_256S_SIG_BYTES,
+};
+
+#[test]
+fn slh_dsa_sign_verify() {
+    run_post_or_panic(); // Includes the SLH-DSA KATs
+    common::login(Role::User).expect("Login failed");
+    let msg = b"root-of-trust certificate";
+
+    // Key generation runs the PCT
+    let kp = slh_dsa_shake_128s_generate_key_pair([0x11u8; 48]).unwrap();
+    let sig = slh_dsa_shake_128s_sign(&kp.signing_key, msg, FIPS_CONTEXT, [0x22u8; 16]).unwrap();
+    assert_eq!(sig.as_ref().len(), SLH_DSA_SHAKE_128S_SIG_BYTES);
+    assert!(slh_dsa_shake_128s_verify(&kp.verification_key, msg, FIPS_CONTEXT, &sig).is_ok());
+    assert!(
+        slh_dsa_shake_128s_verify(&kp.verification_key, b"tampered", FIPS_CONTEXT, &sig).is_err()
+    );
+    assert!(slh_dsa_shake_128s_verify(&kp.verification_key, msg, b"other", &sig).is_err());
+
+    let mut bad = sig.clone();
+    bad.as_mut_slice()[SLH_DSA_SHAKE_128S_SIG_BYTES - 1] ^= 0x01;
+    assert!(slh_dsa_shake_128s_verify(&kp.verification_key, msg, FIPS_CONTEXT, &bad).is_err());
+
+    assert_eq!(
+        slh_dsa_shake_128s_sign(&kp.signing_key, msg, &[0u8; 256], [0x22u8; 16]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    let kp = slh_dsa_shake_256s_generate_key_pair([0x11u8; 96]).unwrap();
+    let sig = slh_dsa_shake_256s_sign(&kp.signing_key, msg, FIPS_CONTEXT, [0x22u8; 32]).unwrap();
+    assert_eq!(sig.as_ref().len(), SLH_DSA_SHAKE_256S_SIG_BYTES);
+    assert!(slh_dsa_shake_256s_verify(&kp.verification_key, msg, FIPS_CONTEXT, &sig).is_ok());
+    assert!(
+        slh_dsa_shake_256s_verify(&kp.verification_key, b"tampered", FIPS_CONTEXT, &sig).is_err()
+    );
+}
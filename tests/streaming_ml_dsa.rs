@@ -0,0 +1,61 @@
+// tests/streaming_ml_dsa.rs
+// Incremental ML-DSA-65: sign/verify a message fed in chunks
+
+#![cfg(all(feature = "ml-dsa", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::{logout, Role};
+use pqc_nostd::{
+    dilithium_generate_key_pair, dilithium_sign, dilithium_verify, run_post_or_panic,
+    DilithiumSigner, DilithiumVerifier, PqcError,
+};
+
+#[test]
+fn streaming_sign_verify() {
+    run_post_or_panic();
+    common::login(Role::User).expect("Login failed");
+
+    let kp = dilithium_generate_key_pair([0x61u8; 32]).unwrap();
+    let ctx = b"firmware-v3";
+    let mut image = [0u8; 20_000];
+    for (i, b) in image.iter_mut().enumerate() {
+        *b = (i * 7 + 3) as u8;
+    }
+
+    // Chunk boundaries must not matter
+    let mut signer = DilithiumSigner::new(&kp.signing_key, ctx).unwrap();
+    for chunk in image.chunks(1000) {
+        signer.update(chunk);
+    }
+    let sig = signer.finalize([0x62u8; 32]).unwrap();
+
+    let reference = dilithium_sign(&kp.signing_key, &image, ctx, [0x62u8; 32]).unwrap();
+    assert_eq!(sig.as_ref(), reference.as_ref());
+    assert!(dilithium_verify(&kp.verification_key, &image, ctx, &sig).is_ok());
+
+    let mut verifier = DilithiumVerifier::new(&kp.verification_key, ctx).unwrap();
+    for chunk in image.chunks(333) {
+        verifier.update(chunk);
+    }
+    assert!(verifier.finalize(&sig).is_ok());
+
+    // Modified stream or context is rejected
+    let mut verifier = DilithiumVerifier::new(&kp.verification_key, ctx).unwrap();
+    verifier.update(&image[..image.len() - 1]);
+    assert!(verifier.finalize(&sig).is_err());
+    let mut verifier = DilithiumVerifier::new(&kp.verification_key, b"").unwrap();
+    verifier.update(&image);
+    assert!(verifier.finalize(&sig).is_err());
+
+    assert_eq!(
+        DilithiumSigner::new(&kp.signing_key, &[0u8; 256]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    // Finalizing after logout is refused
+    let mut signer = DilithiumSigner::new(&kp.signing_key, ctx).unwrap();
+    signer.update(&image);
+    logout();
+    assert!(signer.finalize([0x62u8; 32]).is_err());
+}
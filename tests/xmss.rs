@@ -0,0 +1,92 @@
+// tests/xmss.rs
+// XMSS-SHA2_10_192 verification plus XMSS / XMSS^MT OID separation
+
+#![cfg(all(feature = "xmss", feature = "fips_140_3"))]
+
+mod common;
+
+use pqc_nostd::auth::Role;
+use pqc_nostd::{run_post_or_panic, xmss_verify, xmssmt_verify, PqcError};
+
+// Public key and detached signature (leaf index 2) over MSG, generated with
+// an independent RFC 8391 implementation.
+const MSG: &[u8] = b"partner update bundle";
+const PK_HEX: &str = "\
+    0000000db8e84793033b07d3e37a24cdff2b9636d47e85b35e074ec1303132333435363738393a3b3c3d3e3f40414243\
+    44454647";
+const SIG_HEX: &str = "\
+    00000002dcd42cc7ef9c105a0d9864f57d4322ae38ea160fafbe8694fc1205d4eb20254856247556515b77e0b9800685\
+    019629f71386fab65ce4dbaec79d55bfd55a7ba3f6f401b69cb1dc6a804a9a0c69d3d2422b99b656be21b4e828023819\
+    2f0e92d33176bf526602c8b812042c8653d019977e83878cc255690e2ddac2588e32ddf242236666c329733ed451178d\
+    1c91641769b2c3023a76e2c71f4d5d69917dcd74d61e60930ceb5416237ca8805b7abbc2176505e086dad2588264bc04\
+    de3b39ae955cbcb475b54a645cd718153c9f353153090b487093a007c3a20fccf475e7c1dd00cf5650bd98563c2f3340\
+    ba6e200302738317a92e8950fb26ce958d81a16d96031b377a3eeffcb61826f8015a6087dfe2f73c2ea7b29f7c7996ea\
+    c9e14089509ba508299f7ef8c76ed0509a6d5fde3cd843c8d0be943114988e171a9198c2b2cf244c060e89c38191d823\
+    0e3fbca38d0c30b3bd83bfaf08c58cc17483be399d78ae3312dac2f714fb7edd8715e4df9392d05969828dddf8297acb\
+    6c4c1594a697a679227687015bfcaeafe45571d1eeffca5a46ebede26ea9502f9e888ec5ad92bcf0fc37fa4effad6003\
+    0db340f336f19802a0b964c48c77cfacb05ecd25cecda4ce7c53c499954f23c41f4496d4189d91ce2571666a8e5e7f2c\
+    425efa42d399ee7c89ac1d8b0ff2ac3fce780d78dbc1a1ef582e9597cc24b0f43031a89cbeeba1ba3bee21eebd03b224\
+    5a3dc64309717e6e47579b079209a69cbd37cfd47af17ba7b5ac92ed49cf1340caa1e17e421abba1cbb47f3a53bf3c29\
+    b65340f5ac884eaf610850eb1cbf46b3cc2d59922817b4f10cc1f5e718cf70d8901fb582d4d454e37a6f0eb4fdf56ce3\
+    bff040839656741c14031a982352e5130b90431c7099a0eb0e09e929df08ec60ccfc4e52f3c0bda02b4a05d774b9af6c\
+    7af3a04e9456d3a1604630dca61cc091758a3c9f94d10cfcf0f25174224726dd54fa3f54080b6e4c9cddac9d499ce48f\
+    f45cc329f32407e83313e1e71ca7659184ff3c259162e027a18ee0376db712aa626a89276edae901ce96a59573b155e2\
+    80c9c229dff4f24be6650a1f23e9eaf294f9432fc8f16b029934fec39445729b841138a71ca26453b4dedbb9e86f6f92\
+    5e2b92fb9b5d82fa8a3c4075a0944e47aac0dae8f41091a2bf362b4064513f72fc03d5b5b201721f0a669606d3cf18fe\
+    94aee2a3e59ee151ad1f24d1a3d073928dc8c447a9068955b1124a3a9ba991de007d4103127c17d0666663bfb383ac4b\
+    c0edda36b185699c061d4824c6cfb1f01ecd1619d9b5c4101d659fe4b2f0660b8d99b79a843d7b585fd4b5ff223a09a5\
+    9e2c48c478e3a2175862ce82ff66475fa9e24500a5ef07c7e93914736ca275e0b55d7359d0459743b9ca85dbc030169d\
+    ea44df328dfa177af9052e5f39a1202e940ae8e0b4a40e851d3048099fd7870b08babff1126ca8b4f6e968c1d2285d90\
+    a7fd2592e0a4a3e5bc2cac1834b03159dffb5923fdd14b19331984ab685f6e28b539b269c669717877a1eb84cf6754bc\
+    8db3538541d4f70416e3cbaf051ada7b1937d5ef24150605c4cf49221148c28d76c8d5f6be10e8552ef323d67edc323e\
+    2f9e9e019732ef37c3a639ccd65fabf672134a2e6d60bae07ca4827c0c05abde3d3146f84d8387f6223c52edc6006497\
+    023421049453a0ea018a428f52feaaaba00af70d5da0dd9271672eeddf60a13e9238236377c068df57480d4452cb7aa3\
+    8061da10dad19b8518a8da25658ef2af79d7326d66b137d7a2cd50148695066a54f24ae621558d75b4385b566df5c7df\
+    f255ffad075c35e275173207248fefa2b84cd015157aa9a9ec2b25dc5cc0a46e8e1e3627ff0b3c1923b72ae052ae7e65\
+    4c9725d2439f1a8d7646a4ec7a149c951e0ed84f0f9351d9b5e61a6eebd091865d15b84b9193743bd8a1c78d63c0b0e0\
+    034a11cf399277eccb33a0f3bf0d5e9740f8e8234706c20784b23319145d4ef21d6888cd4170b2e6ba6b35ef90637fa4\
+    1b487e1b13a140d9ac512a17afcc09a3c183cc6376d8c4658f77e84a34cdff0a7ffccac9f5f0e888d8b3edceffc533ab\
+    a44146f0";
+
+fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn xmss_verify_sha2_192() {
+    run_post_or_panic(); // Includes the XMSS and XMSS^MT KATs
+    common::login(Role::User).expect("Login failed");
+    let pk = from_hex(PK_HEX);
+    let sig = from_hex(SIG_HEX);
+
+    assert!(xmss_verify(&pk, MSG, &sig).is_ok());
+    assert!(xmss_verify(&pk, b"tampered bundle", &sig).is_err());
+
+    // Index, randomizer, WOTS+ signature and authentication path are all bound
+    for pos in [3, 10, 500, sig.len() - 1] {
+        let mut bad = sig.clone();
+        bad[pos] ^= 0x01;
+        assert!(xmss_verify(&pk, MSG, &bad).is_err());
+    }
+
+    // Malformed encodings are rejected as invalid parameters
+    assert_eq!(
+        xmss_verify(&pk, MSG, &sig[..sig.len() - 1]).err(),
+        Some(PqcError::InvalidParameter)
+    );
+    let mut index_too_large = sig.clone();
+    index_too_large[..4].copy_from_slice(&1024u32.to_be_bytes());
+    assert_eq!(
+        xmss_verify(&pk, MSG, &index_too_large).err(),
+        Some(PqcError::InvalidParameter)
+    );
+
+    // XMSS OID 0x0d is not an SP 800-208 XMSS^MT parameter set
+    assert_eq!(
+        xmssmt_verify(&pk, MSG, &sig).err(),
+        Some(PqcError::InvalidParameter)
+    );
+}